@@ -1,7 +1,27 @@
-use advent2020::day11::{part1, part2};
+use advent2020::day11::{part1, part2, visualize, Grid};
+use advent_common::input;
+use advent_common::visualize::Recorder;
 
 fn main() {
-    let input = "tests/day11/input";
-    println!("{}", part1::solve(input).unwrap());
-    println!("{}", part2::solve(input).unwrap());
+    let input = &input::path_or("tests/day11/input");
+    let text = input::read(input).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let grid = Grid::parse(&text).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let recorder = Recorder::from_args().unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        std::process::exit(2);
+    });
+    if let Some(mut recorder) = recorder {
+        if let Err(err) = visualize(&grid, &mut recorder) {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        }
+    }
+    println!("{}", part1::solve(&grid));
+    println!("{}", part2::solve(&grid));
 }
@@ -1,7 +1,16 @@
-use advent2020::day12::{part1, part2};
+use advent2020::day12::{part1, part2, Instruction};
+use advent_common::input;
 
 fn main() {
-    let input = "tests/day12/input";
-    println!("{}", part1::solve(input).unwrap());
-    println!("{}", part2::solve(input).unwrap());
+    let input = &input::path_or("tests/day12/input");
+    let text = input::read(input).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let instructions = Instruction::parse_all(&text).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    println!("{}", part1::solve(&instructions));
+    println!("{}", part2::solve(&instructions));
 }
@@ -1,7 +1,20 @@
-use advent2020::day13::{part1, part2};
+use advent2020::day13::{parse_notes, part1, part2};
+use advent_common::input;
 
 fn main() {
-    let input = "tests/day13/input";
-    println!("{}", part1::solve(input).unwrap());
-    println!("{}", part2::solve(input).unwrap());
+    let input = &input::path_or("tests/day13/input");
+    let text = input::read(input).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let notes = parse_notes(&text).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    println!("{}", part1::solve(&notes));
+    let answer2 = part2::solve(&notes).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        std::process::exit(2);
+    });
+    println!("{}", answer2);
 }
@@ -1,7 +1,24 @@
-use advent2020::day14::{part1, part2};
+use advent2020::day14::{part1, part2, Instruction};
+use advent_common::input;
 
 fn main() {
-    let input = "tests/day14/input";
-    println!("{}", part1::solve(input).unwrap());
-    println!("{}", part2::solve(input).unwrap());
+    let input = &input::path_or("tests/day14/input");
+    let text = input::read(input).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let instructions = Instruction::parse_all(&text).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let answer1 = part1::solve(&instructions).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    });
+    println!("{}", answer1);
+    let answer2 = part2::solve(&instructions).unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        std::process::exit(2);
+    });
+    println!("{}", answer2);
 }
@@ -1,7 +1,16 @@
-use advent2020::day15::{part1, part2};
+use advent2020::day15::{parse, part1, part2};
+use advent_common::input;
 
 fn main() {
-    let input = [10, 16, 6, 0, 1, 17];
-    println!("{}", part1::solve(&input));
-    println!("{}", part2::solve(&input));
+    let input = &input::path_or("tests/day15/input");
+    let text = input::read(input).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let numbers = parse(&text).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    println!("{}", part1::solve(&numbers));
+    println!("{}", part2::solve(&numbers));
 }
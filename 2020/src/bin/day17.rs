@@ -1,7 +1,27 @@
-use advent2020::day17::{part1, part2};
+use advent2020::day17::{parse_slice, part1, part2, visualize};
+use advent_common::input;
+use advent_common::visualize::Recorder;
 
 fn main() {
-    let input_path = "tests/day17/input";
-    println!("{}", part1::solve(input_path).unwrap());
-    println!("{}", part2::solve(input_path).unwrap());
+    let input = &input::path_or("tests/day17/input");
+    let text = input::read(input).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let slice = parse_slice(&text).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let recorder = Recorder::from_args().unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        std::process::exit(2);
+    });
+    if let Some(mut recorder) = recorder {
+        if let Err(err) = visualize(&slice, &mut recorder) {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        }
+    }
+    println!("{}", part1::solve(&slice));
+    println!("{}", part2::solve(&slice));
 }
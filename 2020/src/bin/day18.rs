@@ -1,23 +1,30 @@
-use advent2020::day18::{part1, part2};
-use std::fs;
+use advent2020::day18::{part1, part2, tokenize};
+use advent_common::input;
 
 fn main() {
-    let input = "tests/day18/input";
-    let text = match fs::read_to_string(input) {
+    let input = &input::path_or("tests/day18/input");
+    let text = match input::read(input) {
         Ok(text) => text,
         Err(err) => {
             eprintln!("error: {}: {}", input, err);
             std::process::exit(3);
         }
     };
-    match part1::solve(&text) {
+    let homework = match tokenize(&text) {
+        Ok(homework) => homework,
+        Err(err) => {
+            eprintln!("error: {}: {}", input, err);
+            std::process::exit(3);
+        }
+    };
+    match part1::solve(&homework) {
         Ok(answer) => println!("{}", answer),
         Err(err) => {
             eprintln!("error: {}: {}", input, err);
             std::process::exit(1);
         }
     }
-    match part2::solve(&text) {
+    match part2::solve(&homework) {
         Ok(answer) => println!("{}", answer),
         Err(err) => {
             eprintln!("error: {}: {}", input, err);
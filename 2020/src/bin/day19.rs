@@ -1,23 +1,30 @@
-use advent2020::day19::{part1, part2};
-use std::fs;
+use advent2020::day19::{part1, part2, Transmission};
+use advent_common::input;
 
 fn main() {
-    let input = "tests/day19/input";
-    let text = match fs::read_to_string(input) {
+    let input = &input::path_or("tests/day19/input");
+    let text = match input::read(input) {
         Ok(text) => text,
         Err(err) => {
             eprintln!("error: {}: {}", input, err);
             std::process::exit(3);
         }
     };
-    match part1::solve(&text) {
+    let transmission = match Transmission::parse(&text) {
+        Ok(transmission) => transmission,
+        Err(err) => {
+            eprintln!("error: {}: {}", input, err);
+            std::process::exit(3);
+        }
+    };
+    match part1::solve(&transmission) {
         Ok(answer) => println!("{}", answer),
         Err(err) => {
             eprintln!("error: {}: {}", input, err);
             std::process::exit(1);
         }
     }
-    match part2::solve(&text) {
+    match part2::solve(&transmission) {
         Ok(answer) => println!("{}", answer),
         Err(err) => {
             eprintln!("error: {}: {}", input, err);
@@ -1,27 +1,61 @@
-use advent2020::day20::{part1, part2};
-use std::fs;
+use advent2020::day20::{part1, part2, Jigsaw, Pattern, Tile};
+use advent_common::{input, Error};
+use std::env;
+
+/// The prefix of the argument naming a file of an ASCII pattern to find in
+/// the assembled image, which is then printed with the matches highlighted.
+const PATTERN_OPTION: &str = "--pattern=";
+
+fn show_matches(tiles: &[Tile], pattern_path: &str) -> Result<(), Error> {
+    let pattern: Pattern = input::read(pattern_path)
+        .map_err(|err| Error::from(err).in_file(pattern_path))?
+        .parse()
+        .map_err(|err: Error| err.in_file(pattern_path))?;
+    let image = Jigsaw::assemble(tiles)?.image();
+    let matches = image.search(&pattern);
+    print!("{}", image.highlight(&matches));
+    println!("matches: {}", matches.len());
+    println!("roughness: {}", image.roughness(&matches));
+    Ok(())
+}
 
 fn main() {
-    let input = "tests/day20/input";
-    let text = match fs::read_to_string(input) {
+    let input = &input::path_or("tests/day20/input");
+    let text = match input::read(input) {
         Ok(text) => text,
         Err(err) => {
             eprintln!("error: {}: {}", input, err);
             std::process::exit(3);
         }
     };
-    match part1::solve(&text) {
+    let tiles = match Tile::parse_all(&text) {
+        Ok(tiles) => tiles,
+        Err(err) => {
+            eprintln!("error: {}: {}", input, err);
+            std::process::exit(3);
+        }
+    };
+    match part1::solve(&tiles) {
         Ok(answer) => println!("{}", answer),
         Err(err) => {
             eprintln!("error: {}: {}", input, err);
             std::process::exit(1);
         }
     }
-    match part2::solve(&text) {
+    match part2::solve(&tiles) {
         Ok(answer) => println!("{}", answer),
         Err(err) => {
             eprintln!("error: {}: {}", input, err);
             std::process::exit(2);
         }
     }
+    let pattern_path = env::args()
+        .skip(1)
+        .find_map(|arg| arg.strip_prefix(PATTERN_OPTION).map(str::to_owned));
+    if let Some(path) = pattern_path {
+        if let Err(err) = show_matches(&tiles, &path) {
+            eprintln!("error: {}", err);
+            std::process::exit(4);
+        }
+    }
 }
@@ -1,10 +1,16 @@
 use advent2020::day22::{deck, part1, part2};
-use std::fs;
+use advent_common::input;
 
 fn main() {
-    let input_path = "tests/day22/input";
-    let text = fs::read_to_string(input_path).unwrap();
-    let decks = deck::parse_both(text).unwrap();
+    let input = &input::path_or("tests/day22/input");
+    let text = input::read(input).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let decks = deck::parse_both(text).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
     println!("{}", part1::solve(decks.clone()));
     println!("{}", part2::solve(decks));
 }
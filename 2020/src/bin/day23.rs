@@ -1,7 +1,29 @@
-use advent2020::day23::{part1, part2};
+use advent2020::day23::{parse, part1, part2};
+use advent_common::{input, trace};
 
 fn main() {
-    let input = 624397158;
-    println!("{}", part1::solve(input));
-    println!("{}", part2::solve(input));
+    if let Err(err) = trace::init_from_args() {
+        eprintln!("error: {}", err);
+        std::process::exit(2);
+    }
+    let _span = trace::span("2020 day23");
+    let input = &input::path_or("tests/day23/input");
+    let text = input::read(input).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let digits = parse(&text).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let answer1 = {
+        let _span = trace::span("part1");
+        part1::solve(digits)
+    };
+    println!("{}", answer1);
+    let answer2 = {
+        let _span = trace::span("part2");
+        part2::solve(digits)
+    };
+    println!("{}", answer2);
 }
@@ -1,10 +1,13 @@
 use advent2020::day24::Floor;
-use std::fs;
+use advent_common::input;
 
 fn main() {
-    let input_path = "tests/day24/input";
-    let text = fs::read_to_string(input_path).unwrap();
-    let floor: Floor = text.parse().unwrap();
+    let input = &input::path_or("tests/day24/input");
+    let text = input::read(input).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let Ok(floor) = text.parse::<Floor>();
     println!("{}", floor.count_black());
     println!("{}", floor.day(100).count_black());
 }
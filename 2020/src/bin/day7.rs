@@ -1,17 +1,22 @@
 use advent2020::day7::*;
+use advent_common::{input, Error};
+
+fn load_rules(input: &str) -> Result<RuleMap, Error> {
+    parse_rules(&input::read(input)?)
+}
 
 fn main() {
-    let input = "tests/day7/input";
-    let color = ("shiny".to_owned(), "gold".to_owned());
-    let answer1 = match part1::solve(input, &color) {
-        Ok(answer) => answer,
+    let input = &input::path_or("tests/day7/input");
+    let rules = match load_rules(input) {
+        Ok(rules) => rules,
         Err(err) => {
             eprintln!("error: {}: {}", input, err);
-            std::process::exit(1);
+            std::process::exit(3);
         }
     };
-    println!("{}", answer1);
-    let answer2 = match part2::solve(input, &color) {
+    let color = ("shiny".to_owned(), "gold".to_owned());
+    println!("{}", part1::solve(&rules, &color));
+    let answer2 = match part2::solve(&rules, &color) {
         Ok(answer) => answer,
         Err(err) => {
             eprintln!("error: {}: {}", input, err);
@@ -0,0 +1,62 @@
+use advent_common::{Error, Solver};
+use std::collections::{HashMap, HashSet};
+use std::num::ParseIntError;
+
+// O(N) time, O(N) space
+pub fn parse_entries(text: &str) -> Result<HashSet<i32>, ParseIntError> {
+    let mut entries = HashSet::new();
+    for line in text.lines() {
+        entries.insert(line.parse()?);
+    }
+    Ok(entries)
+}
+
+// O(N) time, O(1) space
+pub fn solve_part1(entries: &HashSet<i32>) -> Result<i32, Error> {
+    for entry in entries {
+        let delta = 2020 - entry;
+        if entries.contains(&delta) {
+            return Ok(delta * entry);
+        }
+    }
+    Err(Error::no_solution())
+}
+
+// O(N²) time, O(N²) space
+pub fn solve_part2(entries: &HashSet<i32>) -> Result<i32, Error> {
+    let mut pairs = HashMap::new();
+    for first in entries {
+        for second in entries {
+            if first != second {
+                pairs.insert(first + second, (first, second));
+            }
+        }
+    }
+    for third in entries {
+        let delta = 2020 - third;
+        if let Some((&first, &second)) = pairs.get(&delta) {
+            return Ok(first * second * third);
+        }
+    }
+    Err(Error::no_solution())
+}
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    type Puzzle<'a> = HashSet<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(text: &str) -> Result<HashSet<i32>, Error> {
+        Ok(parse_entries(text)?)
+    }
+
+    fn part1(entries: &HashSet<i32>) -> Result<i32, Error> {
+        solve_part1(entries)
+    }
+
+    fn part2(entries: &HashSet<i32>) -> Result<i32, Error> {
+        solve_part2(entries)
+    }
+}
@@ -0,0 +1,161 @@
+use advent_common::{Error, Result, Solver};
+use std::collections::HashMap;
+use std::mem;
+
+type Graph = HashMap<u32, Vec<u32>>;
+
+const MIN_DELTA: u32 = 1;
+const MAX_DELTA: u32 = 3;
+
+// The standard is_sorted function isn't stable yet.  See:
+// https://doc.rust-lang.org/std/primitive.slice.html#method.is_sorted
+fn is_sorted(joltages: &[u32]) -> bool {
+    (1..joltages.len()).all(|i| joltages[i] >= joltages[i - 1])
+}
+
+pub fn parse_joltages(text: &str) -> Result<Vec<u32>> {
+    let mut joltages = Vec::new();
+    for line in text.lines() {
+        joltages.push(line.parse()?);
+    }
+    joltages.sort_unstable();
+    if let Some(&last) = joltages.last() {
+        joltages.push(last + MAX_DELTA);
+        Ok(joltages)
+    } else {
+        Err(Error::empty_input())
+    }
+}
+
+fn take_kids<'a, I>(head: u32, tail: I) -> Vec<u32>
+where
+    I: IntoIterator<Item = &'a u32>,
+{
+    tail.into_iter()
+        .cloned()
+        .take_while(|&joltage| joltage - head <= MAX_DELTA)
+        .collect()
+}
+
+fn count_paths_with_memo(
+    graph: &Graph,
+    source: u32,
+    target: u32,
+    memo: &mut HashMap<u32, usize>,
+) -> Result<usize> {
+    if source == target {
+        Ok(1)
+    } else if let Some(&known) = memo.get(&source) {
+        Ok(known)
+    } else if let Some(kids) = graph.get(&source) {
+        let mut count = 0;
+        for &kid in kids {
+            count += count_paths_with_memo(graph, kid, target, memo)?;
+        }
+        memo.insert(source, count);
+        Ok(count)
+    } else {
+        Err(Error::parse(format!("bad graph: missing node {}", source)))
+    }
+}
+
+/// Returns the number of paths from source to target in the specified graph.
+fn count_paths(graph: &Graph, source: u32, target: u32) -> Result<usize> {
+    count_paths_with_memo(graph, source, target, &mut HashMap::new())
+}
+
+pub fn solve_part1(adapters: &[u32]) -> Result<usize> {
+    assert!(is_sorted(adapters));
+    let deltas: Vec<u32> = adapters
+        .iter()
+        .scan(0, |x, &y| Some(y - mem::replace(x, y)))
+        .collect();
+    if deltas.iter().any(|&d| d > MAX_DELTA) {
+        return Err(Error::no_solution()); // The chain is broken.
+    }
+    let min_count = deltas.iter().cloned().filter(|&d| d == MIN_DELTA).count();
+    let max_count = deltas.iter().cloned().filter(|&d| d == MAX_DELTA).count();
+    Ok(min_count * max_count)
+}
+
+pub fn solve_part2(adapters: &[u32]) -> Result<usize> {
+    assert!(is_sorted(adapters));
+    let source = 0u32;
+    let target = *adapters.last().unwrap();
+    let mut graph = Graph::new();
+    graph.insert(source, take_kids(0, adapters));
+    for (i, &key) in adapters.iter().enumerate() {
+        graph.insert(key, take_kids(key, &adapters[(i + 1)..]));
+    }
+    match count_paths(&graph, source, target)? {
+        0 => Err(Error::no_solution()),
+        count => Ok(count),
+    }
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Puzzle<'a> = Vec<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<u32>> {
+        parse_joltages(text)
+    }
+
+    fn part1(adapters: &Vec<u32>) -> Result<usize> {
+        solve_part1(adapters)
+    }
+
+    fn part2(adapters: &Vec<u32>) -> Result<usize> {
+        solve_part2(adapters)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use advent_common::ErrorKind;
+    use std::fs;
+
+    fn load_joltages(input: &str) -> Result<Vec<u32>> {
+        parse_joltages(&fs::read_to_string(input)?)
+    }
+
+    #[test]
+    fn part1_sample1a() {
+        let adapters = load_joltages("tests/day10/sample1a").unwrap();
+        assert_eq!(35, solve_part1(&adapters).unwrap());
+    }
+
+    #[test]
+    fn part1_sample1b() {
+        let adapters = load_joltages("tests/day10/sample1b").unwrap();
+        assert_eq!(220, solve_part1(&adapters).unwrap());
+    }
+
+    #[test]
+    fn part2_sample1a() {
+        let adapters = load_joltages("tests/day10/sample1a").unwrap();
+        assert_eq!(8, solve_part2(&adapters).unwrap());
+    }
+
+    #[test]
+    fn part2_sample1b() {
+        let adapters = load_joltages("tests/day10/sample1b").unwrap();
+        assert_eq!(19208, solve_part2(&adapters).unwrap());
+    }
+
+    #[test]
+    fn broken_chain() {
+        let adapters = parse_joltages("1\n2\n6\n").unwrap();
+        let err = solve_part1(&adapters).unwrap_err();
+        assert_eq!(ErrorKind::NoSolution, err.kind());
+        let err = solve_part2(&adapters).unwrap_err();
+        assert_eq!(ErrorKind::NoSolution, err.kind());
+        let graph: Graph = [(0, vec![1])].iter().cloned().collect();
+        let err = count_paths(&graph, 0, 2).unwrap_err();
+        assert_eq!("bad graph: missing node 1", err.message());
+    }
+}
@@ -1,302 +1,138 @@
-use super::{Position, Size, Spot};
-use crate::error::ParseError;
-use std::cmp;
-use std::error::Error;
+use super::Spot;
+use advent_common::grid::{self, Offset, Position};
+use advent_common::Error;
 use std::fmt;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
+use std::iter;
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
-pub struct Grid {
-    height: usize,
-    width: usize,
-    spots: Vec<Spot>,
-}
+pub struct Grid(grid::Grid<Spot>);
 
 impl Grid {
-    fn at(&self, pos: Position) -> Spot {
-        assert!(pos.row < self.height);
-        assert!(pos.column < self.width);
-        self.spots[pos.row * self.width + pos.column]
-    }
-
-    pub fn from_file<P>(input: P) -> Result<Grid, Box<dyn Error>>
-    where
-        P: AsRef<Path>,
-    {
-        let mut lines = BufReader::new(File::open(&input)?).lines();
-        let first_line = match lines.next() {
-            Some(line) => line?,
-            None => todo!("support empty grids"),
-        };
-        if first_line.is_empty() {
-            return Err(Box::new(ParseError::in_file(input, "empty row")));
-        }
-        let width = first_line.len();
-        let mut height = 1;
-        let mut spots = Spot::parse_line(&first_line)?;
-        for line in lines {
-            let line = line?;
-            if line.len() != width {
-                return Err(Box::new(ParseError::at(input, height, "jagged rows")));
-            }
-            spots.extend(Spot::parse_line(&line)?.iter());
-            height += 1;
-        }
-        Ok(Grid {
-            height,
-            width,
-            spots,
-        })
-    }
-
-    pub fn with_size(size: Size) -> Grid {
-        if size.height < 2 || size.width < 2 {
-            todo!("support single-row and single-column grids")
-        } else {
-            Grid {
-                height: size.height,
-                width: size.width,
-                spots: vec![Spot::Floor; size.area()],
-            }
-        }
+    pub fn parse(text: &str) -> Result<Grid, Error> {
+        grid::Grid::parse(text, |b| Spot::from_char(b as char)).map(Grid)
     }
 
     fn count_neighbors1(&self, pos: Position) -> usize {
-        // i and j are 0-based indexes into the 3x3 grid around pos.
-        let mut count = 0;
-        let Position { row, column } = pos;
-        for i in 0..3 {
-            for j in 0..3 {
-                if (i, j) != (1, 1)
-                    && row + i > 0
-                    && column + j > 0
-                    && row + i - 1 < self.height
-                    && column + j - 1 < self.width
-                    && self.at(Position {
-                        row: row + i - 1,
-                        column: column + j - 1,
-                    }) == Spot::Occupied
-                {
-                    count += 1;
-                }
-            }
-        }
-        count
-    }
-
-    fn count_neighbors2_right(&self, pos: Position) -> usize {
-        let row = pos.row;
-        for column in (pos.column + 1)..self.width {
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
-    }
-
-    fn count_neighbors2_up_right(&self, pos: Position) -> usize {
-        let distance = cmp::min(pos.row, self.width - 1 - pos.column);
-        for delta in 1..=distance {
-            let row = pos.row - delta;
-            let column = pos.column + delta;
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
-    }
-
-    fn count_neighbors2_up(&self, pos: Position) -> usize {
-        let column = pos.column;
-        for row in (0..pos.row).rev() {
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
-    }
-
-    fn count_neighbors2_up_left(&self, pos: Position) -> usize {
-        let distance = cmp::min(pos.row, pos.column);
-        for delta in 1..=distance {
-            let row = pos.row - delta;
-            let column = pos.column - delta;
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
-    }
-
-    fn count_neighbors2_left(&self, pos: Position) -> usize {
-        let row = pos.row;
-        for column in (0..pos.column).rev() {
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
+        self.0
+            .neighbors8(pos)
+            .filter(|&pos| self.0[pos] == Spot::Occupied)
+            .count()
     }
 
-    fn count_neighbors2_down_left(&self, pos: Position) -> usize {
-        let distance = cmp::min(self.height - 1 - pos.row, pos.column);
-        for delta in 1..=distance {
-            let row = pos.row + delta;
-            let column = pos.column - delta;
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
+    /// Returns 1 if the first seat visible from pos in the direction of
+    /// offset is occupied, and 0 otherwise.
+    fn count_neighbors2_toward(&self, pos: Position, offset: Offset) -> usize {
+        match self
+            .0
+            .ray(pos, offset)
+            .map(|pos| self.0[pos])
+            .find(|&spot| spot != Spot::Floor)
+        {
+            Some(Spot::Occupied) => 1,
+            _ => 0,
         }
-        0
     }
 
-    fn count_neighbors2_down(&self, pos: Position) -> usize {
-        let column = pos.column;
-        for row in (pos.row + 1)..self.height {
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
+    fn count_neighbors2(&self, pos: Position) -> usize {
+        Offset::ADJACENT
+            .iter()
+            .map(|&offset| self.count_neighbors2_toward(pos, offset))
+            .sum()
+    }
+
+    fn next(
+        &self,
+        count_neighbors: fn(&Grid, Position) -> usize,
+        spot_next: fn(&Spot, usize) -> Spot,
+    ) -> Grid {
+        let (height, width) = (self.0.height(), self.0.width());
+        Grid(grid::Grid::from_fn(height, width, |pos| {
+            match self.0[pos] {
+                Spot::Floor => Spot::Floor,
+                old => spot_next(&old, count_neighbors(self, pos)),
             }
-        }
-        0
+        }))
     }
 
-    fn count_neighbors2_down_right(&self, pos: Position) -> usize {
-        let distance = cmp::min(self.height - 1 - pos.row, self.width - 1 - pos.column);
-        for delta in 1..=distance {
-            let row = pos.row + delta;
-            let column = pos.column + delta;
-            match self.at(Position { row, column }) {
-                Spot::Floor => (),
-                Spot::Empty => return 0,
-                Spot::Occupied => return 1,
-            }
-        }
-        0
+    pub fn next1(&self) -> Grid {
+        self.next(Grid::count_neighbors1, Spot::next1)
     }
 
-    fn count_neighbors2(&self, pos: Position) -> usize {
-        self.count_neighbors2_right(pos)
-            + self.count_neighbors2_up_right(pos)
-            + self.count_neighbors2_up(pos)
-            + self.count_neighbors2_up_left(pos)
-            + self.count_neighbors2_left(pos)
-            + self.count_neighbors2_down_left(pos)
-            + self.count_neighbors2_down(pos)
-            + self.count_neighbors2_down_right(pos)
+    pub fn next2(&self) -> Grid {
+        self.next(Grid::count_neighbors2, Spot::next2)
     }
 
-    pub fn next1(&self, out: &mut Grid) {
-        assert_eq!(out.height, self.height);
-        assert_eq!(out.width, self.width);
-        assert!(self.height > 1);
-        assert!(self.width > 1);
-        out.spots.clear();
-        for row in 0..self.height {
-            for column in 0..self.width {
-                let pos = Position { row, column };
-                let old = self.at(pos);
-                if old == Spot::Floor {
-                    out.spots.push(old);
-                } else {
-                    let count = self.count_neighbors1(pos);
-                    out.spots.push(self.at(pos).next1(count));
-                }
-            }
-        }
+    /// Returns this grid and each of its successors under `next`, ending
+    /// with the first grid that `next` leaves unchanged.
+    pub fn generations(self, next: fn(&Grid) -> Grid) -> impl Iterator<Item = Grid> {
+        iter::successors(Some(self), move |grid| {
+            let new = next(grid);
+            (new != *grid).then_some(new)
+        })
     }
 
-    pub fn next2(&self, out: &mut Grid) {
-        assert_eq!(out.height, self.height);
-        assert_eq!(out.width, self.width);
-        assert!(self.height > 1);
-        assert!(self.width > 1);
-        out.spots.clear();
-        for row in 0..self.height {
-            for column in 0..self.width {
-                let pos = Position { row, column };
-                let old = self.at(pos);
-                if old == Spot::Floor {
-                    out.spots.push(old);
-                } else {
-                    let count = self.count_neighbors2(pos);
-                    out.spots.push(self.at(pos).next2(count));
-                }
-            }
-        }
+    pub fn spots(&self) -> &grid::Grid<Spot> {
+        &self.0
     }
 
     pub fn pop_count(&self) -> usize {
-        self.spots.iter().filter(|&&s| s == Spot::Occupied).count()
-    }
-
-    pub fn size(&self) -> Size {
-        Size {
-            height: self.height,
-            width: self.width,
-        }
+        self.0.values().filter(|&&s| s == Spot::Occupied).count()
     }
 }
 
 impl fmt::Display for Grid {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for row in 0..self.height {
-            for column in 0..self.width {
-                let pos = Position { row, column };
-                self.at(pos).fmt(f)?
-            }
-            writeln!(f)?
-        }
-        Ok(())
+        self.0.fmt(f)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs;
+
+    fn load_grid(input: &str) -> Grid {
+        Grid::parse(&fs::read_to_string(input).unwrap()).unwrap()
+    }
 
     #[test]
     fn count_neighbors2_sample2a() {
-        let grid = Grid::from_file("tests/day11/sample2a").unwrap();
-        let pos = Position { row: 4, column: 3 };
-        assert_eq!(1, grid.count_neighbors2_right(pos));
-        assert_eq!(1, grid.count_neighbors2_up_right(pos));
-        assert_eq!(1, grid.count_neighbors2_up(pos));
-        assert_eq!(1, grid.count_neighbors2_up_left(pos));
-        assert_eq!(1, grid.count_neighbors2_left(pos));
-        assert_eq!(1, grid.count_neighbors2_down_left(pos));
-        assert_eq!(1, grid.count_neighbors2_down(pos));
-        assert_eq!(1, grid.count_neighbors2_down_right(pos));
+        let grid = load_grid("tests/day11/sample2a");
+        let pos = Position::new(4, 3);
+        for offset in Offset::ADJACENT {
+            assert_eq!(1, grid.count_neighbors2_toward(pos, offset), "{:?}", offset);
+        }
         assert_eq!(8, grid.count_neighbors2(pos));
     }
 
     #[test]
     fn count_neighbors2_sample2b() {
-        let grid = Grid::from_file("tests/day11/sample2b").unwrap();
-        let pos = Position { row: 1, column: 1 };
-        assert_eq!(0, grid.count_neighbors2_right(pos));
-        assert_eq!(0, grid.count_neighbors2_up_right(pos));
-        assert_eq!(0, grid.count_neighbors2_up(pos));
-        assert_eq!(0, grid.count_neighbors2_up_left(pos));
-        assert_eq!(0, grid.count_neighbors2_left(pos));
-        assert_eq!(0, grid.count_neighbors2_down_left(pos));
-        assert_eq!(0, grid.count_neighbors2_down(pos));
-        assert_eq!(0, grid.count_neighbors2_down_right(pos));
+        let grid = load_grid("tests/day11/sample2b");
+        let pos = Position::new(1, 1);
+        for offset in Offset::ADJACENT {
+            assert_eq!(0, grid.count_neighbors2_toward(pos, offset), "{:?}", offset);
+        }
         assert_eq!(0, grid.count_neighbors2(pos));
     }
+
+    // Runs both rules to a fixed point, returning the final populations.
+    fn settle(text: &str) -> (usize, usize) {
+        let grid = Grid::parse(text).unwrap();
+        let mut grids = (grid.clone(), grid);
+        loop {
+            let next = (grids.0.next1(), grids.1.next2());
+            if next == grids {
+                return (grids.0.pop_count(), grids.1.pop_count());
+            }
+            grids = next;
+        }
+    }
+
+    #[test]
+    fn degenerate_grids() {
+        assert_eq!((0, 0), settle(""));
+        assert_eq!((1, 1), settle("L\n"));
+        assert_eq!((3, 3), settle("L.LL\n"));
+        assert_eq!((3, 3), settle("L\n.\nL\nL\n"));
+    }
 }
@@ -1,12 +1,42 @@
 mod grid;
-mod position;
-mod size;
 mod spot;
 
 pub mod part1;
 pub mod part2;
 
-use grid::Grid;
-use position::Position;
-use size::Size;
+use advent_common::visualize::Recorder;
+use advent_common::{Error, Solver};
 use spot::Spot;
+
+pub use grid::Grid;
+
+/// Records each round of seating under the rules of part 1, and then under
+/// those of part 2.
+pub fn visualize(grid: &Grid, recorder: &mut Recorder) -> Result<(), Error> {
+    for next in [Grid::next1, Grid::next2] {
+        for grid in grid.clone().generations(next) {
+            recorder.record(grid.spots())?;
+        }
+    }
+    Ok(())
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Puzzle<'a> = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Grid, Error> {
+        Grid::parse(text)
+    }
+
+    fn part1(grid: &Grid) -> Result<usize, Error> {
+        Ok(part1::solve(grid))
+    }
+
+    fn part2(grid: &Grid) -> Result<usize, Error> {
+        Ok(part2::solve(grid))
+    }
+}
@@ -1,30 +1,18 @@
 use super::Grid;
-use std::error::Error;
-use std::mem;
-use std::path::Path;
 
-pub fn solve<P>(input: P) -> Result<usize, Box<dyn Error>>
-where
-    P: AsRef<Path>,
-{
-    let mut old = Grid::from_file(input)?;
-    let mut new = Grid::with_size(old.size());
-    loop {
-        old.next2(&mut new);
-        if old == new {
-            return Ok(old.pop_count());
-        }
-        mem::swap(&mut old, &mut new);
-    }
+pub fn solve(grid: &Grid) -> usize {
+    let last = grid.clone().generations(Grid::next2).last();
+    last.map_or(0, |grid| grid.pop_count())
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs;
 
     #[test]
     fn solve_sample1() {
-        let input = "tests/day11/sample1";
-        assert_eq!(26, solve(input).unwrap());
+        let text = fs::read_to_string("tests/day11/sample1").unwrap();
+        assert_eq!(26, solve(&Grid::parse(&text).unwrap()));
     }
 }
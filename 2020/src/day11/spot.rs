@@ -1,4 +1,5 @@
-use crate::error::ParseError;
+use advent_common::visualize::{Draw, Rgb};
+use advent_common::Error;
 use std::fmt;
 
 /// The state of some position in a Grid.
@@ -10,12 +11,12 @@ pub enum Spot {
 }
 
 impl Spot {
-    fn from_char(c: char) -> Result<Spot, ParseError> {
+    pub fn from_char(c: char) -> Result<Spot, Error> {
         match c {
             '.' => Ok(Spot::Floor),
             'L' => Ok(Spot::Empty),
             '#' => Ok(Spot::Occupied),
-            _ => Err(ParseError::new(format!("{}: bad spot", c))),
+            _ => Err(Error::parse(format!("{}: bad spot", c))),
         }
     }
 
@@ -38,9 +39,23 @@ impl Spot {
             _ => *self,
         }
     }
+}
 
-    pub fn parse_line(line: &str) -> Result<Vec<Spot>, ParseError> {
-        line.chars().map(Spot::from_char).collect()
+impl Draw for Spot {
+    fn glyph(&self) -> char {
+        match self {
+            Spot::Floor => '.',
+            Spot::Empty => 'L',
+            Spot::Occupied => '#',
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        match self {
+            Spot::Floor => [32, 32, 32],
+            Spot::Empty => [64, 160, 64],
+            Spot::Occupied => [224, 96, 32],
+        }
     }
 }
 
@@ -10,7 +10,7 @@ impl Cardinal {
     fn from_isize(index: isize) -> Cardinal {
         use Cardinal::*;
         const CARDINALS: [Cardinal; 4] = [East, North, West, South];
-        CARDINALS[((index % 4 + 4) % 4) as usize]
+        CARDINALS[index.rem_euclid(4) as usize]
     }
 
     pub fn turn(self, degrees: isize) -> Cardinal {
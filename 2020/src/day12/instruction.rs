@@ -1,5 +1,6 @@
-use crate::error::ParseError;
+use advent_common::Error;
 
+#[derive(Clone, Copy, Debug)]
 pub enum Instruction {
     North { distance: usize },
     South { distance: usize },
@@ -11,21 +12,31 @@ pub enum Instruction {
 }
 
 impl Instruction {
-    pub fn parse(mut line: String) -> Result<Instruction, ParseError> {
-        if line.is_empty() {
-            return Err(ParseError::new("empty instruction"));
-        }
-        let action = line.as_bytes()[0];
-        let value: usize = line.split_off(1).parse()?;
+    pub fn parse(mut line: String) -> Result<Instruction, Error> {
+        let action = match line.chars().next() {
+            Some(action) => action,
+            None => return Err(Error::parse("empty instruction")),
+        };
+        let value: usize = line.split_off(action.len_utf8()).parse()?;
         Ok(match action {
-            b'N' => Instruction::North { distance: value },
-            b'E' => Instruction::East { distance: value },
-            b'S' => Instruction::South { distance: value },
-            b'W' => Instruction::West { distance: value },
-            b'L' => Instruction::Left { degrees: value },
-            b'R' => Instruction::Right { degrees: value },
-            b'F' => Instruction::Forward { distance: value },
-            _ => return Err(ParseError::new(format!("{}: bad action", action))),
+            'N' => Instruction::North { distance: value },
+            'E' => Instruction::East { distance: value },
+            'S' => Instruction::South { distance: value },
+            'W' => Instruction::West { distance: value },
+            'L' => Instruction::Left { degrees: value },
+            'R' => Instruction::Right { degrees: value },
+            'F' => Instruction::Forward { distance: value },
+            _ => return Err(Error::parse(format!("{}: bad action", action))),
         })
     }
+
+    /// Parses one instruction per line.
+    pub fn parse_all(text: &str) -> Result<Vec<Instruction>, Error> {
+        let mut instructions = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let instruction = Instruction::parse(line.to_owned());
+            instructions.push(instruction.map_err(|err| err.at_line(index + 1))?);
+        }
+        Ok(instructions)
+    }
 }
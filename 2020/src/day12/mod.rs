@@ -7,8 +7,30 @@ mod vector;
 pub mod part1;
 pub mod part2;
 
+use advent_common::{Error, Solver};
 use direction::Cardinal as CardinalDirection;
-use instruction::Instruction;
 use point::Point;
 use ship::Ship;
 use vector::Vector;
+
+pub use instruction::Instruction;
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    type Puzzle<'a> = Vec<Instruction>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<Instruction>, Error> {
+        Instruction::parse_all(text)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Result<usize, Error> {
+        Ok(part1::solve(instructions))
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Result<usize, Error> {
+        Ok(part2::solve(instructions))
+    }
+}
@@ -1,14 +1,10 @@
 use super::vector::{EAST, NORTH, SOUTH, WEST};
 use super::{Instruction, Ship};
-use std::error::Error;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
 
-pub fn solve<P: AsRef<Path>>(input: P) -> Result<usize, Box<dyn Error>> {
+pub fn solve(instructions: &[Instruction]) -> usize {
     let mut ship = Ship::new();
-    for line in BufReader::new(File::open(input)?).lines() {
-        ship = match Instruction::parse(line?)? {
+    for &instruction in instructions {
+        ship = match instruction {
             Instruction::North { distance } => ship.strafe(NORTH * distance as isize),
             Instruction::South { distance } => ship.strafe(SOUTH * distance as isize),
             Instruction::East { distance } => ship.strafe(EAST * distance as isize),
@@ -18,15 +14,18 @@ pub fn solve<P: AsRef<Path>>(input: P) -> Result<usize, Box<dyn Error>> {
             Instruction::Forward { distance } => ship.forward(distance),
         };
     }
-    Ok(ship.distance())
+    ship.distance()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs;
 
     #[test]
     fn sample1() {
-        assert_eq!(25, solve("tests/day12/sample1").unwrap());
+        let text = fs::read_to_string("tests/day12/sample1").unwrap();
+        let instructions = Instruction::parse_all(&text).unwrap();
+        assert_eq!(25, solve(&instructions));
     }
 }
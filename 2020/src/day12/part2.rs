@@ -1,16 +1,12 @@
 use super::vector::{EAST, NORTH, SOUTH, WEST};
 use super::Vector;
 use super::{Instruction, Ship};
-use std::error::Error;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
 
-pub fn solve<P: AsRef<Path>>(input: P) -> Result<usize, Box<dyn Error>> {
+pub fn solve(instructions: &[Instruction]) -> usize {
     let mut ship = Ship::new();
     let mut way = Vector { dx: 10, dy: 1 };
-    for line in BufReader::new(File::open(input)?).lines() {
-        match Instruction::parse(line?)? {
+    for &instruction in instructions {
+        match instruction {
             Instruction::North { distance } => way += NORTH * distance as isize,
             Instruction::South { distance } => way += SOUTH * distance as isize,
             Instruction::East { distance } => way += EAST * distance as isize,
@@ -22,15 +18,18 @@ pub fn solve<P: AsRef<Path>>(input: P) -> Result<usize, Box<dyn Error>> {
             }
         }
     }
-    Ok(ship.distance())
+    ship.distance()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs;
 
     #[test]
     fn sample1() {
-        assert_eq!(286, solve("tests/day12/sample1").unwrap());
+        let text = fs::read_to_string("tests/day12/sample1").unwrap();
+        let instructions = Instruction::parse_all(&text).unwrap();
+        assert_eq!(286, solve(&instructions));
     }
 }
@@ -1,2 +1,83 @@
 pub mod part1;
 pub mod part2;
+
+use advent_common::{Error, Solver};
+
+/// The earliest time we could depart, and the ID of the bus in each slot of
+/// the schedule, if any.
+#[derive(Debug)]
+pub struct Notes {
+    pub timestamp: usize,
+    pub buses: Vec<Option<usize>>,
+}
+
+fn parse_buses(line: &str) -> Result<Vec<Option<usize>>, Error> {
+    let mut buses = Vec::new();
+    for s in line.split(',') {
+        buses.push(match s {
+            "x" => None,
+            "0" => return Err(Error::parse("bus IDs must be positive")),
+            _ => Some(s.parse()?),
+        });
+    }
+    Ok(buses)
+}
+
+/// Parses the timestamp on the first line, and the schedule on the second.
+pub fn parse_notes(text: &str) -> Result<Notes, Error> {
+    let mut lines = text.lines();
+    let timestamp: usize = lines
+        .next()
+        .ok_or_else(|| Error::parse("empty input"))?
+        .parse()?;
+    let buses = lines
+        .next()
+        .ok_or_else(|| Error::parse("expected two lines, got only one"))?;
+    let buses = parse_buses(buses).map_err(|err| err.at_line(2))?;
+    if lines.next().is_some() {
+        Err(Error::parse("expected only two lines"))
+    } else if buses.iter().all(Option::is_none) {
+        Err(Error::parse("can't find any buses"))
+    } else {
+        Ok(Notes { timestamp, buses })
+    }
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Puzzle<'a> = Notes;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Notes, Error> {
+        parse_notes(text)
+    }
+
+    fn part1(notes: &Notes) -> Result<usize, Error> {
+        Ok(part1::solve(notes))
+    }
+
+    fn part2(notes: &Notes) -> Result<usize, Error> {
+        part2::solve(notes)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bad_notes() {
+        for &(text, want) in &[
+            ("", "empty input"),
+            ("939\n", "expected two lines, got only one"),
+            ("939\nx,x\n", "can't find any buses"),
+            ("939\n7,0\n", "bus IDs must be positive"),
+            ("939\n7,13\n\n", "expected only two lines"),
+        ] {
+            assert_eq!(want, parse_notes(text).unwrap_err().message(), "{:?}", text);
+        }
+        assert!(parse_notes("939\n7,y\n").is_err());
+    }
+}
@@ -1,18 +1,17 @@
 /// This file is a line-by-line translation of src/bin/day13_part2.py.  See
 /// that file for a high-level explanation of the algorithm implemented here.
-use crate::error::{NoSolution, ParseError};
-use std::error::Error;
-use std::fs;
+use super::Notes;
+use advent_common::Error;
 
 fn is_prime(n: usize) -> bool {
     if n < 2 {
         false
-    } else if n % 2 == 0 {
+    } else if n.is_multiple_of(2) {
         n == 2
     } else {
         let mut i = 3;
         while i * i <= n {
-            if n % i == 0 {
+            if n.is_multiple_of(i) {
                 return false;
             }
             i += 2;
@@ -33,37 +32,25 @@ struct BusRemainder {
     remainder: usize,
 }
 
-fn parse_line(line: &str) -> Vec<BusDelay> {
-    line.split(',')
+fn bus_delays(buses: &[Option<usize>]) -> Vec<BusDelay> {
+    buses
+        .iter()
         .enumerate()
-        .filter_map(|(d, s)| s.parse().ok().map(|id| BusDelay { id, delay: d }))
+        .filter_map(|(d, bus)| bus.map(|id| BusDelay { id, delay: d }))
         .collect()
 }
 
-fn load_input(input_path: &str) -> Result<Vec<BusDelay>, Box<dyn Error>> {
-    let text = fs::read_to_string(input_path)?;
-    let line = text
-        .split_terminator('\n')
-        .nth(1)
-        .ok_or_else(|| ParseError::in_file(input_path, "expected two lines"))?;
-    Ok(parse_line(line))
-}
-
 /// Returns an integer N such that multiplicand * N % bus_id == remainder.
-fn find_multiplier(
-    multiplicand: usize,
-    bus_id: usize,
-    remainder: usize,
-) -> Result<usize, NoSolution> {
+fn find_multiplier(multiplicand: usize, bus_id: usize, remainder: usize) -> Result<usize, Error> {
     for n in 0..bus_id {
         if multiplicand * n % bus_id == remainder {
             return Ok(n);
         }
     }
-    Err(NoSolution)
+    Err(Error::no_solution())
 }
 
-fn find_timestamp(bus_remainders: &[BusRemainder]) -> Result<usize, NoSolution> {
+fn find_timestamp(bus_remainders: &[BusRemainder]) -> Result<usize, Error> {
     let product: usize = bus_remainders.iter().map(|bus| bus.id).product();
     let mut sum = 0;
     for bus in bus_remainders {
@@ -75,7 +62,7 @@ fn find_timestamp(bus_remainders: &[BusRemainder]) -> Result<usize, NoSolution>
     Ok(sum % product)
 }
 
-fn solve_buses(buses: Vec<BusDelay>) -> Result<usize, NoSolution> {
+fn solve_buses(buses: Vec<BusDelay>) -> Result<usize, Error> {
     assert!(buses.iter().all(|&BusDelay { id, .. }| is_prime(id)));
     let bus_remainders: Vec<BusRemainder> = buses
         .iter()
@@ -91,13 +78,16 @@ fn solve_buses(buses: Vec<BusDelay>) -> Result<usize, NoSolution> {
     find_timestamp(&bus_remainders)
 }
 
-pub fn solve(input_path: &str) -> Result<usize, Box<dyn Error>> {
-    Ok(solve_buses(load_input(input_path)?)?)
+pub fn solve(notes: &Notes) -> Result<usize, Error> {
+    solve_buses(bus_delays(&notes.buses))
 }
 
 #[cfg(test)]
 mod test {
+    use super::super::{parse_buses, parse_notes};
     use super::*;
+    use advent_common::prop::{self, range, shuffle};
+    use std::fs;
 
     #[test]
     fn test_is_prime() {
@@ -127,8 +117,9 @@ mod test {
 
     #[test]
     fn solve() {
-        let input = load_input("tests/day13/sample1").unwrap();
-        assert_eq!(1068781, solve_buses(input).unwrap());
+        let text = fs::read_to_string("tests/day13/sample1").unwrap();
+        let notes = parse_notes(&text).unwrap();
+        assert_eq!(1068781, solve_buses(bus_delays(&notes.buses)).unwrap());
         for &(line, want) in &[
             ("17,x,13,19", 3417),
             ("67,7,59,61", 754018),
@@ -136,7 +127,30 @@ mod test {
             ("67,7,x,59,61", 1261476),
             ("1789,37,47,1889", 1202161486),
         ] {
-            assert_eq!(want, solve_buses(parse_line(line)).unwrap())
+            let buses = parse_buses(line).unwrap();
+            assert_eq!(want, solve_buses(bus_delays(&buses)).unwrap())
         }
     }
+
+    #[test]
+    fn solve_buses_matches_search() {
+        // Each bus gets a distinct prime ID, and departs some minutes after
+        // the previous one.
+        let ids = shuffle(vec![2, 3, 5, 7, 11, 13]);
+        let gaps = prop::vec(range(0usize..=3), 1..=5);
+        prop::check((ids, gaps), |(ids, gaps)| {
+            let buses: Vec<BusDelay> = gaps
+                .iter()
+                .scan(0, |delay, gap| {
+                    *delay += gap;
+                    Some(*delay)
+                })
+                .zip(ids)
+                .map(|(delay, &id)| BusDelay { id, delay })
+                .collect();
+            let product: usize = buses.iter().map(|bus| bus.id).product();
+            let want = (0..product).find(|t| buses.iter().all(|bus| (t + bus.delay) % bus.id == 0));
+            want == solve_buses(buses).ok()
+        });
+    }
 }
@@ -1,4 +1,4 @@
-use crate::error::ParseError;
+use advent_common::Error;
 use std::str::FromStr;
 
 pub const LEN: usize = 36;
@@ -20,13 +20,13 @@ impl From<Address> for usize {
 }
 
 impl FromStr for Address {
-    type Err = ParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Address, Self::Err> {
         let address: usize = s.parse()?;
         if MAX < address {
             let what = format!("{}: address is too large", address);
-            Err(ParseError::new(what))
+            Err(Error::parse(what))
         } else {
             Ok(Address(address))
         }
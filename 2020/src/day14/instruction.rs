@@ -1,39 +1,39 @@
 use super::address::Address;
 use super::mask::Mask;
 use super::value::Value;
-use crate::error::ParseError;
+use advent_common::Error;
 
 const PREFIX: &str = "mem[";
 
-fn parse_address(line: &str) -> Result<Address, ParseError> {
+fn parse_address(line: &str) -> Result<Address, Error> {
     if !line.starts_with(PREFIX) {
         let what = format!("{}: bad instruction: expected '{}'", line, PREFIX);
-        return Err(ParseError::new(what));
+        return Err(Error::parse(what));
     }
     let end = line.find(']').ok_or_else(|| {
         let what = format!("{}: bad instruction: missing ']'", line);
-        ParseError::new(what)
+        Error::parse(what)
     })?;
     line[PREFIX.len()..end].parse()
 }
 
-fn parse_value(line: &str) -> Result<Value, ParseError> {
+fn parse_value(line: &str) -> Result<Value, Error> {
     let mut parts = line.splitn(3, ' ').skip(1);
     let text = match (parts.next(), parts.next()) {
         (Some("="), Some(text)) => Ok(text),
-        _ => Err(ParseError::new(format!("{}: expected value", line))),
+        _ => Err(Error::parse(format!("{}: expected value", line))),
     }?;
     text.parse()
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub enum Instruction {
     Assign(Address, Value),
     Mask(Mask),
 }
 
 impl Instruction {
-    pub fn parse<S: AsRef<str>>(line: S) -> Result<Instruction, ParseError> {
+    pub fn parse<S: AsRef<str>>(line: S) -> Result<Instruction, Error> {
         let line = line.as_ref();
         if line.starts_with("mask") {
             Ok(Instruction::Mask(Mask::parse_line(line)?))
@@ -44,4 +44,10 @@ impl Instruction {
             ))
         }
     }
+    pub fn parse_all(text: &str) -> Result<Vec<Instruction>, Error> {
+        text.lines()
+            .enumerate()
+            .map(|(i, line)| Instruction::parse(line).map_err(|e| e.at_line(i + 1)))
+            .collect()
+    }
 }
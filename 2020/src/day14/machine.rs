@@ -1,17 +1,19 @@
 use super::instruction::Instruction;
 use super::mask::Mask;
-use super::memory::Sparse;
+use super::memory::Memory;
+use super::pattern::Pattern;
+use advent_common::Error;
 
-pub struct Machine {
+pub struct Machine<M> {
     mask: Mask,
-    memory: Sparse,
+    memory: M,
 }
 
-impl Machine {
-    pub fn new() -> Machine {
+impl<M: Memory> Machine<M> {
+    pub fn new() -> Machine<M> {
         Machine {
             mask: Mask::new(),
-            memory: Sparse::new(),
+            memory: M::default(),
         }
     }
 
@@ -19,7 +21,7 @@ impl Machine {
         match instruction {
             Instruction::Assign(address, value) => {
                 let masked = self.mask.value(value);
-                self.memory[address] = masked;
+                self.memory.write(Pattern::from(address), masked);
             }
             Instruction::Mask(mask) => self.mask = mask,
         }
@@ -28,15 +30,71 @@ impl Machine {
     pub fn execute2(&mut self, instruction: Instruction) {
         match instruction {
             Instruction::Assign(address, value) => {
-                for masked in self.mask.address(address) {
-                    self.memory[masked] = value;
-                }
+                self.memory.write(self.mask.address(address), value);
             }
             Instruction::Mask(mask) => self.mask = mask,
         }
     }
 
-    pub fn sum(&self) -> usize {
+    pub fn sum(&self) -> Result<usize, Error> {
         self.memory.sum()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::super::memory::{Sparse, Symbolic};
+    use super::*;
+    use advent_common::ErrorKind;
+    use std::fs;
+
+    fn run2<M: Memory>(text: &str) -> usize {
+        let mut machine = Machine::<M>::new();
+        for line in text.lines() {
+            machine.execute2(Instruction::parse(line).unwrap());
+        }
+        machine.sum().unwrap()
+    }
+
+    /// Clears the high 24 bits of each mask, leaving at most 12 floating
+    /// bits, so that every address written can be expanded.
+    fn narrow(text: &str) -> String {
+        let narrow = |line: &str| match line.strip_prefix("mask = ") {
+            Some(mask) => format!("mask = {}{}\n", "0".repeat(24), &mask[24..]),
+            None => format!("{}\n", line),
+        };
+        text.lines().map(narrow).collect()
+    }
+
+    #[test]
+    fn symbolic_matches_sparse() {
+        for path in &[
+            "tests/day14/sample1",
+            "tests/day14/sample2",
+            "tests/day14/input",
+        ] {
+            let text = narrow(&fs::read_to_string(path).unwrap());
+            assert_eq!(run2::<Sparse>(&text), run2::<Symbolic>(&text), "{}", path);
+        }
+    }
+
+    #[test]
+    fn symbolic_floats_every_bit() {
+        let text = "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX\nmem[8] = 3\n\
+                    mask = 0000000000000000000000000000000000XX\nmem[0] = 5\n";
+        assert_eq!(3 * ((1 << 36) - 4) + 5 * 4, run2::<Symbolic>(text));
+    }
+
+    #[test]
+    fn sum_too_large() {
+        let mut machine = Machine::<Symbolic>::new();
+        for line in &[
+            "mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX",
+            "mem[0] = 68719476735",
+        ] {
+            machine.execute2(Instruction::parse(line).unwrap());
+        }
+        let err = machine.sum().unwrap_err();
+        assert_eq!(Error::new(ErrorKind::Other, "sum is too large"), err);
+    }
+}
@@ -1,26 +1,26 @@
 use super::address::Address;
-use super::masked::Masked;
+use super::pattern::Pattern;
 use super::value::Value;
-use crate::error::ParseError;
+use advent_common::Error;
 
 const LEN: usize = 36;
 
-fn slice_text(line: &str) -> Result<&str, ParseError> {
+fn slice_text(line: &str) -> Result<&str, Error> {
     let mut parts = line.splitn(3, ' ');
     match (parts.next(), parts.next(), parts.next()) {
         (Some("mask"), Some("="), Some(text)) => Ok(text),
-        _ => Err(ParseError::new(format!("{}: expected mask", line))),
+        _ => Err(Error::parse(format!("{}: expected mask", line))),
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug)]
 pub struct Mask {
     clear: usize,
     set: usize,
 }
 
 impl Mask {
-    fn parse(text: &str) -> Result<Mask, ParseError> {
+    fn parse(text: &str) -> Result<Mask, Error> {
         let mut mask = Mask { clear: 0, set: 0 };
         for (i, b) in text.bytes().enumerate() {
             match b {
@@ -29,7 +29,7 @@ impl Mask {
                 b'1' => mask.set |= 1 << (LEN - i - 1),
                 _ => {
                     let what = format!("{}: bad mask bit", b);
-                    return Err(ParseError::new(what));
+                    return Err(Error::parse(what));
                 }
             };
         }
@@ -40,26 +40,25 @@ impl Mask {
         Mask { clear: 0, set: 0 }
     }
 
-    pub fn address(&self, address: Address) -> Masked {
+    /// Returns the pattern of addresses that a write to `address` writes.
+    pub fn address(&self, address: Address) -> Pattern {
         // A bit is floating if the mask neither clears nor sets it.
-        // The first address to yield has all floating bits zeroed.
         // Note that 0 in an address mask leaves bits unchanged.
         let float = !(self.clear | self.set) & ((1 << LEN) - 1);
-        let first = Address::from((usize::from(address) | self.set) & !float);
-        Masked::new(float, first)
+        Pattern::new(float, Address::from(usize::from(address) | self.set))
     }
 
     pub fn value(&self, value: Value) -> Value {
         Value::from(usize::from(value) & !self.clear | self.set)
     }
 
-    pub fn parse_line<S: AsRef<str>>(line: S) -> Result<Mask, ParseError> {
+    pub fn parse_line<S: AsRef<str>>(line: S) -> Result<Mask, Error> {
         let text = slice_text(line.as_ref())?;
         if text.len() == LEN {
             Mask::parse(text)
         } else {
             let what = format!("{}: bad mask: expected {} bits", text, LEN);
-            Err(ParseError::new(what))
+            Err(Error::parse(what))
         }
     }
 }
@@ -67,6 +66,7 @@ impl Mask {
 #[cfg(test)]
 mod test {
     use super::*;
+    use advent_common::fuzz;
 
     #[test]
     fn value() {
@@ -78,7 +78,14 @@ mod test {
     #[test]
     fn address() {
         let mask = Mask::parse("000000000000000000000000000000X1001X").unwrap();
-        let mut addresses = mask.address(Address::from(42));
+        let mut addresses = mask.address(Address::from(42)).addresses();
         assert_eq!(26, usize::from(addresses.next().unwrap()))
     }
+
+    #[test]
+    fn fuzz_parse_line() {
+        fuzz::parser("tests/day14", &[], |text| {
+            text.lines().map(Mask::parse_line).for_each(drop)
+        });
+    }
 }
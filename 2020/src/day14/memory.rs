@@ -1,39 +1,67 @@
 use super::address::Address;
+use super::pattern::Pattern;
 use super::value::Value;
+use advent_common::{Error, ErrorKind};
 use std::collections::HashMap;
-use std::ops::{Index, IndexMut};
 
+/// Memory that can be written through address patterns.
+pub trait Memory: Default {
+    /// Writes the value to every address matching the pattern.
+    fn write(&mut self, pattern: Pattern, value: Value);
+
+    /// Returns the sum of the values in memory, or an error if it doesn't
+    /// fit in a `usize`.
+    fn sum(&self) -> Result<usize, Error>;
+}
+
+/// Memory holding the value of each written address.
+#[derive(Default)]
 pub struct Sparse {
-    default: Value,
     values: HashMap<Address, Value>,
 }
 
-impl Sparse {
-    pub fn new() -> Sparse {
-        Sparse {
-            default: Value::default(),
-            values: HashMap::new(),
+impl Memory for Sparse {
+    fn write(&mut self, pattern: Pattern, value: Value) {
+        for address in pattern.addresses() {
+            self.values.insert(address, value);
         }
     }
 
-    pub fn sum(&self) -> usize {
-        self.values.values().map(|&v| usize::from(v)).sum()
+    fn sum(&self) -> Result<usize, Error> {
+        self.values
+            .values()
+            .try_fold(0, |sum: usize, &v| sum.checked_add(usize::from(v)))
+            .ok_or_else(|| Error::new(ErrorKind::Other, "sum is too large"))
     }
 }
 
-impl Index<Address> for Sparse {
-    type Output = Value;
+/// Memory holding the value of each write, along with the pattern of
+/// addresses it last wrote.  Writes through patterns having many floating
+/// bits take space and time proportional to the number of earlier writes,
+/// not to the number of addresses written.
+#[derive(Default)]
+pub struct Symbolic {
+    writes: Vec<(Pattern, Value)>, // disjoint patterns
+}
 
-    fn index(&self, address: Address) -> &Self::Output {
-        match self.values.get(&address) {
-            Some(value) => value,
-            None => &self.default,
-        }
+impl Memory for Symbolic {
+    fn write(&mut self, pattern: Pattern, value: Value) {
+        // Overwrite any earlier values in the pattern.
+        self.writes = self
+            .writes
+            .iter()
+            .flat_map(|&(old, value)| old.subtract(&pattern).into_iter().map(move |p| (p, value)))
+            .collect();
+        self.writes.push((pattern, value));
     }
-}
 
-impl IndexMut<Address> for Sparse {
-    fn index_mut(&mut self, address: Address) -> &mut Self::Output {
-        self.values.entry(address).or_insert(self.default)
+    fn sum(&self) -> Result<usize, Error> {
+        // A pattern may match 2^36 addresses, each holding a 36-bit value.
+        self.writes
+            .iter()
+            .try_fold(0, |sum: usize, (pattern, value)| {
+                sum.checked_add(pattern.count().checked_mul(usize::from(*value))?)
+            })
+            .ok_or_else(|| Error::new(ErrorKind::Other, "sum is too large"))
     }
 }
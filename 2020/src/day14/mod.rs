@@ -4,7 +4,32 @@ mod machine;
 mod mask;
 mod masked;
 mod memory;
+mod pattern;
 mod value;
 
 pub mod part1;
 pub mod part2;
+
+pub use instruction::Instruction;
+
+use advent_common::{Error, Solver};
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Puzzle<'a> = Vec<Instruction>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<Instruction>, Error> {
+        Instruction::parse_all(text)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Result<usize, Error> {
+        part1::solve(instructions)
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Result<usize, Error> {
+        part2::solve(instructions)
+    }
+}
@@ -1,28 +1,25 @@
 use super::instruction::Instruction;
 use super::machine::Machine;
-use std::error::Error;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
+use super::memory::Sparse;
+use advent_common::Error;
 
-pub fn solve<P>(input: P) -> Result<usize, Box<dyn Error>>
-where
-    P: AsRef<Path>,
-{
-    let mut machine = Machine::new();
-    for line in BufReader::new(File::open(input)?).lines() {
-        machine.execute1(Instruction::parse(line?)?);
+pub fn solve(instructions: &[Instruction]) -> Result<usize, Error> {
+    let mut machine = Machine::<Sparse>::new();
+    for &instruction in instructions {
+        machine.execute1(instruction);
     }
-    Ok(machine.sum())
+    machine.sum()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs;
 
     #[test]
     fn solve_sample1() {
-        let input = "tests/day14/sample1";
-        assert_eq!(165, solve(input).unwrap());
+        let text = fs::read_to_string("tests/day14/sample1").unwrap();
+        let instructions = Instruction::parse_all(&text).unwrap();
+        assert_eq!(165, solve(&instructions).unwrap());
     }
 }
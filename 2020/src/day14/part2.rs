@@ -1,28 +1,25 @@
 use super::instruction::Instruction;
 use super::machine::Machine;
-use std::error::Error;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
+use super::memory::Symbolic;
+use advent_common::Error;
 
-pub fn solve<P>(input: P) -> Result<usize, Box<dyn Error>>
-where
-    P: AsRef<Path>,
-{
-    let mut machine = Machine::new();
-    for line in BufReader::new(File::open(input)?).lines() {
-        machine.execute2(Instruction::parse(line?)?);
+pub fn solve(instructions: &[Instruction]) -> Result<usize, Error> {
+    let mut machine = Machine::<Symbolic>::new();
+    for &instruction in instructions {
+        machine.execute2(instruction);
     }
-    Ok(machine.sum())
+    machine.sum()
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
+    use std::fs;
 
     #[test]
     fn solve_sample2() {
-        let input = "tests/day14/sample2";
-        assert_eq!(208, solve(input).unwrap());
+        let text = fs::read_to_string("tests/day14/sample2").unwrap();
+        let instructions = Instruction::parse_all(&text).unwrap();
+        assert_eq!(208, solve(&instructions).unwrap());
     }
 }
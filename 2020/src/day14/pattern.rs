@@ -0,0 +1,117 @@
+use super::address::{Address, LEN};
+use super::masked::Masked;
+
+const ALL: usize = (1 << LEN) - 1;
+
+/// A set of addresses, written as a ternary string of bits that are each 0,
+/// 1, or floating (either).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Pattern {
+    float: usize, // bitmask of the floating bits
+    bits: usize,  // values of the other bits; floating bits are 0
+}
+
+impl Pattern {
+    pub fn new(float: usize, address: Address) -> Pattern {
+        let float = float & ALL;
+        Pattern {
+            float,
+            bits: usize::from(address) & !float,
+        }
+    }
+
+    /// Returns the number of addresses matching this pattern.
+    pub fn count(&self) -> usize {
+        1 << self.float.count_ones()
+    }
+
+    /// Returns every address matching this pattern.  There are exponentially
+    /// many in the number of floating bits.
+    pub fn addresses(&self) -> Masked {
+        Masked::new(self.float, Address::from(self.bits))
+    }
+
+    /// Returns whether any address matches both patterns.
+    pub fn intersects(&self, other: &Pattern) -> bool {
+        let fixed = !self.float & !other.float;
+        (self.bits ^ other.bits) & fixed == 0
+    }
+
+    /// Returns disjoint patterns together matching the addresses that match
+    /// this pattern but not `other`.
+    pub fn subtract(&self, other: &Pattern) -> Vec<Pattern> {
+        if !self.intersects(other) {
+            return vec![*self];
+        }
+        // Fix each bit that floats here but not in `other`, one at a time.
+        // With the bit set unlike in `other`, the pattern is disjoint from
+        // `other`, and with it set alike, it's narrowed toward `other`.
+        let mut pieces = Vec::new();
+        let mut rest = *self;
+        for i in 0..LEN {
+            let m = 1 << i;
+            if self.float & !other.float & m != 0 {
+                rest.float &= !m;
+                pieces.push(Pattern {
+                    float: rest.float,
+                    bits: rest.bits | !other.bits & m,
+                });
+                rest.bits |= other.bits & m;
+            }
+        }
+        pieces // What's left of `rest` lies within `other`.
+    }
+}
+
+impl From<Address> for Pattern {
+    fn from(address: Address) -> Pattern {
+        Pattern::new(0, address)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn parse(s: &str) -> Pattern {
+        let (mut float, mut bits) = (0, 0);
+        for c in s.chars() {
+            float = float << 1 | (c == 'X') as usize;
+            bits = bits << 1 | (c == '1') as usize;
+        }
+        Pattern::new(float, Address::from(bits))
+    }
+
+    fn addresses(patterns: &[Pattern]) -> HashSet<usize> {
+        patterns
+            .iter()
+            .flat_map(Pattern::addresses)
+            .map(usize::from)
+            .collect()
+    }
+
+    #[test]
+    fn subtract() {
+        let cases = [
+            ("X1X0X", "0XX01"), // overlapping
+            ("X1X0X", "1X1X0"),
+            ("XXXXX", "X0X1X"),
+            ("X1X0X", "XXX1X"), // disjoint
+            ("01X", "XXX"),     // contained
+            ("XXX", "XXX"),     // equal
+        ];
+        for &(a, b) in &cases {
+            let (a, b) = (parse(a), parse(b));
+            let pieces = a.subtract(&b);
+            let want: HashSet<_> = addresses(&[a])
+                .difference(&addresses(&[b]))
+                .copied()
+                .collect();
+            assert_eq!(want, addresses(&pieces), "{:?} - {:?}", a, b);
+            let total: usize = pieces.iter().map(Pattern::count).sum();
+            assert_eq!(want.len(), total, "pieces overlap");
+            assert_eq!(want.len() < a.count(), a.intersects(&b));
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::error::ParseError;
+use advent_common::Error;
 use std::str::FromStr;
 
 const MAX: usize = (1 << 36) - 1;
@@ -19,13 +19,13 @@ impl From<Value> for usize {
 }
 
 impl FromStr for Value {
-    type Err = ParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let value: usize = s.parse()?;
         if MAX < value {
             let what = format!("{}: value is too large", value);
-            Err(ParseError::new(what))
+            Err(Error::parse(what))
         } else {
             Ok(Value(value))
         }
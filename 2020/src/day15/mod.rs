@@ -2,3 +2,31 @@ mod game;
 
 pub mod part1;
 pub mod part2;
+
+use advent_common::{Error, Solver};
+use std::num::ParseIntError;
+
+/// Parses a comma-separated list of starting numbers.
+pub fn parse(text: &str) -> Result<Vec<usize>, ParseIntError> {
+    text.trim().split(',').map(str::parse).collect()
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Puzzle<'a> = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<usize>, Error> {
+        Ok(parse(text)?)
+    }
+
+    fn part1(numbers: &Vec<usize>) -> Result<usize, Error> {
+        Ok(part1::solve(numbers))
+    }
+
+    fn part2(numbers: &Vec<usize>) -> Result<usize, Error> {
+        Ok(part2::solve(numbers))
+    }
+}
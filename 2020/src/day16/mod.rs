@@ -0,0 +1,207 @@
+use advent_common::parse::{self, Parser};
+use advent_common::{Error, Solver};
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+
+fn parse_range(p: &mut Parser) -> Result<RangeInclusive<u64>, Error> {
+    let first = p.unsigned()?;
+    p.literal("-")?;
+    Ok(first..=p.unsigned()?)
+}
+
+#[derive(Debug, Eq, Hash, PartialEq)]
+struct Rule {
+    field: String,
+    ranges: (RangeInclusive<u64>, RangeInclusive<u64>),
+}
+
+impl Rule {
+    fn is_valid(&self, value: u64) -> bool {
+        self.ranges.0.contains(&value) || self.ranges.1.contains(&value)
+    }
+}
+
+impl Rule {
+    fn parse(p: &mut Parser) -> Result<Rule, Error> {
+        let field = p.take_while(|c| c != ':' && c != '\n').to_owned();
+        p.literal(": ")?;
+        let first = parse_range(p)?;
+        p.literal(" or ")?;
+        Ok(Rule {
+            field,
+            ranges: (first, parse_range(p)?),
+        })
+    }
+}
+
+#[derive(Debug)]
+struct Ticket {
+    values: Vec<u64>,
+}
+
+impl Ticket {
+    fn parse(p: &mut Parser) -> Result<Ticket, Error> {
+        let values = p.separated(",", Parser::unsigned)?;
+        Ok(Ticket { values })
+    }
+}
+
+#[derive(Debug)]
+pub struct Document {
+    rules: Vec<Rule>,
+    ticket: Ticket,       // your ticket
+    tickets: Vec<Ticket>, // nearby tickets
+}
+
+pub fn parse_document(text: &str) -> Result<Document, Error> {
+    parse::all(text, |p| {
+        let rules = p.lines(Rule::parse)?;
+        p.literal("\nyour ticket:\n")?;
+        let ticket = Ticket::parse(p)?;
+        p.end_of_line()?;
+        p.literal("\nnearby tickets:\n")?;
+        let tickets = p.lines(Ticket::parse)?;
+        Ok(Document {
+            rules,
+            ticket,
+            tickets,
+        })
+    })
+}
+
+pub fn solve_part1(doc: &Document) -> u64 {
+    doc.tickets
+        .iter()
+        .flat_map(|ticket| ticket.values.iter())
+        .filter(|&value| !doc.rules.iter().any(|rule| rule.is_valid(*value)))
+        .sum()
+}
+
+fn collect_valid_tickets(doc: &Document) -> impl Iterator<Item = &Ticket> {
+    doc.tickets.iter().filter(move |ticket| {
+        !ticket
+            .values
+            .iter()
+            .any(|value| doc.rules.iter().all(|rule| !rule.is_valid(*value)))
+    })
+}
+
+fn enumerate_values(ticket: &Ticket) -> impl Iterator<Item = (usize, &u64)> {
+    ticket.values.iter().enumerate()
+}
+
+/// Maps columns (by index) to sets of rules that rejected any values in them.
+fn exclude_rules_by_column(doc: &Document) -> Vec<HashSet<&Rule>> {
+    let mut excluded_rules = vec![HashSet::new(); doc.ticket.values.len()];
+    let tickets = collect_valid_tickets(doc);
+    for (column, &value) in tickets.flat_map(enumerate_values) {
+        let rules = doc.rules.iter().filter(|rule| !rule.is_valid(value));
+        excluded_rules[column].extend(rules);
+    }
+    excluded_rules
+}
+
+fn complement<'doc>(
+    sets: &[HashSet<&'doc Rule>],
+    universe: &'doc [Rule],
+) -> HashMap<usize, HashSet<&'doc Rule>> {
+    let universe = universe.iter().collect::<HashSet<_>>();
+    sets.iter().map(|set| &universe - set).enumerate().collect()
+}
+
+/// Maps each rule to its column index.
+fn map_columns(doc: &Document) -> Result<HashMap<&Rule, usize>, Error> {
+    // Map columns to sets of rules that cannot apply to them ("exclusions").
+    // Complement the sets to find applicable rules by column ("candidates").
+    // While candidates remain (i.e., any column is mapped to multiple rules):
+    //      Find the first column having exactly one candidate.
+    //          If none, return Error.
+    //      Graduate that entry to a match:
+    //          Map the rule to the column ("columns").
+    //          Remove the entry from the candidates map.
+    //      Remove the matched rule from all remaining candidate sets.
+    // Return the final mapping from rules to columns.
+    let exclusions = exclude_rules_by_column(doc);
+    let mut candidates = complement(&exclusions, &doc.rules);
+    let mut columns = HashMap::new();
+    while !candidates.is_empty() {
+        let (&column, rule) = candidates
+            .iter_mut()
+            .find_map(|(column, rules)| {
+                if rules.len() == 1 {
+                    rules.drain().next().map(|rule| (column, rule))
+                } else {
+                    None
+                }
+            })
+            .ok_or_else(Error::no_solution)?;
+        candidates.remove(&column);
+        columns.insert(rule, column);
+        for rules in candidates.values_mut() {
+            rules.remove(&rule);
+        }
+    }
+    Ok(columns)
+}
+
+pub fn solve_part2(doc: &Document) -> Result<u64, Error> {
+    Ok(map_columns(doc)?
+        .iter()
+        .filter_map(|(rule, &column)| {
+            if rule.field.starts_with("departure") {
+                doc.ticket.values.get(column)
+            } else {
+                None
+            }
+        })
+        .product())
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    type Puzzle<'a> = Document;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<Document, Error> {
+        parse_document(text)
+    }
+
+    fn part1(doc: &Document) -> Result<u64, Error> {
+        Ok(solve_part1(doc))
+    }
+
+    fn part2(doc: &Document) -> Result<u64, Error> {
+        solve_part2(doc)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    fn load_document(input: &str) -> Document {
+        parse_document(&fs::read_to_string(input).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn part1_sample1() {
+        let doc = load_document("tests/day16/sample1");
+        assert_eq!(71, solve_part1(&doc));
+    }
+
+    #[test]
+    fn part2_sample2() {
+        let doc = load_document("tests/day16/sample2");
+        assert_eq!(1, solve_part2(&doc).unwrap());
+    }
+
+    #[test]
+    fn parse_error_location() {
+        let text = "class: 1-3 or 5-7\nrow: 6-11 or 33+44\n";
+        let err = parse_document(text).unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(2), Some(16)));
+    }
+}
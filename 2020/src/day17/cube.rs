@@ -1,4 +1,5 @@
-use crate::error::ParseError;
+use advent_common::visualize::{Draw, Rgb};
+use advent_common::Error;
 use std::fmt::{self, Display, Formatter};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -8,25 +9,31 @@ pub enum Cube {
 }
 
 impl Cube {
-    pub fn parse(c: char) -> Result<Cube, ParseError> {
+    pub fn parse(c: char) -> Result<Cube, Error> {
         match c {
             '#' => Ok(Cube::Active),
             '.' => Ok(Cube::Inactive),
-            _ => Err(ParseError::new(format!("bad cube: {}", c))),
+            _ => Err(Error::parse(format!("bad cube: {}", c))),
         }
     }
 
     pub fn is_active(self) -> bool {
         self == Cube::Active
     }
+}
+
+impl Draw for Cube {
+    fn glyph(&self) -> char {
+        match self {
+            Cube::Active => '#',
+            Cube::Inactive => '.',
+        }
+    }
 
-    /// Returns the next state of a cube having this state currently, given n
-    /// active neighboring cubes.
-    pub fn next(&self, n: usize) -> Cube {
-        match (self, n) {
-            (Cube::Active, 2) => Cube::Active,
-            (_, 3) => Cube::Active,
-            (_, _) => Cube::Inactive,
+    fn color(&self) -> Rgb {
+        match self {
+            Cube::Active => [96, 224, 255],
+            Cube::Inactive => [16, 16, 48],
         }
     }
 }
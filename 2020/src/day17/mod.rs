@@ -1,8 +1,42 @@
 mod cube;
-mod grid3d;
-mod grid4d;
-mod point3d;
-mod point4d;
+mod pocket;
 
 pub mod part1;
 pub mod part2;
+
+use advent_common::visualize::Recorder;
+use advent_common::{Error, Solver};
+use pocket::Pocket;
+
+pub use pocket::{parse_slice, Slice};
+
+/// Records the z slices of the pocket over the six cycles of part 1.
+pub fn visualize(slice: &[[isize; 2]], recorder: &mut Recorder) -> Result<(), Error> {
+    let mut pocket = Pocket::<3>::from_slice(slice);
+    recorder.record(&pocket.slices())?;
+    for _ in 0..6 {
+        pocket = pocket.advance(1);
+        recorder.record(&pocket.slices())?;
+    }
+    Ok(())
+}
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    type Puzzle<'a> = Slice;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Slice, Error> {
+        parse_slice(text)
+    }
+
+    fn part1(slice: &Slice) -> Result<usize, Error> {
+        Ok(part1::solve(slice))
+    }
+
+    fn part2(slice: &Slice) -> Result<usize, Error> {
+        Ok(part2::solve(slice))
+    }
+}
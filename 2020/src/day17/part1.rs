@@ -1,8 +1,5 @@
-use super::grid3d::Grid3d;
-use std::error::Error;
-use std::fs;
+use super::pocket::Pocket;
 
-pub fn solve(input_path: &str) -> Result<usize, Box<dyn Error>> {
-    let grid: Grid3d = fs::read_to_string(input_path)?.parse()?;
-    Ok(grid.advance(6).population())
+pub fn solve(slice: &[[isize; 2]]) -> usize {
+    Pocket::<3>::from_slice(slice).advance(6).population()
 }
@@ -0,0 +1,188 @@
+use super::cube::Cube;
+use advent_common::automaton::{Automaton, Neighborhood, Point, Rule};
+use advent_common::grid::Grid;
+use advent_common::Error;
+use std::fmt::{self, Display, Formatter};
+use std::ops::Index;
+use std::str::FromStr;
+
+/// The active cubes of the initial slice of a pocket, as x and y coordinates.
+pub type Slice = Vec<[isize; 2]>;
+
+/// Parses the initial slice of a pocket.
+pub fn parse_slice(text: &str) -> Result<Slice, Error> {
+    let lines: Vec<_> = text
+        .lines()
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .collect();
+    if lines.is_empty() {
+        return Err(Error::parse("empty grid"));
+    }
+    let dx = lines[0].len();
+    if lines.iter().any(|s| s.len() != dx) {
+        return Err(Error::parse("jagged grid"));
+    }
+    let mut active = Vec::new();
+    for (y, line) in lines.iter().enumerate() {
+        for (x, cube) in line.chars().enumerate() {
+            if Cube::parse(cube)?.is_active() {
+                active.push([x as isize, y as isize]);
+            }
+        }
+    }
+    Ok(active)
+}
+
+/// An infinite set of cubes arranged contiguously in N-space.  Cubes follow
+/// the rules of Conway's Game of Life, with all 3^N - 1 surrounding cubes as
+/// neighbors.
+#[derive(Clone, Debug)]
+pub struct Pocket<const N: usize> {
+    cubes: Automaton<N>,
+}
+
+impl<const N: usize> Pocket<N> {
+    /// Creates a pocket from its initial slice, at 0 in all but the first two
+    /// dimensions, which are x and y.
+    pub fn from_slice(slice: &[[isize; 2]]) -> Pocket<N> {
+        let active = slice.iter().map(|&[x, y]| {
+            let mut point = [0; N];
+            point[0] = x;
+            point[1] = y;
+            point
+        });
+        Pocket {
+            cubes: Automaton::new(active, Neighborhood::Moore, Rule::life()),
+        }
+    }
+
+    #[cfg(test)]
+    fn count_neighbors(&self, point: Point<N>) -> usize {
+        self.cubes.count_neighbors(point)
+    }
+
+    /// Returns the number of active cubes in this pocket.
+    pub fn population(&self) -> usize {
+        self.cubes.population()
+    }
+
+    pub fn advance(self, time: usize) -> Pocket<N> {
+        Pocket {
+            cubes: self.cubes.advance(time),
+        }
+    }
+}
+
+impl<const N: usize> Index<Point<N>> for Pocket<N> {
+    type Output = Cube;
+
+    fn index(&self, point: Point<N>) -> &Self::Output {
+        if self.cubes.is_live(point) {
+            &Cube::Active
+        } else {
+            &Cube::Inactive
+        }
+    }
+}
+
+/// Parses the initial slice of the pocket.  See [`Pocket::from_slice`].
+impl<const N: usize> FromStr for Pocket<N> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Pocket::from_slice(&parse_slice(s)?))
+    }
+}
+
+impl Pocket<3> {
+    /// Returns the active region of this pocket as a single grid, with its
+    /// z slices laid out left to right and separated by blank columns.
+    pub fn slices(&self) -> Grid<Option<Cube>> {
+        let (min, max) = match self.cubes.bounds() {
+            Some(bounds) => bounds,
+            None => return Grid::new(0, 0, None),
+        };
+        let dx = (max[0] - min[0] + 1) as usize;
+        let dy = (max[1] - min[1] + 1) as usize;
+        let dz = (max[2] - min[2] + 1) as usize;
+        Grid::from_fn(dy, dz * (dx + 1) - 1, |pos| {
+            let (x, y, z) = (pos.column % (dx + 1), pos.row, pos.column / (dx + 1));
+            let point = [x as isize, y as isize, z as isize];
+            (x < dx).then(|| self[[min[0] + point[0], min[1] + point[1], min[2] + point[2]]])
+        })
+    }
+}
+
+impl Display for Pocket<3> {
+    #[rustfmt::skip]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.cubes.bounds() {
+            Some((min, max)) => write!(f, "{}",
+                ((min[2])..=max[2]).map(|z| {
+                ((min[1])..=max[1]).map(|y| {
+                ((min[0])..=max[0]).map(|x| {
+                    self[[x, y, z]].to_string()
+                }).collect::<Vec<_>>().join("")
+                }).collect::<Vec<_>>().join("\n")
+                }).collect::<Vec<_>>().join("\n\n")),
+            None => Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use advent_common::visualize::Draw;
+
+    fn sample1<const N: usize>() -> Pocket<N> {
+        ".#.
+         ..#
+         ###"
+        .parse()
+        .unwrap()
+    }
+
+    #[test]
+    fn count_neighbors() {
+        let pocket = sample1::<3>();
+        assert_eq!(1, pocket.count_neighbors([0, 0, -1]));
+        assert_eq!(2, pocket.count_neighbors([1, 0, -1]));
+        let pocket = sample1::<4>();
+        assert_eq!(1, pocket.count_neighbors([0, 0, -1, 0]));
+        assert_eq!(2, pocket.count_neighbors([1, 0, -1, 0]));
+    }
+
+    #[test]
+    fn read_after_write() {
+        assert_eq!(".#.\n..#\n###", sample1::<3>().to_string());
+    }
+
+    #[test]
+    fn outer_space() {
+        assert_eq!(Cube::Inactive, sample1::<3>()[[-1, 0, 0]]);
+        assert_eq!(Cube::Inactive, sample1::<4>()[[-1, 0, 0, 0]]);
+    }
+
+    #[test]
+    fn slices() {
+        let pocket = sample1::<3>().advance(1);
+        let text: Vec<String> = pocket
+            .slices()
+            .rows()
+            .map(|row| row.iter().map(Draw::glyph).collect())
+            .collect();
+        assert_eq!(text, ["#.. #.# #..", "..# .## ..#", ".#. .#. .#."]);
+    }
+
+    #[test]
+    fn simulate() {
+        assert_eq!(112, sample1::<3>().advance(6).population());
+    }
+
+    #[test]
+    fn simulate4d() {
+        assert_eq!(848, sample1::<4>().advance(6).population());
+    }
+}
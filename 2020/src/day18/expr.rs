@@ -0,0 +1,48 @@
+use super::op::Op;
+use advent_common::Error;
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+
+/// A parsed expression.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Expr {
+    Value(u64),
+    Prefix(Op, Box<Expr>),
+    Infix(Op, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    pub fn eval(&self) -> Result<i64, Error> {
+        match self {
+            Expr::Value(value) => Ok(i64::try_from(*value)?),
+            Expr::Prefix(op, operand) => op.apply_prefix(operand.eval()?),
+            Expr::Infix(op, left, right) => op.apply(left.eval()?, right.eval()?),
+        }
+    }
+
+    fn fmt_operand(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Expr::Value(_) => write!(f, "{}", self),
+            _ => write!(f, "({})", self),
+        }
+    }
+}
+
+/// Expressions display with every compound operand in parentheses, so that
+/// they parse back the same way whatever the precedence table.
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Expr::Value(value) => write!(f, "{}", value),
+            Expr::Prefix(op, operand) => {
+                write!(f, "{}", op)?;
+                operand.fmt_operand(f)
+            }
+            Expr::Infix(op, left, right) => {
+                left.fmt_operand(f)?;
+                write!(f, " {} ", op)?;
+                right.fmt_operand(f)
+            }
+        }
+    }
+}
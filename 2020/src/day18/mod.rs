@@ -1,5 +1,66 @@
-mod partial;
+//! The homework's arithmetic, with operator precedence made configurable.
+//!
+//! Expressions are tokenized, then parsed into an [`Expr`] by a Pratt parser
+//! whose operators, precedence, and associativity come from a [`Table`].
+//! Each part of the puzzle is just a different table.
+
+mod expr;
+mod op;
+mod parser;
+mod table;
 mod token;
 
 pub mod part1;
 pub mod part2;
+
+pub use expr::Expr;
+pub use op::Op;
+pub use parser::parse;
+pub use table::{Assoc, Table};
+pub use token::Token;
+
+use advent_common::{Error, ErrorKind, Solver};
+use std::convert::TryFrom;
+
+/// Splits each line of the homework into tokens.
+pub fn tokenize(text: &str) -> Result<Vec<Vec<Token>>, Error> {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| Token::parse_all(line).map_err(|err| err.at_line(index + 1)))
+        .collect()
+}
+
+/// Returns the sum of the expressions on each line of the homework, parsed
+/// using the given table.
+pub fn sum(homework: &[Vec<Token>], table: &Table) -> Result<u64, Error> {
+    let mut sum: i64 = 0;
+    for (index, tokens) in homework.iter().enumerate() {
+        let value = parse(tokens, table)
+            .and_then(|expr| expr.eval())
+            .map_err(|err| err.at_line(index + 1))?;
+        sum = sum
+            .checked_add(value)
+            .ok_or_else(|| Error::new(ErrorKind::Other, "sum is too large").at_line(index + 1))?;
+    }
+    u64::try_from(sum).map_err(|err| Error::new(ErrorKind::Other, "sum is negative").because(err))
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    type Puzzle<'a> = Vec<Vec<Token>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<Vec<Vec<Token>>, Error> {
+        tokenize(text)
+    }
+
+    fn part1(homework: &Vec<Vec<Token>>) -> Result<u64, Error> {
+        part1::solve(homework)
+    }
+
+    fn part2(homework: &Vec<Vec<Token>>) -> Result<u64, Error> {
+        part2::solve(homework)
+    }
+}
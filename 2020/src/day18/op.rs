@@ -0,0 +1,98 @@
+use advent_common::{Error, ErrorKind};
+use std::convert::TryFrom;
+use std::fmt::{self, Display, Formatter};
+
+/// An arithmetic operator.  Whether it's infix or prefix, and how tightly it
+/// binds, depends on the [`Table`](super::Table) used to parse it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Op {
+    Add, // +
+    Sub, // -
+    Mul, // *
+    Div, // /
+    Pow, // ^
+}
+
+impl Op {
+    pub fn symbol(self) -> char {
+        match self {
+            Op::Add => '+',
+            Op::Sub => '-',
+            Op::Mul => '*',
+            Op::Div => '/',
+            Op::Pow => '^',
+        }
+    }
+
+    /// Applies this operator to two operands.  Division truncates toward
+    /// zero, like Rust's.  Arithmetic errors, such as division by zero or
+    /// overflow, are of kind [`ErrorKind::Other`], since the input parsed.
+    pub fn apply(self, a: i64, b: i64) -> Result<i64, Error> {
+        let result = match self {
+            Op::Add => a.checked_add(b),
+            Op::Sub => a.checked_sub(b),
+            Op::Mul => a.checked_mul(b),
+            Op::Div if b == 0 => return Err(Error::new(ErrorKind::Other, "division by zero")),
+            Op::Div => a.checked_div(b),
+            Op::Pow => {
+                let b = u32::try_from(b)
+                    .map_err(|_| Error::new(ErrorKind::Other, format!("bad exponent: {}", b)))?;
+                a.checked_pow(b)
+            }
+        };
+        result.ok_or_else(|| too_large(format!("{} {} {}", a, self, b)))
+    }
+
+    /// Applies this operator to a single operand.  Only `+` and `-` have
+    /// prefix meanings.
+    pub fn apply_prefix(self, a: i64) -> Result<i64, Error> {
+        match self {
+            Op::Add => Ok(a),
+            Op::Sub => a.checked_neg().ok_or_else(|| too_large(format!("-{}", a))),
+            _ => Err(Error::parse(format!("{} is not a prefix operator", self))),
+        }
+    }
+}
+
+/// Returns an error reporting that the value of an expression overflows.
+fn too_large(expr: String) -> Error {
+    Error::new(ErrorKind::Other, format!("{} is too large", expr))
+}
+
+impl Display for Op {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn apply() {
+        assert_eq!(Ok(-3), Op::Sub.apply(4, 7));
+        assert_eq!(Ok(-2), Op::Div.apply(-7, 3));
+        assert_eq!(Ok(1024), Op::Pow.apply(2, 10));
+        assert_eq!(Ok(-5), Op::Sub.apply_prefix(5));
+        for err in [
+            Op::Div.apply(1, 0),
+            Op::Pow.apply(2, -1),
+            Op::Pow.apply(2, 64),
+            Op::Mul.apply(i64::MAX, 2),
+            Op::Div.apply(i64::MIN, -1),
+            Op::Sub.apply_prefix(i64::MIN),
+            Op::Mul.apply_prefix(3),
+        ] {
+            assert!(err.is_err(), "{:?}", err);
+        }
+        assert_eq!(
+            Err(Error::new(ErrorKind::Other, "division by zero")),
+            Op::Div.apply(1, 0)
+        );
+        assert_eq!(
+            ErrorKind::Other,
+            Op::Mul.apply(i64::MAX, 2).unwrap_err().kind()
+        );
+    }
+}
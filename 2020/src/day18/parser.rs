@@ -0,0 +1,170 @@
+use super::expr::Expr;
+use super::table::Table;
+use super::token::Token;
+use advent_common::Error;
+use std::iter::Peekable;
+use std::slice::Iter;
+
+/// A Pratt parser: each operator's binding powers, from the table, decide
+/// whether it takes the expression to its left as its operand, or is left
+/// for an operator that binds more tightly.
+struct Parser<'a> {
+    tokens: Peekable<Iter<'a, Token>>,
+    table: &'a Table,
+}
+
+impl<'a> Parser<'a> {
+    /// Parses a value, parenthesized expression, or prefix operator and its
+    /// operand.
+    fn operand(&mut self) -> Result<Expr, Error> {
+        match self.tokens.next() {
+            Some(&Token::Value(value)) => Ok(Expr::Value(value)),
+            Some(Token::Open) => {
+                let expr = self.expr(0)?;
+                match self.tokens.next() {
+                    Some(Token::Close) => Ok(expr),
+                    _ => Err(Error::parse("unclosed (")),
+                }
+            }
+            Some(&Token::Op(op)) => match self.table.prefix_power(op) {
+                Some(power) => Ok(Expr::Prefix(op, Box::new(self.expr(power)?))),
+                None => Err(Error::parse(format!("unexpected {}", op))),
+            },
+            Some(Token::Close) => Err(Error::parse("unexpected )")),
+            None => Err(Error::parse("expected a value")),
+        }
+    }
+
+    /// Parses an expression including only infix operators whose left
+    /// binding power is at least `min_power`.
+    fn expr(&mut self, min_power: usize) -> Result<Expr, Error> {
+        let mut left = self.operand()?;
+        loop {
+            let op = match self.tokens.peek() {
+                Some(&&Token::Op(op)) => op,
+                Some(Token::Close) | None => break,
+                Some(token) => return Err(Error::parse(format!("unexpected {}", token))),
+            };
+            let (left_power, right_power) = self
+                .table
+                .infix_power(op)
+                .ok_or_else(|| Error::parse(format!("unexpected {}", op)))?;
+            if left_power < min_power {
+                break;
+            }
+            self.tokens.next();
+            let right = self.expr(right_power)?;
+            left = Expr::Infix(op, Box::new(left), Box::new(right));
+        }
+        Ok(left)
+    }
+}
+
+/// Parses tokens into an expression, with operators as defined by a table.
+pub fn parse(tokens: &[Token], table: &Table) -> Result<Expr, Error> {
+    let mut parser = Parser {
+        tokens: tokens.iter().peekable(),
+        table,
+    };
+    let expr = parser.expr(0)?;
+    match parser.tokens.next() {
+        None => Ok(expr),
+        Some(token) => Err(Error::parse(format!("unexpected {}", token))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::op::Op;
+    use super::*;
+    use advent_common::fuzz;
+
+    fn parse_str(text: &str, table: &Table) -> Result<Expr, Error> {
+        parse(&Token::parse_all(text)?, table)
+    }
+
+    fn eval(text: &str) -> Result<i64, Error> {
+        parse_str(text, &Table::arithmetic())?.eval()
+    }
+
+    #[test]
+    fn precedence() {
+        for &(want, text) in &[
+            (14, "2 + 3 * 4"),
+            (20, "(2 + 3) * 4"),
+            (3, "8 - 3 - 2"),
+            (2, "12 / 3 / 2"),
+            (512, "2 ^ 3 ^ 2"),
+            (-4, "-2 ^ 2"),
+            (-6, "2 * -3"),
+            (3, "--3"),
+            (-1, "-3 + 2"),
+            (-5, "-(3 + 2)"),
+        ] {
+            assert_eq!(Ok(want), eval(text), "{}", text);
+        }
+    }
+
+    #[test]
+    fn display() {
+        let table = Table::arithmetic();
+        let flat =
+            Table::new()
+                .prefix(&[Op::Sub])
+                .right(&[Op::Add, Op::Sub, Op::Mul, Op::Div, Op::Pow]);
+        for &(want, text) in &[
+            ("(1 + (2 * 3)) - 4", "1 + 2 * 3 - 4"),
+            ("2 ^ (3 ^ 2)", "2^3^2"),
+            ("-(2 ^ 2)", "-2^2"),
+            ("-(-3)", "- -3"),
+            ("7", "((7))"),
+        ] {
+            let expr = parse_str(text, &table).unwrap();
+            assert_eq!(want, expr.to_string());
+            // Fully parenthesized, it parses the same with any table.
+            assert_eq!(Ok(expr), parse_str(want, &flat));
+        }
+    }
+
+    #[test]
+    fn errors() {
+        for &(want, text) in &[
+            ("expected a value", "1 +"),
+            ("expected a value", ""),
+            ("unclosed (", "(1 + 2"),
+            ("unexpected )", "1 + 2)"),
+            ("unexpected )", "1 + ()"),
+            ("unexpected 2", "1 2"),
+            ("unexpected (", "1 (2)"),
+            ("unexpected *", "* 2"),
+        ] {
+            let err = parse_str(text, &Table::arithmetic()).unwrap_err();
+            assert_eq!(want, err.message(), "{}", text);
+        }
+        // Operators not in the table are errors.
+        let table = Table::new().left(&[Op::Add, Op::Mul]);
+        assert!(parse_str("1 - 2", &table).is_err());
+        assert!(parse_str("-2", &table).is_err());
+        assert!(eval("1 / (2 - 2)").is_err());
+        assert!(eval("9223372036854775808").is_err());
+    }
+
+    #[test]
+    fn fuzz_parse() {
+        let tables = [
+            Table::arithmetic(),
+            Table::new().left(&[Op::Add, Op::Mul]),
+            Table::new().prefix(&[Op::Add, Op::Sub]).right(&[Op::Pow]),
+        ];
+        fuzz::parser("tests/day18", &["-2 ^ (3 / -1) * 4"], |text| {
+            for line in text.lines() {
+                for table in &tables {
+                    if let Ok(expr) = parse_str(line, table) {
+                        let _ = expr.eval();
+                        let _ = expr.to_string();
+                    }
+                }
+            }
+        });
+    }
+}
@@ -1,66 +1,41 @@
-use super::partial::Partial;
+use super::op::Op;
+use super::table::Table;
 use super::token::Token;
-use crate::error::ParseError;
+use advent_common::Error;
 
-fn eval_imp(tokens: &mut Vec<Token>) -> Result<u64, ParseError> {
-    let mut op: Option<Partial> = None;
-    while let Some(token) = tokens.pop() {
-        op = match token {
-            Token::Plus => Some(Partial::Plus(match op {
-                Some(Partial::Value(u)) => u,
-                _ => return Err(ParseError::new("unexpected +")),
-            })),
-            Token::Times => Some(Partial::Times(match op {
-                Some(Partial::Value(u)) => u,
-                _ => return Err(ParseError::new("unexpected *")),
-            })),
-            Token::Open => {
-                let v = eval_imp(tokens)?;
-                match op {
-                    Some(part) => Some(part.apply(v)?),
-                    None => Some(Partial::Value(v)),
-                }
-            }
-            Token::Close => break,
-            Token::Value(v) => match op {
-                Some(part) => Some(part.apply(v)?),
-                None => Some(Partial::Value(v)),
-            },
-        };
-    }
-    match op {
-        Some(Partial::Plus(_)) => Err(ParseError::new("unsatisified +")),
-        Some(Partial::Times(_)) => Err(ParseError::new("unsatisified *")),
-        Some(Partial::Value(u)) => Ok(u),
-        None => Err(ParseError::new("expected tokens")),
-    }
+/// Addition and multiplication have the same precedence, and are evaluated
+/// left to right.
+pub fn table() -> Table {
+    Table::new().left(&[Op::Add, Op::Mul])
 }
 
-fn eval(mut tokens: Vec<Token>) -> Result<u64, ParseError> {
-    tokens.reverse();
-    eval_imp(&mut tokens)
-}
-
-pub fn solve(text: &str) -> Result<u64, ParseError> {
-    let mut sum = 0;
-    for line in text.lines() {
-        sum += eval(Token::parse_all(line)?)?;
-    }
-    Ok(sum)
+pub fn solve(homework: &[Vec<Token>]) -> Result<u64, Error> {
+    super::sum(homework, &table())
 }
 
 #[cfg(test)]
 mod test {
+    use super::super::tokenize;
     use super::*;
 
+    fn solve_str(text: &str) -> Result<u64, Error> {
+        tokenize(text).and_then(|homework| solve(&homework))
+    }
+
     #[test]
     fn solve_sample1a() {
         // no parens
-        assert_eq!(71, solve("1 + 2 * 3 + 4 * 5 + 6").unwrap());
+        assert_eq!(71, solve_str("1 + 2 * 3 + 4 * 5 + 6").unwrap());
     }
 
     #[test]
     fn solve_sample1b() {
-        assert_eq!(51, solve("1 + (2 * 3) + (4 * (5 + 6))").unwrap());
+        assert_eq!(51, solve_str("1 + (2 * 3) + (4 * (5 + 6))").unwrap());
+    }
+
+    #[test]
+    fn solve_errors() {
+        assert!(solve_str("1 + 2\n3 - 4").is_err());
+        assert!(solve_str("1 +").is_err());
     }
 }
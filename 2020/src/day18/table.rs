@@ -0,0 +1,96 @@
+use super::op::Op;
+
+/// Which way a chain of operators at the same level groups: `1 - 2 - 3` is
+/// `(1 - 2) - 3` if subtraction is left associative.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Assoc {
+    Left,
+    Right,
+}
+
+/// The operators a parser accepts, and how tightly each binds.
+///
+/// A table is built from the loosest level of precedence to the tightest;
+/// each call to [`left`](Table::left), [`right`](Table::right), or
+/// [`prefix`](Table::prefix) adds a level binding tighter than the ones
+/// before.  For example, conventional arithmetic is:
+///
+/// ```text
+/// Table::new()
+///     .left(&[Op::Add, Op::Sub])
+///     .left(&[Op::Mul, Op::Div])
+///     .prefix(&[Op::Sub])
+///     .right(&[Op::Pow])
+/// ```
+///
+/// If an operator is added more than once in the same position, the last
+/// level wins.
+#[derive(Clone, Debug, Default)]
+pub struct Table {
+    infix: Vec<(Op, Assoc, usize)>,
+    prefix: Vec<(Op, usize)>,
+    levels: usize,
+}
+
+impl Table {
+    pub fn new() -> Table {
+        Table::default()
+    }
+
+    /// Returns the table for conventional arithmetic, shown above.
+    pub fn arithmetic() -> Table {
+        Table::new()
+            .left(&[Op::Add, Op::Sub])
+            .left(&[Op::Mul, Op::Div])
+            .prefix(&[Op::Sub])
+            .right(&[Op::Pow])
+    }
+
+    /// Adds a level of left associative infix operators.
+    pub fn left(self, ops: &[Op]) -> Table {
+        self.infix(Assoc::Left, ops)
+    }
+
+    /// Adds a level of right associative infix operators.
+    pub fn right(self, ops: &[Op]) -> Table {
+        self.infix(Assoc::Right, ops)
+    }
+
+    pub fn infix(mut self, assoc: Assoc, ops: &[Op]) -> Table {
+        self.levels += 1;
+        let level = self.levels;
+        self.infix.extend(ops.iter().map(|&op| (op, assoc, level)));
+        self
+    }
+
+    /// Adds a level of prefix operators.
+    pub fn prefix(mut self, ops: &[Op]) -> Table {
+        self.levels += 1;
+        let level = self.levels;
+        self.prefix.extend(ops.iter().map(|&op| (op, level)));
+        self
+    }
+
+    // A Pratt parser compares binding powers, rather than levels.  Each level
+    // has two powers, so that an operator can bind a little more tightly on
+    // one side than the other, making a chain of them group on that side.
+    // Operators on lower levels have lower powers than either.
+
+    /// Returns the left and right binding powers of an infix operator, or
+    /// None if it's not one.  The powers are always positive.
+    pub fn infix_power(&self, op: Op) -> Option<(usize, usize)> {
+        let &(_, assoc, level) = self.infix.iter().rfind(|entry| entry.0 == op)?;
+        Some(match assoc {
+            Assoc::Left => (2 * level, 2 * level + 1),
+            Assoc::Right => (2 * level, 2 * level),
+        })
+    }
+
+    /// Returns the right binding power of a prefix operator, or None if it's
+    /// not one.  The operand includes every infix operator on a higher level
+    /// only.
+    pub fn prefix_power(&self, op: Op) -> Option<usize> {
+        let &(_, level) = self.prefix.iter().rfind(|entry| entry.0 == op)?;
+        Some(2 * level + 1)
+    }
+}
@@ -1,47 +1,87 @@
-use crate::error::ParseError;
+use super::op::Op;
+use advent_common::Error;
+use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Token {
-    Plus,  // +
-    Times, // *
+    Op(Op),
     Open,  // (
     Close, // )
     Value(u64),
 }
 
 impl Token {
-    pub fn parse_all(expr: &str) -> Result<Vec<Token>, ParseError> {
+    /// Splits an expression into tokens.  Values are runs of digits; any
+    /// other character is a token by itself, unless it's whitespace.
+    pub fn parse_all(expr: &str) -> Result<Vec<Token>, Error> {
         let mut tokens = Vec::new();
-        let mut words: Vec<&str> = expr.split_whitespace().collect();
-        words.reverse();
-        while let Some(word) = words.pop() {
-            if word.len() > 1 && !word.chars().next().unwrap().is_numeric() {
-                let (first, second) = word.split_at(1);
-                words.push(second);
-                words.push(first);
-            } else if word.len() > 1 && !word.chars().nth(word.len() - 1).unwrap().is_numeric() {
-                let (first, second) = word.split_at(word.len() - 1);
-                words.push(second);
-                words.push(first);
+        let mut rest = expr.trim_start();
+        while let Some(c) = rest.chars().next() {
+            let len = if c.is_ascii_digit() {
+                rest.find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(rest.len())
             } else {
-                tokens.push(word.parse()?);
-            }
+                c.len_utf8()
+            };
+            tokens.push(rest[..len].parse()?);
+            rest = rest[len..].trim_start();
         }
         Ok(tokens)
     }
 }
 
 impl FromStr for Token {
-    type Err = ParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Token, Self::Err> {
         Ok(match s {
-            "+" => Token::Plus,
-            "*" => Token::Times,
+            "+" => Token::Op(Op::Add),
+            "-" => Token::Op(Op::Sub),
+            "*" => Token::Op(Op::Mul),
+            "/" => Token::Op(Op::Div),
+            "^" => Token::Op(Op::Pow),
             "(" => Token::Open,
             ")" => Token::Close,
-            _ => Token::Value(s.parse()?),
+            _ => match s.parse() {
+                Ok(value) => Token::Value(value),
+                Err(err) => return Err(Error::parse(format!("bad token: {}", s)).because(err)),
+            },
         })
     }
 }
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Token::Op(op) => write!(f, "{}", op),
+            Token::Open => write!(f, "("),
+            Token::Close => write!(f, ")"),
+            Token::Value(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use advent_common::fuzz;
+
+    #[test]
+    fn parse_all() {
+        let tokens = Token::parse_all("((2 + 40) * 3)").unwrap();
+        assert_eq!(9, tokens.len());
+        let tokens = Token::parse_all("-2^(3/-1)").unwrap();
+        let text: Vec<_> = tokens.iter().map(Token::to_string).collect();
+        assert_eq!(["-", "2", "^", "(", "3", "/", "-", "1", ")"], &text[..]);
+        assert!(Token::parse_all("(\u{1f384}").is_err());
+        assert!(Token::parse_all("1\u{e9}").is_err());
+    }
+
+    #[test]
+    fn fuzz_parse_all() {
+        fuzz::parser("tests/day18", &[], |text| {
+            text.lines().map(Token::parse_all).for_each(drop)
+        });
+    }
+}
@@ -0,0 +1,430 @@
+//! Message rules compiled to a grammar, matched by an Earley parser.
+//!
+//! An Earley parser reads a message from left to right, keeping, at each
+//! position, the set of partly matched branches that could continue there.
+//! Unlike matching each rule recursively, it terminates whatever the rules,
+//! including rules that refer to themselves at either end of a branch, and
+//! its work is polynomial in the length of the message.
+
+use super::rule::{Atom, Branch, RuleMap};
+use advent_common::Error;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Display, Formatter};
+
+#[derive(Debug)]
+enum Symbol {
+    Rule(usize), // a rule index, rather than id
+    Literal(String),
+}
+
+/// A partly matched branch: rule `rule`'s branch `branch`, which began
+/// matching at position `start`, with `dot` of its symbols matched so far.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Item {
+    rule: usize,
+    branch: usize,
+    dot: usize,
+    start: usize,
+}
+
+impl Item {
+    fn advance(self) -> Item {
+        Item {
+            dot: self.dot + 1,
+            ..self
+        }
+    }
+}
+
+/// The rules that matched, as `(rule, start, end)` triples, each meaning that
+/// the rule matched the bytes of a message from `start` up to `end`.
+type Spans = HashSet<(usize, usize, usize)>;
+
+/// How a message matched a rule: the branch that matched, and what matched
+/// each of its atoms.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Tree<'a> {
+    pub id: usize,
+    pub branch: usize,
+    pub children: Vec<Node<'a>>,
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Node<'a> {
+    Rule(Tree<'a>),
+    Literal(&'a str),
+}
+
+/// Trees display as nested lists of rule ids and literals, like
+/// `(0 (1 "a") (2 "b"))`.
+impl Display for Tree<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "({}", self.id)?;
+        for child in &self.children {
+            match child {
+                Node::Rule(tree) => write!(f, " {}", tree)?,
+                Node::Literal(literal) => write!(f, " {:?}", literal)?,
+            }
+        }
+        write!(f, ")")
+    }
+}
+
+/// A set of rules, compiled for matching messages against one of them.
+/// To parse each distinct message of a large set only once, match them
+/// through a [`Matcher`].
+#[derive(Debug)]
+pub struct Grammar {
+    ids: Vec<usize>,              // rule ids, by index
+    rules: Vec<Vec<Vec<Symbol>>>, // branches of symbols, by rule index
+    nullable: Vec<bool>,          // whether each rule matches ""
+    start: usize,
+}
+
+impl Grammar {
+    /// Compiles the rules, to match messages against the rule with id
+    /// `start`.
+    pub fn new(rules: &RuleMap, start: usize) -> Result<Grammar, Error> {
+        let mut ids: Vec<usize> = rules.iter().map(|(id, _)| id).collect();
+        ids.sort_unstable();
+        let index: HashMap<usize, usize> = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        let start = *index
+            .get(&start)
+            .ok_or_else(|| Error::parse(format!("can't find rule {}", start)))?;
+        let rules: Vec<Vec<Vec<Symbol>>> = ids
+            .iter()
+            .map(|&id| {
+                let branches = &rules.get(id).expect("rule ids are from the map").branches;
+                let symbols = |branch: &Branch| {
+                    let atoms = branch.0.iter();
+                    atoms
+                        .map(|atom| match atom {
+                            Atom::RuleId(id) => Symbol::Rule(index[id]),
+                            Atom::Literal(literal) => Symbol::Literal(literal.clone()),
+                        })
+                        .collect()
+                };
+                branches.iter().map(symbols).collect()
+            })
+            .collect();
+        let nullable = nullable(&rules);
+        Ok(Grammar {
+            ids,
+            rules,
+            nullable,
+            start,
+        })
+    }
+
+    /// Returns whether the message matches the start rule.
+    pub fn matches(&self, message: &str) -> bool {
+        self.is_match(message, &self.spans(message))
+    }
+
+    /// Returns how the message matches the start rule, or None if it doesn't.
+    /// If it matches in more than one way, returns any one of them.
+    pub fn parse(&self, message: &str) -> Option<Tree<'_>> {
+        self.tree(message, &self.spans(message))
+    }
+
+    /// Returns a matcher that remembers the messages it has parsed.
+    pub fn matcher(&self) -> Matcher<'_> {
+        Matcher {
+            grammar: self,
+            memo: HashMap::new(),
+        }
+    }
+
+    fn is_match(&self, message: &str, spans: &Spans) -> bool {
+        spans.contains(&(self.start, 0, message.len()))
+    }
+
+    fn tree(&self, message: &str, spans: &Spans) -> Option<Tree<'_>> {
+        let mut parser = TreeBuilder {
+            grammar: self,
+            message: message.as_bytes(),
+            spans,
+            path: HashSet::new(),
+        };
+        parser.tree(self.start, 0, message.len())
+    }
+
+    /// Returns every span of the message matched by a rule, in a way that
+    /// could be part of a match of the start rule.
+    fn spans(&self, message: &str) -> Spans {
+        let message = message.as_bytes();
+        let mut sets = vec![Vec::new(); message.len() + 1];
+        let mut seen = vec![HashSet::new(); message.len() + 1];
+        let mut add = |sets: &mut Vec<Vec<Item>>, i: usize, item: Item| {
+            if seen[i].insert(item) {
+                sets[i].push(item);
+            }
+        };
+        for branch in 0..self.rules[self.start].len() {
+            let item = Item {
+                rule: self.start,
+                branch,
+                dot: 0,
+                start: 0,
+            };
+            add(&mut sets, 0, item);
+        }
+
+        let mut spans = HashSet::new();
+        for i in 0..sets.len() {
+            let mut next = 0;
+            while let Some(&item) = sets[i].get(next) {
+                next += 1;
+                match self.rules[item.rule][item.branch].get(item.dot) {
+                    // Complete: advance every item that was waiting for
+                    // this rule to match, from where this item started.
+                    None => {
+                        spans.insert((item.rule, item.start, i));
+                        let mut waiting = 0;
+                        while let Some(&other) = sets[item.start].get(waiting) {
+                            waiting += 1;
+                            let symbols = &self.rules[other.rule][other.branch];
+                            if let Some(Symbol::Rule(rule)) = symbols.get(other.dot) {
+                                if *rule == item.rule {
+                                    add(&mut sets, i, other.advance());
+                                }
+                            }
+                        }
+                    }
+                    // Predict: start matching each branch of the rule here.
+                    // A rule that matches "" may already have completed here,
+                    // so also advance past it.
+                    Some(&Symbol::Rule(rule)) => {
+                        for branch in 0..self.rules[rule].len() {
+                            let start = i;
+                            let dot = 0;
+                            add(
+                                &mut sets,
+                                i,
+                                Item {
+                                    rule,
+                                    branch,
+                                    dot,
+                                    start,
+                                },
+                            );
+                        }
+                        if self.nullable[rule] {
+                            add(&mut sets, i, item.advance());
+                        }
+                    }
+                    // Scan: match the literal against the message.
+                    Some(Symbol::Literal(literal)) => {
+                        if message[i..].starts_with(literal.as_bytes()) {
+                            add(&mut sets, i + literal.len(), item.advance());
+                        }
+                    }
+                }
+            }
+        }
+        spans
+    }
+}
+
+/// Matches messages against a grammar, remembering the spans matched in each
+/// distinct message, so that a message repeated in a large set is parsed
+/// only once whether it's matched or turned into a tree.
+pub struct Matcher<'g> {
+    grammar: &'g Grammar,
+    memo: HashMap<String, Spans>,
+}
+
+impl<'g> Matcher<'g> {
+    /// Returns whether the message matches the grammar's start rule.
+    pub fn matches(&mut self, message: &str) -> bool {
+        let grammar = self.grammar;
+        grammar.is_match(message, self.spans(message))
+    }
+
+    /// Returns how the message matches the grammar's start rule, or None if
+    /// it doesn't.
+    pub fn parse(&mut self, message: &str) -> Option<Tree<'g>> {
+        let grammar = self.grammar;
+        grammar.tree(message, self.spans(message))
+    }
+
+    fn spans(&mut self, message: &str) -> &Spans {
+        if !self.memo.contains_key(message) {
+            let spans = self.grammar.spans(message);
+            self.memo.insert(message.to_owned(), spans);
+        }
+        &self.memo[message]
+    }
+}
+
+/// Returns whether each rule can match "".
+fn nullable(rules: &[Vec<Vec<Symbol>>]) -> Vec<bool> {
+    let mut nullable = vec![false; rules.len()];
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (rule, branches) in rules.iter().enumerate() {
+            if nullable[rule] {
+                continue;
+            }
+            nullable[rule] = branches.iter().any(|symbols| {
+                symbols.iter().all(|symbol| match symbol {
+                    Symbol::Rule(other) => nullable[*other],
+                    Symbol::Literal(literal) => literal.is_empty(),
+                })
+            });
+            changed |= nullable[rule];
+        }
+    }
+    nullable
+}
+
+/// Recovers a parse tree from the spans matched by each rule.
+struct TreeBuilder<'a, 'm> {
+    grammar: &'a Grammar,
+    message: &'m [u8],
+    spans: &'m Spans,
+    path: HashSet<(usize, usize, usize)>, // the spans being built
+}
+
+impl<'a> TreeBuilder<'a, '_> {
+    fn tree(&mut self, rule: usize, start: usize, end: usize) -> Option<Tree<'a>> {
+        // A rule can match a span by way of itself, such as by a branch
+        // `8: 8`, but no tree is smaller for doing so.
+        let span = (rule, start, end);
+        if !self.spans.contains(&span) || !self.path.insert(span) {
+            return None;
+        }
+        let grammar = self.grammar;
+        let tree = grammar.rules[rule]
+            .iter()
+            .enumerate()
+            .find_map(|(branch, symbols)| {
+                let children = self.children(symbols, start, end)?;
+                let id = grammar.ids[rule];
+                Some(Tree {
+                    id,
+                    branch,
+                    children,
+                })
+            });
+        self.path.remove(&span);
+        tree
+    }
+
+    /// Returns what matched each symbol, if the symbols together matched
+    /// the span from `start` to `end`.
+    fn children(
+        &mut self,
+        symbols: &'a [Symbol],
+        start: usize,
+        end: usize,
+    ) -> Option<Vec<Node<'a>>> {
+        let (first, rest) = match symbols.split_first() {
+            Some(split) => split,
+            None if start == end => return Some(Vec::new()),
+            None => return None,
+        };
+        match first {
+            Symbol::Literal(literal) => {
+                let mid = start + literal.len();
+                if mid > end || !self.message[start..].starts_with(literal.as_bytes()) {
+                    return None;
+                }
+                let mut children = self.children(rest, mid, end)?;
+                children.insert(0, Node::Literal(literal));
+                Some(children)
+            }
+            &Symbol::Rule(rule) => (start..=end).find_map(|mid| {
+                let tree = self.tree(rule, start, mid)?;
+                let mut children = self.children(rest, mid, end)?;
+                children.insert(0, Node::Rule(tree));
+                Some(children)
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    fn grammar(lines: &[&str]) -> Grammar {
+        let rules = lines.iter().map(|line| line.parse().unwrap()).collect();
+        Grammar::new(&RuleMap::new(rules).unwrap(), 0).unwrap()
+    }
+
+    #[test]
+    fn matches() {
+        let grammar = grammar(&["0: 1 2 | 2 1", "1: \"a\"", "2: \"b\""]);
+        assert!(grammar.matches("ab"));
+        assert!(grammar.matches("ba"));
+        assert!(!grammar.matches("aa"));
+        assert!(!grammar.matches("abb"));
+        assert!(!grammar.matches(""));
+    }
+
+    #[test]
+    fn matches_recursive() {
+        // Left and right recursion, and a rule matching "".
+        let grammar = grammar(&[
+            "0: 0 1 | 2",
+            "1: \"a\"",
+            "2: \"b\" 2 \"c\" | 3",
+            "3: \"bc\" | 3 3 | 4",
+            "4: ",
+        ]);
+        for message in &["", "a", "aa", "bca", "bbcbcc", "bbccaaa"] {
+            assert!(grammar.matches(message), "{}", message);
+        }
+        for message in &["b", "ab", "bbc", "cb"] {
+            assert!(!grammar.matches(message), "{}", message);
+        }
+    }
+
+    #[test]
+    fn parse() {
+        let grammar = grammar(&["0: 1 2 | 2 1", "1: \"a\" | \"a\" 1", "2: \"b\""]);
+        let tree = grammar.parse("aab").unwrap();
+        assert_eq!(r#"(0 (1 "a" (1 "a")) (2 "b"))"#, tree.to_string());
+        assert_eq!(0, tree.branch);
+        assert_eq!(None, grammar.parse("aba"));
+    }
+
+    #[test]
+    fn parse_sample() {
+        let text = fs::read_to_string("tests/day19/sample1").unwrap();
+        let mut lines = text.lines();
+        let rules = lines.by_ref().take_while(|line| !line.is_empty());
+        let rules = RuleMap::new(rules.map(|line| line.parse().unwrap()).collect());
+        let grammar = Grammar::new(&rules.unwrap(), 0).unwrap();
+        let want = concat!(
+            r#"(0 (4 "a") (1 (2 (4 "a") (4 "a")) (3 (4 "a") (5 "b")))"#,
+            r#" (5 "b"))"#
+        );
+        assert_eq!(want, grammar.parse("aaaabb").unwrap().to_string());
+        for message in lines {
+            let tree = grammar.parse(message);
+            assert_eq!(grammar.matches(message), tree.is_some(), "{}", message);
+        }
+    }
+
+    #[test]
+    fn matcher() {
+        let grammar = grammar(&["0: 1 2 | 2 1", "1: \"a\" | \"a\" 1", "2: \"b\""]);
+        let mut matcher = grammar.matcher();
+        for message in &["aab", "aba", "aab", "ba"] {
+            assert_eq!(grammar.matches(message), matcher.matches(message));
+            assert_eq!(grammar.parse(message), matcher.parse(message));
+        }
+        assert_eq!(3, matcher.memo.len());
+    }
+
+    #[test]
+    fn missing_start() {
+        let rules = RuleMap::new(vec!["1: \"a\"".parse().unwrap()]).unwrap();
+        let err = Grammar::new(&rules, 0).unwrap_err();
+        assert_eq!("can't find rule 0", err.message());
+    }
+}
@@ -1,4 +1,55 @@
+//! Message validation against a grammar of numbered rules.
+//!
+//! The rules are parsed, then compiled to a [`Grammar`], which matches
+//! messages with an Earley parser, and so handles any recursive rules,
+//! such as the ones part 2 swaps in.
+
+mod grammar;
 mod rule;
 
 pub mod part1;
 pub mod part2;
+
+pub use grammar::{Grammar, Matcher, Node, Tree};
+
+use advent_common::{Error, Solver};
+use rule::Rule;
+
+/// The rules, followed by a blank line and the received messages.
+pub struct Transmission<'a> {
+    rules: Vec<Rule>,
+    messages: Vec<&'a str>,
+}
+
+impl Transmission<'_> {
+    pub fn parse(text: &str) -> Result<Transmission<'_>, Error> {
+        let mut lines = text.lines().enumerate();
+        let rules = lines
+            .by_ref()
+            .take_while(|(_, line)| !line.is_empty())
+            .map(|(index, line)| line.parse().map_err(|err: Error| err.at_line(index + 1)))
+            .collect::<Result<_, _>>()?;
+        let messages = lines.map(|(_, line)| line).collect();
+        Ok(Transmission { rules, messages })
+    }
+}
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Puzzle<'a> = Transmission<'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Transmission<'_>, Error> {
+        Transmission::parse(text)
+    }
+
+    fn part1(transmission: &Transmission) -> Result<usize, Error> {
+        part1::solve(transmission)
+    }
+
+    fn part2(transmission: &Transmission) -> Result<usize, Error> {
+        part2::solve(transmission)
+    }
+}
@@ -1,42 +1,51 @@
-//! Each `count_bytes` function in this module tries to match its first
-//! parameter against a specified line, and returns a set of the number of
-//! bytes each match would consume.  For example, if a pattern can match a
-//! prefix of a given line in three different ways, consuming 2, 5, or 9 bytes
-//! respectively, then `pattern.count_bytes(line, rules)` returns a set of the
-//! values 2, 5, and 9.
-
-use crate::error::ParseError;
-use std::cell::RefCell;
-use std::collections::{HashMap, HashSet};
-use std::str::FromStr;
+//! The syntax of the message rules.  See [`Grammar`](super::grammar::Grammar)
+//! for matching messages against them.
 
-pub type RuleMap = HashMap<usize, Pattern>;
+use advent_common::Error;
+use std::collections::HashMap;
+use std::str::FromStr;
 
-#[derive(Debug)]
-enum Atom {
-    RuleId(usize),
-    Literal(String),
-}
+/// The patterns of a set of rules, by rule id.  Every rule id referenced by
+/// a pattern is known to have a pattern of its own.
+pub struct RuleMap(HashMap<usize, Pattern>);
 
-impl Atom {
-    fn count_bytes(&self, line: &str, rules: &RuleMap) -> HashSet<usize> {
-        match self {
-            Atom::RuleId(id) => {
-                let pattern = rules
-                    .get(id)
-                    .unwrap_or_else(|| panic!("can't find rule id {}", id));
-                pattern.count_bytes(line, rules)
-            }
-            Atom::Literal(prefix) if line.starts_with(prefix) => {
-                [prefix.len()].iter().cloned().collect()
+impl RuleMap {
+    pub fn new(rules: Vec<Rule>) -> Result<RuleMap, Error> {
+        let map: HashMap<usize, Pattern> = rules
+            .into_iter()
+            .map(|rule| (rule.id, rule.pattern))
+            .collect();
+        for (id, pattern) in map.iter() {
+            for atom in pattern.branches.iter().flat_map(|branch| branch.0.iter()) {
+                match atom {
+                    Atom::RuleId(other) if !map.contains_key(other) => {
+                        let what = format!("rule {} refers to missing rule {}", id, other);
+                        return Err(Error::parse(what));
+                    }
+                    _ => (),
+                }
             }
-            _ => HashSet::new(),
         }
+        Ok(RuleMap(map))
     }
+
+    pub fn get(&self, id: usize) -> Option<&Pattern> {
+        self.0.get(&id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &Pattern)> {
+        self.0.iter().map(|(&id, pattern)| (id, pattern))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Atom {
+    RuleId(usize),
+    Literal(String),
 }
 
 impl FromStr for Atom {
-    type Err = ParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.len() > 2 && s.starts_with('"') && s.ends_with('"') {
@@ -47,33 +56,12 @@ impl FromStr for Atom {
     }
 }
 
-fn count_bytes(atoms: &[Atom], line: &str, rules: &RuleMap) -> HashSet<usize> {
-    match atoms.len() {
-        0 => HashSet::new(),
-        1 => atoms[0].count_bytes(line, rules),
-        _ => {
-            let mut counts = HashSet::new();
-            for head_count in atoms[0].count_bytes(line, rules) {
-                for tail_count in count_bytes(&atoms[1..], &line[head_count..], rules) {
-                    counts.insert(head_count + tail_count);
-                }
-            }
-            counts
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Branch(Vec<Atom>);
-
-impl Branch {
-    fn count_bytes(&self, line: &str, rules: &RuleMap) -> HashSet<usize> {
-        count_bytes(&self.0, line, rules)
-    }
-}
+/// A sequence of atoms, all of which must match in order.
+#[derive(Clone, Debug)]
+pub struct Branch(pub Vec<Atom>);
 
 impl FromStr for Branch {
-    type Err = ParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let atoms = s
@@ -84,40 +72,13 @@ impl FromStr for Branch {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Pattern {
-    memo: RefCell<HashMap<String, HashSet<usize>>>,
-    branches: Vec<Branch>, // alternative sequences of atoms
-}
-
-impl Pattern {
-    fn count_bytes(&self, line: &str, rules: &RuleMap) -> HashSet<usize> {
-        if self.memo.borrow().contains_key(line) {
-            self.memo.borrow()[line].clone()
-        } else {
-            let counts = {
-                let mut counts = HashSet::new();
-                for branch in self.branches.iter() {
-                    counts.extend(branch.count_bytes(line, &rules));
-                }
-                counts
-            };
-            self.memo
-                .borrow_mut()
-                .insert(line.to_owned(), counts.clone());
-            counts
-        }
-    }
-
-    pub fn matches(&self, line: &str, rules: &RuleMap) -> bool {
-        self.count_bytes(line, rules)
-            .into_iter()
-            .any(|count| count == line.len())
-    }
+    pub branches: Vec<Branch>, // alternative sequences of atoms
 }
 
 impl FromStr for Pattern {
-    type Err = ParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let branches = s
@@ -125,20 +86,18 @@ impl FromStr for Pattern {
             .split(" | ")
             .map(|branch| branch.parse())
             .collect::<Result<_, _>>()?;
-        Ok(Pattern {
-            memo: RefCell::new(HashMap::new()),
-            branches,
-        })
+        Ok(Pattern { branches })
     }
 }
 
+#[derive(Clone)]
 pub struct Rule {
     pub id: usize,
     pub pattern: Pattern,
 }
 
 impl FromStr for Rule {
-    type Err = ParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let id_pattern = s.split(':').collect::<Vec<_>>();
@@ -146,9 +105,29 @@ impl FromStr for Rule {
             [id, pattern] => (id.parse()?, pattern.parse()?),
             _ => {
                 let what = format!("pattern needs colon: {}", s);
-                return Err(ParseError::new(what));
+                return Err(Error::parse(what));
             }
         };
         Ok(Rule { id, pattern })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rule_map(lines: &[&str]) -> Result<RuleMap, Error> {
+        let rules = lines
+            .iter()
+            .map(|line| line.parse())
+            .collect::<Result<_, _>>()?;
+        RuleMap::new(rules)
+    }
+
+    #[test]
+    fn missing_rule() {
+        let rules = rule_map(&["0: 1 2", "1: \"a\""]);
+        let err = rules.err().expect("rule 2 is missing");
+        assert_eq!(err.message(), "rule 0 refers to missing rule 2");
+    }
+}
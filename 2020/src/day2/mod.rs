@@ -0,0 +1,121 @@
+use advent_common::{Error, Solver};
+use std::ops::Range;
+use std::str::FromStr;
+
+// Policy
+
+#[derive(Debug)]
+struct Policy {
+    range: Range<u32>,
+    letter: char,
+}
+
+fn parse_letter(s: &str) -> Result<char, Error> {
+    match s.chars().collect::<Vec<char>>()[..] {
+        [c] => Ok(c),
+        _ => Err(Error::parse(format!(r#"bad letter: "{}""#, s))),
+    }
+}
+
+fn parse_range(s: &str) -> Result<Range<u32>, Error> {
+    match s.splitn(2, '-').collect::<Vec<&str>>()[..] {
+        [min, max] => Ok(Range {
+            start: min.parse()?,
+            end: max.parse::<u32>()? + 1,
+        }),
+        _ => Err(Error::parse(format!("bad range: {}", s))),
+    }
+}
+
+impl FromStr for Policy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.splitn(2, ' ').collect::<Vec<&str>>()[..] {
+            [range, letter] => Ok(Policy {
+                range: parse_range(range)?,
+                letter: parse_letter(letter)?,
+            }),
+            _ => Err(Error::parse(format!("bad policy: {}", s))),
+        }
+    }
+}
+
+// Entry
+
+#[derive(Debug)]
+pub struct Entry {
+    policy: Policy,
+    password: String,
+}
+
+impl FromStr for Entry {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.rsplitn(2, ": ").collect::<Vec<&str>>()[..] {
+            [password, policy] => Ok(Entry {
+                policy: policy.parse()?,
+                password: password.to_owned(),
+            }),
+            _ => Err(Error::parse(format!("bad entry: {}", s))),
+        }
+    }
+}
+
+// O(N) time, O(N) space
+pub fn parse_entries(text: &str) -> Result<Vec<Entry>, Error> {
+    let mut entries = Vec::new();
+    for line in text.lines() {
+        entries.push(line.parse()?);
+    }
+    Ok(entries)
+}
+
+// Part 1
+
+// O(M * N) time, O(1) space where M is the average string length
+pub fn solve_part1(entries: &[Entry]) -> u32 {
+    entries
+        .iter()
+        .filter(|Entry { policy, password }| {
+            let Policy { range, letter } = policy;
+            let count = password.chars().filter(|c| c == letter).count() as u32;
+            range.contains(&count)
+        })
+        .count() as u32
+}
+
+// Part 2
+
+// O(M * N) time, O(1) space where M is the average string length
+pub fn solve_part2(entries: &[Entry]) -> u32 {
+    entries
+        .iter()
+        .filter(|Entry { policy, password }| {
+            let Policy { range, letter } = policy;
+            let matches_at = |n| password.chars().nth(n as usize) == Some(*letter);
+            matches_at(range.start - 1) ^ matches_at(range.end - 2)
+        })
+        .count() as u32
+}
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    type Puzzle<'a> = Vec<Entry>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Vec<Entry>, Error> {
+        parse_entries(text)
+    }
+
+    fn part1(entries: &Vec<Entry>) -> Result<u32, Error> {
+        Ok(solve_part1(entries))
+    }
+
+    fn part2(entries: &Vec<Entry>) -> Result<u32, Error> {
+        Ok(solve_part2(entries))
+    }
+}
@@ -0,0 +1,214 @@
+use advent_common::Error;
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// A shape to search an image for, drawn in ASCII.  Each `#` is a pixel that
+/// must be `#` in the image; every other character matches anything.
+///
+/// ```text
+///                   #
+/// #    ##    ##    ###
+///  #  #  #  #  #  #
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pattern {
+    cells: Vec<(usize, usize)>, // row and column of each '#', sorted
+    height: usize,
+    width: usize,
+}
+
+impl Pattern {
+    fn new(mut cells: Vec<(usize, usize)>) -> Pattern {
+        cells.sort_unstable();
+        let height = cells.iter().map(|&(i, _)| i + 1).max().unwrap_or(0);
+        let width = cells.iter().map(|&(_, j)| j + 1).max().unwrap_or(0);
+        Pattern {
+            cells,
+            height,
+            width,
+        }
+    }
+
+    /// Returns the number of `#` pixels in the pattern.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    fn clockwise(&self) -> Pattern {
+        let h = self.height;
+        Pattern::new(self.cells.iter().map(|&(i, j)| (j, h - 1 - i)).collect())
+    }
+
+    fn mirrored(&self) -> Pattern {
+        let w = self.width;
+        Pattern::new(self.cells.iter().map(|&(i, j)| (i, w - 1 - j)).collect())
+    }
+
+    /// Returns every distinct rotation and reflection of the pattern: eight
+    /// of them, unless the pattern is symmetric.
+    pub fn orientations(&self) -> Vec<Pattern> {
+        let mut orientations: Vec<Pattern> = Vec::new();
+        for mut pattern in [self.clone(), self.mirrored()] {
+            for _ in 0..4 {
+                if !orientations.contains(&pattern) {
+                    orientations.push(pattern.clone());
+                }
+                pattern = pattern.clockwise();
+            }
+        }
+        orientations
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let cells: Vec<_> = s
+            .lines()
+            .enumerate()
+            .flat_map(|(i, line)| {
+                let columns = line.chars().enumerate();
+                columns.filter(|&(_, c)| c == '#').map(move |(j, _)| (i, j))
+            })
+            .collect();
+        if cells.is_empty() {
+            return Err(Error::parse("pattern has no '#'"));
+        }
+        // Leading blank lines and columns are part of no orientation.
+        let top = cells.iter().map(|&(i, _)| i).min().unwrap_or(0);
+        let left = cells.iter().map(|&(_, j)| j).min().unwrap_or(0);
+        let cells = cells.iter().map(|&(i, j)| (i - top, j - left)).collect();
+        Ok(Pattern::new(cells))
+    }
+}
+
+/// Where a pattern was found in an image: the pixels it covers, and the
+/// top left corner of the rectangle bounding them.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Match {
+    pub row: usize,
+    pub column: usize,
+    pub cells: Vec<(usize, usize)>,
+}
+
+/// A rectangular image of `#` and `.` pixels.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    rows: Vec<Vec<u8>>,
+}
+
+impl Image {
+    pub fn new(rows: Vec<Vec<u8>>) -> Image {
+        Image { rows }
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.rows.first().map_or(0, Vec::len)
+    }
+
+    fn is_set(&self, i: usize, j: usize) -> bool {
+        self.rows[i][j] == b'#'
+    }
+
+    /// Returns every place the pattern appears, in any orientation.  Matches
+    /// may overlap.
+    pub fn search(&self, pattern: &Pattern) -> Vec<Match> {
+        let mut matches = Vec::new();
+        for pattern in pattern.orientations() {
+            let rows = (self.height() + 1).saturating_sub(pattern.height);
+            let columns = (self.width() + 1).saturating_sub(pattern.width);
+            for row in 0..rows {
+                for column in 0..columns {
+                    let cells = pattern.cells.iter().map(|&(i, j)| (row + i, column + j));
+                    if cells.clone().all(|(i, j)| self.is_set(i, j)) {
+                        let cells = cells.collect();
+                        matches.push(Match { row, column, cells });
+                    }
+                }
+            }
+        }
+        matches
+    }
+
+    /// Returns the number of `#` pixels not covered by any of the matches.
+    pub fn roughness(&self, matches: &[Match]) -> usize {
+        let covered: HashSet<_> = matches.iter().flat_map(|m| m.cells.iter()).collect();
+        let set = (0..self.height())
+            .flat_map(|i| (0..self.width()).map(move |j| (i, j)))
+            .filter(|&(i, j)| self.is_set(i, j));
+        set.filter(|cell| !covered.contains(cell)).count()
+    }
+
+    /// Returns a copy of the image with the pixels covered by the matches
+    /// drawn as `O`.
+    pub fn highlight(&self, matches: &[Match]) -> Image {
+        let mut image = self.clone();
+        for &(i, j) in matches.iter().flat_map(|m| m.cells.iter()) {
+            image.rows[i][j] = b'O';
+        }
+        image
+    }
+}
+
+impl Display for Image {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for row in &self.rows {
+            writeln!(f, "{}", String::from_utf8_lossy(row))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn image(text: &str) -> Image {
+        let rows = text
+            .trim()
+            .lines()
+            .map(|line| line.trim().bytes().collect());
+        Image::new(rows.collect())
+    }
+
+    #[test]
+    fn orientations() {
+        let ell: Pattern = "#\n#\n##".parse().unwrap();
+        assert_eq!(8, ell.orientations().len());
+        let plus: Pattern = " #\n###\n #".parse().unwrap();
+        assert_eq!(1, plus.orientations().len());
+        let bar: Pattern = "\n  ###".parse().unwrap();
+        assert_eq!((1, 3), (bar.height, bar.width));
+        assert_eq!(2, bar.orientations().len());
+        assert!("...".parse::<Pattern>().is_err());
+    }
+
+    #[test]
+    fn search() {
+        let ell: Pattern = "#\n#\n##".parse().unwrap();
+        let image = image(
+            "
+            ....#
+            ##..#
+            #..##
+            #....",
+        );
+        let matches = image.search(&ell);
+        let corners: Vec<_> = matches.iter().map(|m| (m.row, m.column)).collect();
+        assert_eq!(vec![(0, 3), (1, 0)], corners);
+        assert_eq!(0, image.roughness(&matches));
+        let want = "....O\nOO..O\nO..OO\nO....\n";
+        assert_eq!(want, image.highlight(&matches).to_string());
+        assert_eq!(4, image.roughness(&matches[..1]));
+    }
+}
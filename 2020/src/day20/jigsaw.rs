@@ -0,0 +1,251 @@
+use super::image::Image;
+use super::neighbor::NeighborSet;
+use super::tile::{Projection, Tile};
+use advent_common::Error;
+use std::collections::{HashMap, HashSet};
+
+/// Where a tile goes in the assembled image, and how it's turned.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Placement {
+    pub tile_id: u64,
+    pub row: usize,
+    pub column: usize,
+    pub turns: u8,     // clockwise quarter turns
+    pub flipped: bool, // mirrored left to right, before turning
+}
+
+/// Tiles arranged in a square, so that the edges of adjacent tiles match.
+pub struct Jigsaw {
+    side: usize,             // the edge length of the square, in tiles
+    pieces: Vec<Projection>, // in row major order
+}
+
+impl Jigsaw {
+    /// Arranges the tiles, which must all be the same size, and be a square
+    /// number of them.
+    pub fn assemble(tiles: &[Tile]) -> Result<Jigsaw, Error> {
+        let side = (1..)
+            .find(|side| side * side >= tiles.len())
+            .filter(|side| side * side == tiles.len())
+            .ok_or_else(|| Error::parse(format!("{} tiles can't make a square", tiles.len())))?;
+        if tiles.iter().any(|tile| tile.side() != tiles[0].side()) {
+            return Err(Error::parse("tiles differ in size"));
+        }
+        let projections = Projection::collect(tiles);
+        let mut assembler = Assembler {
+            neighbors: NeighborSet::graph(&projections),
+            side,
+            image: Vec::new(),
+            used: HashSet::new(),
+        };
+        if !assembler.recur() {
+            return Err(Error::no_solution());
+        }
+        let pieces = assembler.image.into_iter().cloned().collect();
+        Ok(Jigsaw { side, pieces })
+    }
+
+    /// Returns the number of tiles along each edge of the square.
+    pub fn side(&self) -> usize {
+        self.side
+    }
+
+    /// Returns where each tile goes, in row major order.
+    pub fn placements(&self) -> Vec<Placement> {
+        let side = self.side;
+        let pieces = self.pieces.iter().enumerate();
+        pieces
+            .map(|(index, piece)| Placement {
+                tile_id: piece.tile_id,
+                row: index / side,
+                column: index % side,
+                turns: piece.turns,
+                flipped: piece.flipped,
+            })
+            .collect()
+    }
+
+    /// Returns the product of the IDs of the corner tiles.
+    pub fn corner_id_product(&self) -> u64 {
+        let m = self.side - 1;
+        [(0, 0), (0, m), (m, 0), (m, m)]
+            .iter()
+            .map(|(i, j)| self.pieces[i * self.side + j].tile_id)
+            .product()
+    }
+
+    /// Returns the image made by joining the tiles with their borders
+    /// removed.
+    pub fn image(&self) -> Image {
+        let interior = self.pieces[0].interior.len();
+        let mut rows: Vec<Vec<u8>> = vec![Vec::new(); self.side * interior];
+        for (index, piece) in self.pieces.iter().enumerate() {
+            let i = index / self.side;
+            for (k, line) in piece.interior.iter().enumerate() {
+                rows[i * interior + k].extend(line);
+            }
+        }
+        Image::new(rows)
+    }
+}
+
+/// Places tiles one at a time, left to right and top to bottom, backtracking
+/// whenever no unused tile fits.
+struct Assembler<'a> {
+    neighbors: HashMap<&'a Projection, NeighborSet<'a>>,
+    side: usize,
+    image: Vec<&'a Projection>,
+    used: HashSet<u64>, // tile IDs
+}
+
+impl<'a> Assembler<'a> {
+    /// Returns the unused projections whose left edge matches the right edge
+    /// of the tile to the left, if any, and whose top edge matches the bottom
+    /// edge of the tile above, if any.
+    fn candidates(&self) -> Vec<&'a Projection> {
+        let (side, len) = (self.side, self.image.len());
+        let (i, j) = (len / side, len % side);
+        let left = (j > 0).then(|| self.image[len - 1]);
+        let above = (i > 0).then(|| self.image[len - side]);
+        let v: Vec<&'a Projection> = match (left, above) {
+            (Some(left), Some(above)) => self.neighbors[left]
+                .rights
+                .iter()
+                .cloned()
+                .filter(|q| q.top == above.bottom)
+                .collect(),
+            (Some(left), None) => self.neighbors[left].rights.clone(),
+            (None, Some(above)) => self.neighbors[above].downs.clone(),
+            (None, None) => self.neighbors.keys().cloned().collect(),
+        };
+        v.into_iter()
+            .filter(|q| !self.used.contains(&q.tile_id))
+            .collect()
+    }
+
+    fn recur(&mut self) -> bool {
+        if self.image.len() == self.side * self.side {
+            return true;
+        }
+        for p in self.candidates() {
+            self.image.push(p);
+            self.used.insert(p.tile_id);
+            if self.recur() {
+                return true;
+            }
+            self.used.remove(&p.tile_id);
+            self.image.pop();
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use advent_common::prop::Rng;
+    use std::fs;
+
+    fn sample() -> Jigsaw {
+        let text = fs::read_to_string("tests/day20/sample1").unwrap();
+        Jigsaw::assemble(&Tile::parse_all(&text).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn placements() {
+        let jigsaw = sample();
+        let placements = jigsaw.placements();
+        assert_eq!(9, placements.len());
+        let corners: HashSet<_> = placements
+            .iter()
+            .filter(|p| p.row != 1 && p.column != 1)
+            .map(|p| p.tile_id)
+            .collect();
+        let want: HashSet<u64> = [1951, 3079, 2971, 1171].iter().copied().collect();
+        assert_eq!(want, corners);
+        let center = placements.iter().find(|p| (p.row, p.column) == (1, 1));
+        assert_eq!(1427, center.unwrap().tile_id);
+        let image = jigsaw.image();
+        assert_eq!((24, 24), (image.height(), image.width()));
+    }
+
+    /// Cuts a random picture into tiles of any size, overlapping by a pixel
+    /// at each edge, as the puzzle's tiles do.
+    fn cut(rng: &mut Rng, side: usize, tile_side: usize) -> Vec<Tile> {
+        let size = side * (tile_side - 1) + 1;
+        let picture: Vec<Vec<char>> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.next_u64() & 1 == 0 { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        let mut tiles = Vec::new();
+        for i in 0..side {
+            for j in 0..side {
+                let (top, left) = (i * (tile_side - 1), j * (tile_side - 1));
+                let mut text = format!("Tile {}:\n", tiles.len() + 1);
+                for row in &picture[top..(top + tile_side)] {
+                    text.extend(&row[left..(left + tile_side)]);
+                    text.push('\n');
+                }
+                tiles.push(text.parse().unwrap());
+            }
+        }
+        tiles
+    }
+
+    /// Asserts that every pair of adjacent pieces shares an edge.
+    fn assert_seams_match(jigsaw: &Jigsaw) {
+        let side = jigsaw.side;
+        for (index, piece) in jigsaw.pieces.iter().enumerate() {
+            let (i, j) = (index / side, index % side);
+            if j + 1 < side {
+                assert_eq!(piece.right, jigsaw.pieces[index + 1].left);
+            }
+            if i + 1 < side {
+                assert_eq!(piece.bottom, jigsaw.pieces[index + side].top);
+            }
+        }
+    }
+
+    #[test]
+    fn assemble_any_size() {
+        let mut rng = Rng::new(20);
+        for &(side, tile_side) in &[(1, 2), (2, 3), (3, 5), (4, 12)] {
+            let tiles = cut(&mut rng, side, tile_side);
+            let jigsaw = Jigsaw::assemble(&tiles).unwrap();
+            assert_eq!(side, jigsaw.side());
+            assert_seams_match(&jigsaw);
+            let image = jigsaw.image();
+            assert_eq!(side * (tile_side - 2), image.height());
+            assert_eq!(side * (tile_side - 2), image.width());
+        }
+    }
+
+    #[test]
+    fn assemble_errors() {
+        let mut rng = Rng::new(20);
+        let mut tiles = cut(&mut rng, 2, 4);
+        let err = Jigsaw::assemble(&tiles[..3]).err().unwrap();
+        assert_eq!("3 tiles can't make a square", err.message());
+        tiles[3] = cut(&mut rng, 1, 5).remove(0);
+        let err = Jigsaw::assemble(&tiles).err().unwrap();
+        assert_eq!("tiles differ in size", err.message());
+    }
+
+    #[test]
+    fn assemble_unsolvable() {
+        // Tile 2's blank edges are opposite each other, but a corner tile's
+        // inner edges are adjacent, so one of its .##. edges must face a
+        // neighbor, and no other tile has such an edge.
+        let text = "\
+            Tile 1:\n....\n....\n....\n....\n\n\
+            Tile 2:\n.##.\n....\n....\n.##.\n\n\
+            Tile 3:\n....\n....\n....\n....\n\n\
+            Tile 4:\n.#..\n....\n....\n....\n";
+        let tiles = Tile::parse_all(text).unwrap();
+        let err = Jigsaw::assemble(&tiles).err().unwrap();
+        assert_eq!(Error::no_solution(), err);
+    }
+}
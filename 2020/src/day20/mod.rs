@@ -1,6 +1,41 @@
+//! Assembling an image from square tiles, and searching it for patterns.
+//!
+//! A [`Jigsaw`] arranges tiles of any size so that their edges match,
+//! turning and flipping them as needed, and reports where each one went.
+//! Joined, the tiles' interiors make an [`Image`], which can be searched
+//! for an ASCII [`Pattern`] in every orientation.
+
+mod image;
+mod jigsaw;
 mod neighbor;
 mod tile;
 mod rotate;
 
 pub mod part1;
 pub mod part2;
+
+pub use image::{Image, Match, Pattern};
+pub use jigsaw::{Jigsaw, Placement};
+pub use tile::Tile;
+
+use advent_common::{Error, Solver};
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Puzzle<'a> = Vec<Tile>;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<Tile>, Error> {
+        Tile::parse_all(text)
+    }
+
+    fn part1(tiles: &Vec<Tile>) -> Result<u64, Error> {
+        part1::solve(tiles)
+    }
+
+    fn part2(tiles: &Vec<Tile>) -> Result<usize, Error> {
+        part2::solve(tiles)
+    }
+}
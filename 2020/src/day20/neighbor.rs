@@ -8,7 +8,7 @@ pub struct NeighborSet<'a> {
 }
 
 impl NeighborSet<'_> {
-    pub fn graph(projections: &[Projection]) -> HashMap<&Projection, NeighborSet> {
+    pub fn graph(projections: &[Projection]) -> HashMap<&Projection, NeighborSet<'_>> {
         let mut neighbors = HashMap::new();
         for p in projections {
             let rights = projections.iter().filter(|q| p.right == q.left).collect();
@@ -1,7 +1,7 @@
 mod projection;
 
 use super::rotate;
-use crate::error::ParseError;
+use advent_common::Error;
 use std::str::FromStr;
 
 pub use projection::Projection;
@@ -10,7 +10,7 @@ fn collect_column(lines: &[&str], n: usize) -> String {
     lines.iter().flat_map(|line| line.chars().nth(n)).collect()
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub struct Tile {
     id: u64,
     top: String,
@@ -21,32 +21,47 @@ pub struct Tile {
 }
 
 impl Tile {
-    pub fn parse_all(text: &str) -> Result<Vec<Tile>, ParseError> {
+    pub fn parse_all(text: &str) -> Result<Vec<Tile>, Error> {
         let mut tiles = Vec::new();
         for paragraph in text.trim().split("\n\n") {
             tiles.push(paragraph.parse()?);
         }
         Ok(tiles)
     }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the number of pixels along each edge of the tile.
+    pub fn side(&self) -> usize {
+        self.top.len()
+    }
 }
 
 impl FromStr for Tile {
-    type Err = ParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let lines: Vec<&str> = s.lines().collect();
         if lines.len() < 3
             || lines[1].len() < 2
             || lines[2..].iter().any(|line| line.len() != lines[1].len())
+            || lines[1..]
+                .iter()
+                .any(|line| line.bytes().any(|b| !b"#.".contains(&b)))
         {
-            return Err(ParseError::new("bad tile"));
+            return Err(Error::parse("bad tile"));
+        }
+        if lines.len() - 1 != lines[1].len() {
+            return Err(Error::parse("tile isn't square"));
         }
         let id = lines[0]
             .trim_end_matches(':')
             .split_whitespace()
             .last()
             .and_then(|id| id.parse().ok())
-            .ok_or_else(|| ParseError::new("expected tile ID"))?;
+            .ok_or_else(|| Error::parse("expected tile ID"))?;
         let interior = lines[2..(lines.len() - 1)]
             .iter()
             .map(|line| Vec::from(&line[1..(line.len() - 1)]))
@@ -54,10 +69,31 @@ impl FromStr for Tile {
         Ok(Tile {
             id,
             top: lines[1].to_owned(),
-            right: collect_column(&lines[1..], lines[0].len() - 1),
+            right: collect_column(&lines[1..], lines[1].len() - 1),
             bottom: lines[lines.len() - 1].to_owned(),
             left: collect_column(&lines[1..], 0),
             interior,
         })
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use advent_common::fuzz;
+
+    #[test]
+    fn from_str() {
+        let tile: Tile = "Tile 7:\n#..\n..#\n.##\n".parse().unwrap();
+        assert_eq!((tile.id, tile.right.as_str()), (7, ".##"));
+        assert_eq!(3, tile.side());
+        assert!("1\n..#.\n#.\u{e9}\n##..".parse::<Tile>().is_err());
+        let err = "Tile 2:\n#..\n..#\n".parse::<Tile>().unwrap_err();
+        assert_eq!("tile isn't square", err.message());
+    }
+
+    #[test]
+    fn fuzz_parse_all() {
+        fuzz::parser("tests/day20", &[], Tile::parse_all);
+    }
+}
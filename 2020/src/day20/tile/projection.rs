@@ -1,6 +1,7 @@
-use super::{Tile, rotate};
+use super::{rotate, Tile};
 
-/// Rotation and/or reflection of a Tile.
+/// Rotation and/or reflection of a Tile: the tile, mirrored left to right if
+/// `flipped`, then turned clockwise `turns` times.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Projection {
     pub tile_id: u64,
@@ -8,7 +9,9 @@ pub struct Projection {
     pub right: String,
     pub bottom: String,
     pub left: String,
-    pub interior: Vec<Vec<u8>>
+    pub interior: Vec<Vec<u8>>,
+    pub turns: u8,
+    pub flipped: bool,
 }
 
 impl Projection {
@@ -42,6 +45,8 @@ impl Tile {
             bottom: bottom.clone(),
             left: left.clone(),
             interior: self.interior.clone(),
+            turns: 0,
+            flipped: false,
         };
         let turn1 = Projection {
             tile_id: self.id,
@@ -50,6 +55,8 @@ impl Tile {
             bottom: rev_right.clone(),
             left: bottom.clone(),
             interior: rotate::clockwise(&turn0.interior),
+            turns: 1,
+            flipped: false,
         };
         let turn2 = Projection {
             // two turns: totally topsy turvy
@@ -59,6 +66,8 @@ impl Tile {
             bottom: rev_top.clone(),
             left: rev_right.clone(),
             interior: rotate::clockwise(&turn1.interior),
+            turns: 2,
+            flipped: false,
         };
         let turn3 = Projection {
             tile_id: self.id,
@@ -67,15 +76,21 @@ impl Tile {
             bottom: left.clone(),
             left: rev_top.clone(),
             interior: rotate::clockwise(&turn2.interior),
+            turns: 3,
+            flipped: false,
         };
         let (top, right, bottom, left, rev_top, rev_right, rev_bottom, rev_left) = (
             rev_top, left, rev_bottom, right, top, rev_left, bottom, rev_right,
         );
-        let interior: Vec<_> = self.interior.iter().map(|line| {
-            let mut line = line.clone();
-            line.reverse();
-            line
-        }).collect();
+        let interior: Vec<_> = self
+            .interior
+            .iter()
+            .map(|line| {
+                let mut line = line.clone();
+                line.reverse();
+                line
+            })
+            .collect();
         let flip_turn0 = Projection {
             tile_id: self.id,
             top: top.clone(),
@@ -83,6 +98,8 @@ impl Tile {
             bottom: bottom.clone(),
             left: left.clone(),
             interior,
+            turns: 0,
+            flipped: true,
         };
         let flip_turn1 = Projection {
             tile_id: self.id,
@@ -91,6 +108,8 @@ impl Tile {
             bottom: rev_right.clone(),
             left: bottom,
             interior: rotate::clockwise(&flip_turn0.interior),
+            turns: 1,
+            flipped: true,
         };
         let flip_turn2 = Projection {
             tile_id: self.id,
@@ -99,6 +118,8 @@ impl Tile {
             bottom: rev_top.clone(),
             left: rev_right,
             interior: rotate::clockwise(&flip_turn1.interior),
+            turns: 2,
+            flipped: true,
         };
         let flip_turn3 = Projection {
             tile_id: self.id,
@@ -107,6 +128,8 @@ impl Tile {
             bottom: left,
             left: rev_top,
             interior: rotate::clockwise(&flip_turn2.interior),
+            turns: 3,
+            flipped: true,
         };
         [
             turn0, turn1, turn2, turn3, flip_turn0, flip_turn1, flip_turn2, flip_turn3,
@@ -1,13 +1,13 @@
-use crate::error::ParseError;
+use advent_common::Error;
 use std::collections::VecDeque;
 
 pub type Card = u8;
 pub type Deck = VecDeque<Card>; // ordered top to bottom
 
-pub fn parse_both(text: String) -> Result<(Deck, Deck), ParseError> {
+pub fn parse_both(text: String) -> Result<(Deck, Deck), Error> {
     let mut decks = (Deck::new(), Deck::new());
     let mut lines = text.lines().skip(1);
-    while let Some(line) = lines.next() {
+    for line in lines.by_ref() {
         if line.is_empty() {
             break;
         }
@@ -4,3 +4,25 @@ pub mod part1;
 pub mod part2;
 
 pub use deck::{Card, Deck};
+
+use advent_common::{Error, Solver};
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    type Puzzle<'a> = (Deck, Deck);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<(Deck, Deck), Error> {
+        deck::parse_both(text.to_owned())
+    }
+
+    fn part1(decks: &(Deck, Deck)) -> Result<u64, Error> {
+        Ok(part1::solve(decks.clone()))
+    }
+
+    fn part2(decks: &(Deck, Deck)) -> Result<u64, Error> {
+        Ok(part2::solve(decks.clone()))
+    }
+}
@@ -1,2 +1,45 @@
 pub mod part1;
 pub mod part2;
+
+use advent_common::{Error, Solver};
+use std::num::ParseIntError;
+
+/// Parses the cup labels, which are listed as a single string of digits.
+pub fn parse(text: &str) -> Result<u64, ParseIntError> {
+    text.trim().parse()
+}
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    type Puzzle<'a> = u64;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<u64, Error> {
+        Ok(parse(text)?)
+    }
+
+    fn part1(digits: &u64) -> Result<u64, Error> {
+        Ok(part1::solve(*digits))
+    }
+
+    fn part2(digits: &u64) -> Result<u64, Error> {
+        Ok(part2::solve(*digits))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use advent_common::prop::{self, range, shuffle};
+
+    #[test]
+    fn parts_agree() {
+        let labels = shuffle((1..=9u64).collect());
+        prop::check((labels, range(0usize..=200)), |(labels, moves)| {
+            let digits = labels.iter().fold(0, |n, &label| n * 10 + label);
+            part1::play(digits, *moves) == part2::play1(digits, *moves)
+        });
+    }
+}
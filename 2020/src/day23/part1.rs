@@ -5,7 +5,7 @@ type Cup = u8;
 type CupArray = [Cup; BASE - 1];
 
 fn has_all_digits(cups: CupArray) -> bool {
-    (1..BASE).all(|digit| cups.iter().any(|&cup| cup == digit as Cup))
+    (1..BASE).all(|digit| cups.contains(&(digit as Cup)))
 }
 
 #[derive(Clone)]
@@ -58,8 +58,13 @@ impl Circle {
     }
 }
 
+/// Returns the labels after cup 1 once `moves` moves have been made.
+pub(super) fn play(digits: u64, moves: usize) -> u64 {
+    Circle::from_digits(digits).nth(moves).as_answer()
+}
+
 pub fn solve(digits: u64) -> u64 {
-    Circle::from_digits(digits).nth(100).as_answer()
+    play(digits, 100)
 }
 
 #[cfg(test)]
@@ -27,6 +27,8 @@
 //!   pushing back thousands of subsequent cups, even when they had nothing to
 //!   do with the move.
 
+use advent_common::trace::Progress;
+
 const WINDOW: usize = 3; // number of cups moved at a time
 
 type Cup = u32;
@@ -41,14 +43,12 @@ pub struct Circle {
 
 impl Circle {
     fn from_digits(mut digits: u64, len: usize) -> Circle {
-        let mut prefix = Vec::new(); // in reverse order
-        prefix.reserve(9);
+        let mut prefix = Vec::with_capacity(9); // in reverse order
         while digits > 0 {
             prefix.push((digits % 10) as Cup - 1);
             digits /= 10;
         }
-        let mut adjacent = Vec::new();
-        adjacent.reserve(len);
+        let mut adjacent = Vec::with_capacity(len);
         adjacent.resize(prefix.len(), 0);
         for (i, &cup) in prefix.iter().enumerate() {
             adjacent[cup as usize] = prefix[(i + prefix.len() - 1) % prefix.len()];
@@ -95,7 +95,11 @@ impl Circle {
     }
 
     fn nth(self, n: usize) -> Circle {
-        (0..n).fold(self, |circle, _| circle.next())
+        let mut progress = Progress::new("moves", n);
+        (0..n).fold(self, |circle, _| {
+            progress.tick();
+            circle.next()
+        })
     }
 
     fn as_answer(&self) -> u64 {
@@ -132,7 +136,12 @@ pub fn solve(digits: u64) -> u64 {
 /// 2. The circle for part 2 supports automatic insertion of more cups than
 ///    were explicitly specified in the input.
 pub fn solve1(digits: u64) -> u64 {
-    Circle::from_digits(digits, 9).nth(100).as_answer1()
+    play1(digits, 100)
+}
+
+/// Returns the part 1 answer once `moves` moves have been made.
+pub(super) fn play1(digits: u64, moves: usize) -> u64 {
+    Circle::from_digits(digits, 9).nth(moves).as_answer1()
 }
 
 #[cfg(test)]
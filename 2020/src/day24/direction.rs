@@ -1,3 +1,6 @@
+use advent_common::automaton::Point;
+
+#[derive(Clone, Copy, Debug)]
 pub enum HexDirection {
     East,
     NorthEast,
@@ -8,51 +11,36 @@ pub enum HexDirection {
 }
 
 impl HexDirection {
-    pub fn all() -> HexDirections {
-        HexDirections {
-            next: Some(HexDirection::East),
+    /// Returns the displacement of the adjacent tile in this direction, in
+    /// axial coordinates.
+    pub fn offset(self) -> Point<2> {
+        match self {
+            HexDirection::East => [1, 0],
+            HexDirection::NorthEast => [1, -1],
+            HexDirection::NorthWest => [0, -1],
+            HexDirection::West => [-1, 0],
+            HexDirection::SouthWest => [-1, 1],
+            HexDirection::SouthEast => [0, 1],
         }
     }
 
+    /// Returns the tile reached by following the directions in `line` from
+    /// the reference tile at the origin.
+    pub fn follow(line: &str) -> Point<2> {
+        HexDirection::parse_line(line).fold([0, 0], |[q, r], dir| {
+            let [dq, dr] = dir.offset();
+            [q + dq, r + dr]
+        })
+    }
+
     /// HexDirections can't implement std::str::FromStr because it depends on
     /// the lifetime of the supplied string reference.  FromStr really wants
     /// its result to be independent of the lifetime of the original string.
-    pub fn parse_line(line: &str) -> ParseLine {
+    pub fn parse_line(line: &str) -> ParseLine<'_> {
         ParseLine { line }
     }
 }
 
-pub enum SquareDirection {
-    East,
-    NorthEast,
-    North,
-    NorthWest,
-    West,
-    SouthWest,
-    South,
-    SouthEast,
-}
-
-pub struct HexDirections {
-    next: Option<HexDirection>,
-}
-
-impl Iterator for HexDirections {
-    type Item = HexDirection;
-
-    fn next(&mut self) -> Option<Self::Item> {
-        match self.next {
-            Some(HexDirection::East) => self.next.replace(HexDirection::NorthEast),
-            Some(HexDirection::NorthEast) => self.next.replace(HexDirection::NorthWest),
-            Some(HexDirection::NorthWest) => self.next.replace(HexDirection::West),
-            Some(HexDirection::West) => self.next.replace(HexDirection::SouthWest),
-            Some(HexDirection::SouthWest) => self.next.replace(HexDirection::SouthEast),
-            Some(HexDirection::SouthEast) => self.next.take(),
-            None => None,
-        }
-    }
-}
-
 pub struct ParseLine<'a> {
     line: &'a str,
 }
@@ -84,3 +72,14 @@ impl Iterator for ParseLine<'_> {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn follow() {
+        assert_eq!([0, 1], HexDirection::follow("esew"));
+        assert_eq!([0, 0], HexDirection::follow("nwwswee"));
+    }
+}
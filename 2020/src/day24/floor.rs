@@ -1,49 +1,27 @@
 use super::direction::HexDirection;
-use super::latlon::LatLon;
+use advent_common::automaton::{Automaton, Neighborhood, Rule};
 use std::collections::HashSet;
 
 use std::convert::Infallible;
-use std::mem;
 use std::str::FromStr;
 
+/// A floor of hexagonal tiles, each white or black.  Every day, a black tile
+/// with zero or more than two adjacent black tiles is flipped to white, and a
+/// white tile with exactly two adjacent black tiles is flipped to black.
+#[derive(Clone)]
 pub struct Floor {
-    black: HashSet<LatLon>,
+    black: Automaton<2>,
 }
 
 impl Floor {
     pub fn count_black(&self) -> usize {
-        self.black.len()
+        self.black.population()
     }
 
-    pub fn next_is_black(&self, tile: LatLon) -> bool {
-        let count = tile
-            .neighbors()
-            .filter(|adjacent| self.black.contains(adjacent))
-            .count();
-        count == 2 || (count == 1 && self.black.contains(&tile))
-    }
-
-    fn next_into(&self, next: &mut HashSet<LatLon>) {
-        next.clear();
-        for &tile in &self.black {
-            if self.next_is_black(tile) {
-                next.insert(tile);
-            }
-            for neighbor in tile.neighbors() {
-                if self.next_is_black(neighbor) {
-                    next.insert(neighbor);
-                }
-            }
-        }
-    }
-
-    pub fn day(mut self, day: usize) -> Floor {
-        let next = &mut HashSet::new();
-        for _ in 0..day {
-            self.next_into(next);
-            mem::swap(&mut self.black, next);
+    pub fn day(self, day: usize) -> Floor {
+        Floor {
+            black: self.black.advance(day),
         }
-        self
     }
 }
 
@@ -52,16 +30,18 @@ impl FromStr for Floor {
 
     fn from_str(s: &str) -> Result<Floor, Self::Err> {
         let mut black = HashSet::new();
-        let origin = LatLon(0, 0);
         for line in s.lines() {
-            let tile = origin.hence(HexDirection::parse_line(line));
+            let tile = HexDirection::follow(line);
             if black.contains(&tile) {
                 black.remove(&tile);
             } else {
                 black.insert(tile);
             }
         }
-        Ok(Floor { black })
+        let rule = Rule::new([2], [1, 2]);
+        Ok(Floor {
+            black: Automaton::new(black, Neighborhood::Hex, rule),
+        })
     }
 }
 
@@ -1,17 +1,33 @@
-//! We'll model our hex grid as a square grid by mapping two of the
-//! semicardinal directions to north and south.  There's a little wackiness
-//! though, because hex grid axes aren't orthogonal like square grids are:
-//! We'll have to alternate which pair of semicardinals to remap (either
-//! westerly or easterly) from one row to the next.  Assuming the reference
-//! tile is at the origin (0, 0), we'll map NE/SE to north and south in even
-//! numbered rows, but map NW/SW in odd ones.
-//!
-//! Note that unmapped semicardinals mean the same thing they would in any
-//! rectangular grid.  For example, going SW from a tile in an even numbered
-//! row means going diagonally south and west.
+//! We'll model our hex grid using axial coordinates: Each tile is identified
+//! by a pair (q, r), where q increases going east and r increases going
+//! southeast.  The third hex axis, running northeast, is implied: going
+//! northeast means increasing q and decreasing r, as if going east and then
+//! northwest.  Flipping tiles each day is then a cellular automaton on the
+//! usual hexagonal neighborhood.
 
 mod direction;
 mod floor;
-mod latlon;
 
 pub use floor::Floor;
+
+use advent_common::{Error, Solver};
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    type Puzzle<'a> = Floor;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Floor, Error> {
+        Ok(text.parse()?)
+    }
+
+    fn part1(floor: &Floor) -> Result<usize, Error> {
+        Ok(floor.count_black())
+    }
+
+    fn part2(floor: &Floor) -> Result<usize, Error> {
+        Ok(floor.clone().day(100).count_black())
+    }
+}
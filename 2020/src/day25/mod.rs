@@ -0,0 +1,111 @@
+use advent_common::{Error, Solver};
+
+const DIVISOR: u64 = 20201227;
+
+fn transform(size: u64, subject: u64) -> u64 {
+    let mut value = 1;
+    for _ in 0..size {
+        value *= subject;
+        value %= DIVISOR;
+    }
+    value
+}
+
+fn find(key: u64, subject: u64) -> Result<u64, Error> {
+    let mut value = 1;
+    for size in 1.. {
+        value *= subject;
+        value %= DIVISOR;
+        if key == value {
+            return Ok(size);
+        }
+    }
+    Err(Error::no_solution())
+}
+
+/// Parses the public keys of the card and door, in that order.
+pub fn parse_keys(text: &str) -> Result<(u64, u64), Error> {
+    let mut keys = text.split_whitespace();
+    let card = keys
+        .next()
+        .ok_or_else(|| Error::parse("expected card's public key"))?
+        .parse()?;
+    let door = keys
+        .next()
+        .ok_or_else(|| Error::parse("expected door's public key"))?
+        .parse()?;
+    if keys.next().is_none() {
+        Ok((card, door))
+    } else {
+        Err(Error::parse("expected only two keys"))
+    }
+}
+
+/// Returns the encryption key shared by the card and door with the specified
+/// public keys.
+pub fn solve_part1(card_key: u64, door_key: u64) -> Result<u64, Error> {
+    let card_size = find(card_key, 7)?;
+    Ok(transform(card_size, door_key))
+}
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    type Puzzle<'a> = (u64, u64);
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<(u64, u64), Error> {
+        parse_keys(text)
+    }
+
+    fn part1(&(card_key, door_key): &(u64, u64)) -> Result<u64, Error> {
+        solve_part1(card_key, door_key)
+    }
+
+    fn part2(_: &(u64, u64)) -> Result<u64, Error> {
+        // Day 25 has only one puzzle.
+        Err(Error::no_solution())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CARD_KEY: u64 = 5764801;
+    const DOOR_KEY: u64 = 17807724;
+    const CARD_SIZE: u64 = 8;
+    const DOOR_SIZE: u64 = 11;
+    const ENC_KEY: u64 = 14897079;
+
+    #[test]
+    fn transform_card() {
+        assert_eq!(CARD_KEY, transform(CARD_SIZE, 7));
+    }
+
+    #[test]
+    fn transform_door() {
+        assert_eq!(DOOR_KEY, transform(DOOR_SIZE, 7));
+    }
+
+    #[test]
+    fn find_card() {
+        assert_eq!(Ok(CARD_SIZE), find(CARD_KEY, 7));
+    }
+
+    #[test]
+    fn find_door() {
+        assert_eq!(Ok(DOOR_SIZE), find(DOOR_KEY, 7));
+    }
+
+    #[test]
+    fn enc_card() {
+        assert_eq!(ENC_KEY, transform(CARD_SIZE, DOOR_KEY));
+    }
+
+    #[test]
+    fn enc_door() {
+        assert_eq!(ENC_KEY, transform(DOOR_SIZE, CARD_KEY));
+    }
+}
@@ -0,0 +1,52 @@
+use advent_common::{Error, Solver};
+
+pub fn parse_rows(text: &str) -> Vec<String> {
+    text.lines().map(str::to_owned).collect()
+}
+
+fn solve(rows: &[String], right: usize, down: usize) -> i32 {
+    let mut iter = rows.iter();
+    let row = iter.next().expect("empty table");
+    let width = row.len();
+    let mut x = 0;
+    let mut count = if row.as_bytes()[0] == b'#' { 1 } else { 0 };
+    while let Some(row) = iter.nth(down - 1) {
+        x = (x + right) % width;
+        if row.as_bytes()[x] == b'#' {
+            count += 1
+        }
+    }
+    count
+}
+
+pub fn solve_part1(rows: &[String]) -> i32 {
+    solve(rows, 3, 1)
+}
+
+pub fn solve_part2(rows: &[String]) -> i32 {
+    solve(rows, 1, 1)
+        * solve(rows, 3, 1)
+        * solve(rows, 5, 1)
+        * solve(rows, 7, 1)
+        * solve(rows, 1, 2)
+}
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    type Puzzle<'a> = Vec<String>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(text: &str) -> Result<Vec<String>, Error> {
+        Ok(parse_rows(text))
+    }
+
+    fn part1(rows: &Vec<String>) -> Result<i32, Error> {
+        Ok(solve_part1(rows))
+    }
+
+    fn part2(rows: &Vec<String>) -> Result<i32, Error> {
+        Ok(solve_part2(rows))
+    }
+}
@@ -0,0 +1,174 @@
+use advent_common::{Error, Solver};
+use std::collections::HashMap;
+use std::mem;
+use std::ops::RangeInclusive;
+
+pub type Passport = HashMap<String, String>;
+
+const KEYS: [&str; 7] = [
+    "byr", // Birth Year
+    "iyr", // Issue Year
+    "eyr", // Expiration Year
+    "hgt", // Height
+    "hcl", // Hair Color
+    "ecl", // Eye Color
+    "pid", // Passport ID
+];
+
+pub fn parse_passports(text: &str) -> Result<Vec<Passport>, Error> {
+    let mut passports = Vec::new();
+    let mut passport = Passport::new();
+    for (index, line) in text.lines().enumerate() {
+        if line.is_empty() {
+            passports.push(mem::take(&mut passport));
+        } else {
+            for pair in line.split_whitespace() {
+                let (key, value) = pair.split_once(':').ok_or_else(|| {
+                    Error::parse(format!("{}: expected key:value", pair)).at_line(index + 1)
+                })?;
+                passport.insert(key.to_owned(), value.to_owned());
+            }
+        }
+    }
+    if !passport.is_empty() {
+        passports.push(passport);
+    }
+    Ok(passports)
+}
+
+fn is_valid1(passport: &Passport) -> bool {
+    KEYS.iter().all(|&key| passport.contains_key(key))
+}
+
+pub fn solve_part1(passports: &[Passport]) -> usize {
+    passports.iter().filter(|&x| is_valid1(x)).count()
+}
+
+fn is_four_digits_in_range(s: &str, r: RangeInclusive<u32>) -> bool {
+    s.len() == 4 && s.parse().ok().filter(|n| r.contains(n)).is_some()
+}
+
+fn byr_is_valid(s: &str) -> bool {
+    is_four_digits_in_range(s, 1920..=2002)
+}
+
+fn iyr_is_valid(s: &str) -> bool {
+    is_four_digits_in_range(s, 2010..=2020)
+}
+
+fn eyr_is_valid(s: &str) -> bool {
+    is_four_digits_in_range(s, 2020..=2030)
+}
+
+fn hgt_is_valid(s: &str) -> bool {
+    s.len() > 2 && {
+        match s.split_at(s.len() - 2) {
+            (num, "cm") => num
+                .parse::<u32>()
+                .ok()
+                .filter(|n| (150..=193).contains(n))
+                .is_some(),
+            (num, "in") => num
+                .parse::<u32>()
+                .ok()
+                .filter(|n| (59..=76).contains(n))
+                .is_some(),
+            _ => false,
+        }
+    }
+}
+
+fn hcl_is_valid(s: &str) -> bool {
+    s.len() == 7 && s.starts_with('#') && s.chars().skip(1).all(|c| "0123456789abcdef".contains(c))
+}
+
+fn ecl_is_valid(s: &str) -> bool {
+    ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"][..].contains(&s)
+}
+
+fn pid_is_valid(s: &str) -> bool {
+    s.len() == 9 && s.chars().all(|c| "0123456789".contains(c))
+}
+
+fn has_valid<F>(passport: &Passport, key: &str, criterion: F) -> bool
+where
+    F: FnOnce(&str) -> bool,
+{
+    passport.get(key).filter(|value| criterion(value)).is_some()
+}
+
+fn is_valid2(passport: &Passport) -> bool {
+    has_valid(passport, "byr", byr_is_valid)
+        && has_valid(passport, "iyr", iyr_is_valid)
+        && has_valid(passport, "eyr", eyr_is_valid)
+        && has_valid(passport, "hgt", hgt_is_valid)
+        && has_valid(passport, "hcl", hcl_is_valid)
+        && has_valid(passport, "ecl", ecl_is_valid)
+        && has_valid(passport, "pid", pid_is_valid)
+}
+
+pub fn solve_part2(passports: &[Passport]) -> usize {
+    passports.iter().filter(|&x| is_valid2(x)).count()
+}
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Puzzle<'a> = Vec<Passport>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<Passport>, Error> {
+        parse_passports(text)
+    }
+
+    fn part1(passports: &Vec<Passport>) -> Result<usize, Error> {
+        Ok(solve_part1(passports))
+    }
+
+    fn part2(passports: &Vec<Passport>) -> Result<usize, Error> {
+        Ok(solve_part2(passports))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn criteria() {
+        assert!(byr_is_valid("2002"));
+        assert!(!byr_is_valid("2003"));
+
+        assert!(hgt_is_valid("60in"));
+        assert!(hgt_is_valid("190cm"));
+        assert!(!hgt_is_valid("190in"));
+        assert!(!hgt_is_valid("190"));
+
+        assert!(hcl_is_valid("#123abc"));
+        assert!(!hcl_is_valid("#123abz"));
+        assert!(!hcl_is_valid("123abc"));
+
+        assert!(ecl_is_valid("brn"));
+        assert!(!ecl_is_valid("wat"));
+
+        assert!(pid_is_valid("000000001"));
+        assert!(!pid_is_valid("0123456789"));
+    }
+
+    #[test]
+    fn valids() {
+        let text = fs::read_to_string("tests/day4/valids").expect("can't read input");
+        let passports = parse_passports(&text).unwrap();
+        assert!(4 == solve_part1(&passports));
+        assert!(4 == solve_part2(&passports));
+    }
+
+    #[test]
+    fn invalids2() {
+        let text = fs::read_to_string("tests/day4/invalids2").expect("can't read input");
+        let passports = parse_passports(&text).unwrap();
+        assert!(0 == solve_part2(&passports));
+    }
+}
@@ -0,0 +1,61 @@
+use advent_common::{Error, Solver};
+use std::collections::HashSet;
+
+fn parse_char(c: char) -> Result<usize, String> {
+    match c {
+        'F' | 'L' => Ok(0),
+        'B' | 'R' => Ok(1),
+        _ => Err(format!("{}: expected any of FBLR", c)),
+    }
+}
+
+fn parse(seat: &str) -> Result<usize, String> {
+    let mut value = 0;
+    for c in seat.chars() {
+        value = (value << 1) | parse_char(c)?;
+    }
+    Ok(value)
+}
+
+pub fn parse_seats(text: &str) -> Result<Vec<usize>, String> {
+    let mut seats = Vec::new();
+    for line in text.lines() {
+        seats.push(parse(line)?);
+    }
+    if seats.is_empty() {
+        Err("empty input".to_owned())
+    } else {
+        Ok(seats)
+    }
+}
+
+pub fn solve_part1(seats: &[usize]) -> Option<usize> {
+    seats.iter().cloned().max()
+}
+
+pub fn solve_part2(seats: &[usize]) -> Option<usize> {
+    const MAX_ID: usize = (1 << 10) - 1; // seats have ten-bit IDs
+    let taken: HashSet<usize> = seats.iter().cloned().collect();
+    (1..MAX_ID)
+        .find(|id| !taken.contains(id) && taken.contains(&(id - 1)) && taken.contains(&(id + 1)))
+}
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    type Puzzle<'a> = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<usize>, Error> {
+        Ok(parse_seats(text)?)
+    }
+
+    fn part1(seats: &Vec<usize>) -> Result<usize, Error> {
+        solve_part1(seats).ok_or_else(Error::no_solution)
+    }
+
+    fn part2(seats: &Vec<usize>) -> Result<usize, Error> {
+        solve_part2(seats).ok_or_else(Error::no_solution)
+    }
+}
@@ -0,0 +1,65 @@
+use advent_common::{Error, Solver};
+use std::collections::HashSet;
+use std::mem;
+
+/// The questions to which each person in a group answered yes.
+pub type Group = Vec<HashSet<char>>;
+
+/// Parses groups separated by blank lines, with one person per line.
+pub fn parse_groups(text: &str) -> Result<Vec<Group>, Error> {
+    let mut groups = Vec::new();
+    let mut group = Group::new();
+    for (index, line) in text.lines().enumerate() {
+        if line.is_empty() {
+            if !group.is_empty() {
+                groups.push(mem::take(&mut group));
+            }
+        } else if let Some(c) = line.chars().find(|c| !c.is_ascii_lowercase()) {
+            let what = format!("{:?}: expected a question from a to z", c);
+            return Err(Error::parse(what).at_line(index + 1));
+        } else {
+            group.push(line.chars().collect());
+        }
+    }
+    if !group.is_empty() {
+        groups.push(group);
+    }
+    Ok(groups)
+}
+
+pub fn solve_part1(groups: &[Group]) -> usize {
+    let anyone = |group: &Group| group.iter().flatten().collect::<HashSet<_>>().len();
+    groups.iter().map(anyone).sum()
+}
+
+pub fn solve_part2(groups: &[Group]) -> usize {
+    let everyone = |group: &Group| {
+        group.split_first().map_or(0, |(first, rest)| {
+            let questions = first.iter();
+            questions
+                .filter(|c| rest.iter().all(|person| person.contains(c)))
+                .count()
+        })
+    };
+    groups.iter().map(everyone).sum()
+}
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    type Puzzle<'a> = Vec<Group>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<Group>, Error> {
+        parse_groups(text)
+    }
+
+    fn part1(groups: &Vec<Group>) -> Result<usize, Error> {
+        Ok(solve_part1(groups))
+    }
+
+    fn part2(groups: &Vec<Group>) -> Result<usize, Error> {
+        Ok(solve_part2(groups))
+    }
+}
@@ -1,2 +1,78 @@
 pub mod part1;
 pub mod part2;
+
+use advent_common::{Error, Solver};
+use std::collections::HashMap;
+
+/// A bag color, such as ("shiny", "gold").
+pub type Color = (String, String);
+
+/// The number of bags of each color directly inside each color of bag.
+pub type RuleMap = HashMap<Color, HashMap<Color, usize>>;
+
+/// Parses one rule per line, such as "light red bags contain 1 bright white
+/// bag, 2 muted yellow bags."  Every color inside a bag must have a rule.
+pub fn parse_rules(text: &str) -> Result<RuleMap, Error> {
+    let mut rules = RuleMap::new();
+    for line in text.lines() {
+        let mut tokens = line.split_whitespace();
+        let key = (
+            tokens.next().ok_or("expected shade")?.to_owned(),
+            tokens.next().ok_or("expected color")?.to_owned(),
+        );
+        let mut tokens = tokens.skip(2); // "bags contain"
+        let mut contents = HashMap::new();
+        while let (Some(count), Some(shade), Some(color), Some(_bags)) =
+            (tokens.next(), tokens.next(), tokens.next(), tokens.next())
+        {
+            // The loop test fails if the bag contains "no" "other" "bags."
+            let color = (shade.to_owned(), color.to_owned());
+            contents.insert(color, count.parse()?);
+        }
+        rules.insert(key, contents);
+    }
+    for color in rules.values().flat_map(HashMap::keys) {
+        if !rules.contains_key(color) {
+            let what = format!("{} {} bags have no rule", color.0, color.1);
+            return Err(Error::parse(what));
+        }
+    }
+    Ok(rules)
+}
+
+/// Returns the color of the bag we're carrying.
+fn shiny_gold() -> Color {
+    ("shiny".to_owned(), "gold".to_owned())
+}
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    type Puzzle<'a> = RuleMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<RuleMap, Error> {
+        parse_rules(text)
+    }
+
+    fn part1(rules: &RuleMap) -> Result<usize, Error> {
+        Ok(part1::solve(rules, &shiny_gold()))
+    }
+
+    fn part2(rules: &RuleMap) -> Result<usize, Error> {
+        part2::solve(rules, &shiny_gold())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn missing_rule() {
+        let text = "light red bags contain 1 bright white bag.\n";
+        let err = parse_rules(text).unwrap_err();
+        assert_eq!("bright white bags have no rule", err.message());
+    }
+}
@@ -0,0 +1,44 @@
+use advent_common::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Parses boot code, one instruction per line.
+pub fn assemble<I>(text: &str) -> Result<Vec<I>, Error>
+where
+    I: FromStr<Err = Error>,
+{
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| line.parse().map_err(|err: Error| err.at_line(index + 1)))
+        .collect()
+}
+
+/// Writes a program as boot code, one instruction per line.
+pub fn disassemble<I: Display>(program: &[I]) -> String {
+    program
+        .iter()
+        .map(|instruction| format!("{}\n", instruction))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::Instruction;
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn round_trip() {
+        for path in ["tests/day8/sample1", "tests/day8/input"] {
+            let text = fs::read_to_string(path).unwrap();
+            let program: Vec<Instruction> = assemble(&text).unwrap();
+            assert_eq!(text, disassemble(&program));
+        }
+    }
+
+    #[test]
+    fn bad_line() {
+        let err = assemble::<Instruction>("nop +0\njmp\n").unwrap_err();
+        assert_eq!(Some(2), err.line());
+    }
+}
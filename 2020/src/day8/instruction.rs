@@ -0,0 +1,115 @@
+use advent_common::Error;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+/// The state of the handheld console's processor.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Registers {
+    pub acc: i64,  // accumulator
+    pub pc: isize, // program counter
+}
+
+/// An instruction the console can execute.  The console's own instruction
+/// set is [`Instruction`], but a [`Machine`](super::Machine) runs any type
+/// implementing this trait.
+pub trait Execute {
+    /// Returns the registers after running this instruction, including
+    /// the advanced program counter, or None if a register would overflow.
+    fn execute(&self, registers: Registers) -> Option<Registers>;
+
+    /// Returns the instruction that this one may have been corrupted from,
+    /// if any.
+    fn patched(&self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Instruction {
+    Acc(i64),
+    Jmp(isize),
+    Nop(isize),
+}
+
+impl Execute for Instruction {
+    fn execute(&self, registers: Registers) -> Option<Registers> {
+        let Registers { acc, pc } = registers;
+        Some(match *self {
+            Instruction::Acc(arg) => Registers {
+                acc: acc.checked_add(arg)?,
+                pc: pc.checked_add(1)?,
+            },
+            Instruction::Jmp(arg) => Registers {
+                acc,
+                pc: pc.checked_add(arg)?,
+            },
+            Instruction::Nop(_) => Registers {
+                acc,
+                pc: pc.checked_add(1)?,
+            },
+        })
+    }
+
+    /// Exactly one `jmp` or `nop` in the boot code is corrupted: it's
+    /// supposed to be the other.
+    fn patched(&self) -> Option<Instruction> {
+        match *self {
+            Instruction::Acc(_) => None,
+            Instruction::Jmp(arg) => Some(Instruction::Nop(arg)),
+            Instruction::Nop(arg) => Some(Instruction::Jmp(arg)),
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Instruction, Error> {
+        let tokens: Vec<_> = s.split_whitespace().collect();
+        match &tokens[..] {
+            ["acc", arg] => Ok(Instruction::Acc(arg.parse()?)),
+            ["jmp", arg] => Ok(Instruction::Jmp(arg.parse()?)),
+            ["nop", arg] => Ok(Instruction::Nop(arg.parse()?)),
+            _ => Err(Error::parse(format!("bad instruction: {}", s))),
+        }
+    }
+}
+
+/// Instructions are displayed as they're written in boot code, with signed
+/// arguments.
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Instruction::Acc(arg) => write!(f, "acc {:+}", arg),
+            Instruction::Jmp(arg) => write!(f, "jmp {:+}", arg),
+            Instruction::Nop(arg) => write!(f, "nop {:+}", arg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn execute() {
+        let mut registers = Registers::default();
+        for (text, acc, pc) in [("acc -3", -3, 1), ("jmp +4", -3, 5), ("nop -9", -3, 6)] {
+            let instruction: Instruction = text.parse().unwrap();
+            registers = instruction.execute(registers).unwrap();
+            assert_eq!(Registers { acc, pc }, registers);
+            assert_eq!(text, instruction.to_string());
+        }
+        let registers = Registers {
+            acc: i64::MAX,
+            pc: 2,
+        };
+        assert_eq!(None, Instruction::Acc(1).execute(registers));
+        assert_eq!(None, Instruction::Jmp(isize::MAX).execute(registers));
+        assert!("acc".parse::<Instruction>().is_err());
+        assert!("mul +2".parse::<Instruction>().is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use super::instruction::{Execute, Instruction, Registers};
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+/// The instructions run by a machine that went around a loop, in the order
+/// they ran, beginning with the instruction it was about to run again.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub indexes: Vec<usize>,
+}
+
+/// Why a machine stopped running.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Stop {
+    /// The program counter moved just past the last instruction, which is
+    /// how programs end normally.
+    Halt,
+    /// The next instruction has run before, so the program would run
+    /// forever.
+    Loop(Cycle),
+    /// The program counter moved outside the program, other than to just
+    /// past its end.
+    OutOfBounds(isize),
+    /// The next instruction is at a breakpoint.
+    Breakpoint(usize),
+    /// Running the instruction at this index would overflow a register.
+    Overflow(usize),
+}
+
+/// One executed instruction: its index, and the accumulator afterward.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Step {
+    pub index: usize,
+    pub acc: i64,
+}
+
+/// Runs a program, one instruction at a time, stopping before it runs any
+/// instruction twice.
+pub struct Machine<'a, I = Instruction> {
+    program: &'a [I],
+    registers: Registers,
+    trace: Vec<Step>,
+    seen: Vec<Option<usize>>, // maps instruction indexes to trace indexes
+}
+
+impl<'a, I: Execute> Machine<'a, I> {
+    pub fn new(program: &'a [I]) -> Machine<'a, I> {
+        Machine {
+            program,
+            registers: Registers::default(),
+            trace: Vec::new(),
+            seen: vec![None; program.len()],
+        }
+    }
+
+    pub fn registers(&self) -> Registers {
+        self.registers
+    }
+
+    pub fn acc(&self) -> i64 {
+        self.registers.acc
+    }
+
+    /// Returns every instruction run so far, in order.  Since no instruction
+    /// runs twice, the trace is never longer than the program.
+    pub fn trace(&self) -> &[Step] {
+        &self.trace
+    }
+
+    /// Returns the index of the next instruction, or the reason there isn't
+    /// one.
+    fn next_index(&self) -> Result<usize, Stop> {
+        let pc = self.registers.pc;
+        if pc == self.program.len() as isize {
+            return Err(Stop::Halt);
+        }
+        let index = usize::try_from(pc)
+            .ok()
+            .filter(|&index| index < self.program.len())
+            .ok_or(Stop::OutOfBounds(pc))?;
+        match self.seen[index] {
+            Some(first) => {
+                let indexes = self.trace[first..].iter().map(|step| step.index);
+                Err(Stop::Loop(Cycle {
+                    indexes: indexes.collect(),
+                }))
+            }
+            None => Ok(index),
+        }
+    }
+
+    /// Runs the next instruction, or returns why the machine can't.
+    pub fn step(&mut self) -> Result<(), Stop> {
+        let index = self.next_index()?;
+        self.registers = self.program[index]
+            .execute(self.registers)
+            .ok_or(Stop::Overflow(index))?;
+        self.seen[index] = Some(self.trace.len());
+        let acc = self.registers.acc;
+        self.trace.push(Step { index, acc });
+        advent_common::trace!("{:4}: acc {}", index, acc);
+        Ok(())
+    }
+
+    /// Runs instructions until the program halts, loops, jumps out of
+    /// bounds, or overflows.
+    pub fn run(&mut self) -> Stop {
+        self.run_until(&HashSet::new())
+    }
+
+    /// Runs instructions until the program stops, or the next instruction's
+    /// index is a breakpoint.  The first instruction always runs, so that a
+    /// machine stopped at a breakpoint can resume.
+    pub fn run_until(&mut self, breakpoints: &HashSet<usize>) -> Stop {
+        if let Err(stop) = self.step() {
+            return stop;
+        }
+        loop {
+            match self.next_index() {
+                Ok(index) if breakpoints.contains(&index) => return Stop::Breakpoint(index),
+                Ok(_) => (),
+                Err(stop) => return stop,
+            }
+            if let Err(stop) = self.step() {
+                return stop;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::assemble;
+    use super::*;
+    use std::fs;
+
+    fn sample() -> Vec<Instruction> {
+        assemble(&fs::read_to_string("tests/day8/sample1").unwrap()).unwrap()
+    }
+
+    #[test]
+    fn run() {
+        let program = sample();
+        let mut machine = Machine::new(&program);
+        let cycle = Cycle {
+            indexes: vec![1, 2, 6, 7, 3, 4],
+        };
+        assert_eq!(Stop::Loop(cycle), machine.run());
+        assert_eq!(5, machine.acc());
+        assert_eq!(7, machine.trace().len());
+        assert_eq!(Step { index: 6, acc: 2 }, machine.trace()[3]);
+
+        assert_eq!(Stop::Halt, Machine::new(&program[..2]).run());
+        assert_eq!(Stop::OutOfBounds(-2), Machine::new(&program[3..5]).run());
+    }
+
+    #[test]
+    fn overflow() {
+        let text = "acc +9223372036854775807\nnop +0\nacc +1\n";
+        let program: Vec<Instruction> = assemble(text).unwrap();
+        let mut machine = Machine::new(&program);
+        assert_eq!(Stop::Overflow(2), machine.run());
+        assert_eq!(
+            Registers {
+                acc: i64::MAX,
+                pc: 2
+            },
+            machine.registers()
+        );
+        assert_eq!(2, machine.trace().len());
+        let program = [Instruction::Nop(0), Instruction::Jmp(isize::MAX)];
+        assert_eq!(Stop::Overflow(1), Machine::new(&program).run());
+    }
+
+    #[test]
+    fn run_until() {
+        let program = sample();
+        let mut machine = Machine::new(&program);
+        let breakpoints = [3, 7].iter().copied().collect();
+        assert_eq!(Stop::Breakpoint(7), machine.run_until(&breakpoints));
+        assert_eq!(Registers { acc: 2, pc: 7 }, machine.registers());
+        assert_eq!(Stop::Breakpoint(3), machine.run_until(&breakpoints));
+        assert_eq!(Ok(()), machine.step());
+        assert!(matches!(machine.run_until(&breakpoints), Stop::Loop(_)));
+    }
+}
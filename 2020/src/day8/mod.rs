@@ -0,0 +1,73 @@
+//! The handheld game console's boot code interpreter.
+//!
+//! A [`Machine`] runs a program of any instruction set implementing
+//! [`Execute`], one instruction at a time or until a breakpoint, recording a
+//! trace of what ran and stopping when the program halts or starts to loop.
+//! Programs are assembled from and disassembled to boot code text, and
+//! [`find_patch`] searches for the single corrupted instruction that keeps a
+//! program from halting.
+
+mod asm;
+mod instruction;
+mod machine;
+mod patch;
+
+pub use asm::{assemble, disassemble};
+pub use instruction::{Execute, Instruction, Registers};
+pub use machine::{Cycle, Machine, Step, Stop};
+pub use patch::{find_patch, Patch};
+
+use advent_common::{Error, Solver};
+
+pub type Program = Vec<Instruction>;
+
+/// Returns the accumulator just before any instruction runs a second time.
+pub fn solve_part1(program: &[Instruction]) -> Result<i64, Error> {
+    let mut machine = Machine::new(program);
+    match machine.run() {
+        Stop::Loop(_) => Ok(machine.acc()),
+        _ => Err(Error::no_solution()),
+    }
+}
+
+/// Returns the accumulator once the program, fixed by switching one `jmp` or
+/// `nop` to the other, halts.
+pub fn solve_part2(program: &[Instruction]) -> Result<i64, Error> {
+    find_patch(program)
+        .map(|patch| patch.acc)
+        .ok_or_else(Error::no_solution)
+}
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    type Puzzle<'a> = Program;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(text: &str) -> Result<Program, Error> {
+        assemble(text)
+    }
+
+    fn part1(program: &Program) -> Result<i64, Error> {
+        solve_part1(program)
+    }
+
+    fn part2(program: &Program) -> Result<i64, Error> {
+        solve_part2(program)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn sample1() {
+        let text = fs::read_to_string("tests/day8/sample1").unwrap();
+        let program = assemble(&text).unwrap();
+        assert_eq!(5, solve_part1(&program).unwrap());
+        assert_eq!(8, solve_part2(&program).unwrap());
+    }
+}
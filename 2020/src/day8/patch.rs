@@ -0,0 +1,56 @@
+use super::instruction::Execute;
+use super::machine::{Machine, Stop};
+
+/// A change to one instruction that makes a program halt.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Patch<I> {
+    pub index: usize,
+    pub instruction: I,
+    /// The accumulator once the patched program halts.
+    pub acc: i64,
+}
+
+/// Returns the first patch to a single instruction, as proposed by
+/// [`Execute::patched`], that makes the program halt.
+pub fn find_patch<I: Execute + Clone>(program: &[I]) -> Option<Patch<I>> {
+    let mut patched = program.to_vec();
+    for (index, original) in program.iter().enumerate() {
+        let instruction = match original.patched() {
+            Some(instruction) => instruction,
+            None => continue,
+        };
+        patched[index] = instruction.clone();
+        let mut machine = Machine::new(&patched);
+        if machine.run() == Stop::Halt {
+            let acc = machine.acc();
+            return Some(Patch {
+                index,
+                instruction,
+                acc,
+            });
+        }
+        patched[index] = original.clone();
+    }
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::{assemble, Instruction};
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn sample1() {
+        let text = fs::read_to_string("tests/day8/sample1").unwrap();
+        let program: Vec<Instruction> = assemble(&text).unwrap();
+        let patch = Patch {
+            index: 7,
+            instruction: Instruction::Nop(-4),
+            acc: 8,
+        };
+        assert_eq!(Some(patch), find_patch(&program));
+        let program: Vec<Instruction> = assemble("jmp +0\nacc +1\njmp -1\n").unwrap();
+        assert_eq!(None, find_patch(&program));
+    }
+}
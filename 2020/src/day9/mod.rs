@@ -0,0 +1,109 @@
+use advent_common::{Error, Result, Solver};
+use std::collections::VecDeque;
+
+const PREAMBLE_SIZE: usize = 25;
+
+fn is_sum_of_any_pair(number: usize, past: &VecDeque<usize>) -> bool {
+    for m in past {
+        for n in past {
+            if m != n && m + n == number {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Parses one number per line.
+pub fn parse_numbers(text: &str) -> Result<Vec<usize>> {
+    let mut numbers = Vec::new();
+    for line in text.lines() {
+        numbers.push(line.parse()?);
+    }
+    Ok(numbers)
+}
+
+/// Returns the first of the specified numbers, after a preamble of the
+/// specified memory length, that isn't the sum of two in memory.
+pub fn solve_part1(numbers: &[usize], memory: usize) -> Result<usize> {
+    let (preamble, rest) = numbers.split_at(memory.min(numbers.len()));
+    let mut past: VecDeque<usize> = preamble.iter().cloned().collect();
+    for &number in rest {
+        if !is_sum_of_any_pair(number, &past) {
+            return Ok(number);
+        }
+        past.pop_front();
+        past.push_back(number);
+    }
+    Err(Error::no_solution())
+}
+
+/// Returns the sum of the smallest and largest of the specified numbers.
+fn sum_min_max(numbers: &[usize]) -> usize {
+    let min = numbers.iter().min().unwrap();
+    let max = numbers.iter().max().unwrap();
+    min + max
+}
+
+/// Returns the sum of the first and last numbers of a contiguous subsequence
+/// of the specified numbers that adds up to the specified series.
+pub fn solve_part2(numbers: &[usize], series: usize) -> Result<usize> {
+    let sums: Vec<usize> = numbers // sum of numbers up to each index, inclusive
+        .iter()
+        .scan(0, |sum, number| {
+            *sum += number;
+            Some(*sum)
+        })
+        .collect();
+    for end in 1..sums.len() {
+        if sums[end] == series {
+            return Ok(sum_min_max(&numbers[0..end]));
+        }
+        for begin in 0..end {
+            if sums[end] - sums[begin] == series {
+                return Ok(sum_min_max(&numbers[(begin + 1)..end]));
+            }
+        }
+    }
+    Err(Error::no_solution())
+}
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    type Puzzle<'a> = Vec<usize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<usize>> {
+        parse_numbers(text)
+    }
+
+    fn part1(numbers: &Vec<usize>) -> Result<usize> {
+        solve_part1(numbers, PREAMBLE_SIZE)
+    }
+
+    fn part2(numbers: &Vec<usize>) -> Result<usize> {
+        solve_part2(numbers, solve_part1(numbers, PREAMBLE_SIZE)?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn sample1() {
+        let text = fs::read_to_string("tests/day9/sample1").unwrap();
+        let numbers = parse_numbers(&text).unwrap();
+        assert_eq!(127, solve_part1(&numbers, 5).unwrap());
+    }
+
+    #[test]
+    fn sample2() {
+        let text = fs::read_to_string("tests/day9/sample2").unwrap();
+        let numbers = parse_numbers(&text).unwrap();
+        assert_eq!(62, solve_part2(&numbers, 127).unwrap());
+    }
+}
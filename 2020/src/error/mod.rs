@@ -1,7 +0,0 @@
-mod empty_file;
-mod no_solution;
-mod parse_error;
-
-pub use empty_file::EmptyFile;
-pub use no_solution::NoSolution;
-pub use parse_error::ParseError;
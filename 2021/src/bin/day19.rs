@@ -0,0 +1,25 @@
+use advent2021::day19::{parse_cubes, solve, Cube};
+use advent_common::{input, trace, Error};
+
+fn load_cubes(input: &str) -> Result<Vec<Cube>, Error> {
+    parse_cubes(&input::read(input)?)
+}
+
+fn main() {
+    trace::init_from_args().unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        std::process::exit(2);
+    });
+    let _span = trace::span("2021 day19");
+    let input = &input::path_or("tests/day19/input");
+    let cubes = load_cubes(input).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let (answer1, answer2) = solve(&cubes).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    println!("{}", answer1);
+    println!("{}", answer2);
+}
@@ -0,0 +1,39 @@
+use advent2021::day20::{part1, part2, visualize, Puzzle};
+use advent_common::visualize::Recorder;
+use advent_common::{input, Error};
+
+fn load_puzzle(input: &str) -> Result<Puzzle, Error> {
+    Puzzle::parse(&input::read(input)?)
+}
+
+fn main() {
+    let input = &input::path_or("tests/day20/input");
+    let puzzle = load_puzzle(input).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    let recorder = Recorder::from_args().unwrap_or_else(|err| {
+        eprintln!("error: {}", err);
+        std::process::exit(2);
+    });
+    if let Some(mut recorder) = recorder {
+        if let Err(err) = visualize(&puzzle, &mut recorder) {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        }
+    }
+    match part1::solve(&puzzle) {
+        Ok(answer) => println!("{}", answer),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        }
+    }
+    match part2::solve(&puzzle) {
+        Ok(answer) => println!("{}", answer),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use advent2021::day21::{part1, part2, Puzzle};
+use advent_common::{input, Error};
+
+fn load_puzzle(input: &str) -> Result<Puzzle, Error> {
+    Puzzle::parse(&input::read(input)?)
+}
+
+fn main() {
+    let input = &input::path_or("tests/day21/input");
+    let puzzle = load_puzzle(input).unwrap_or_else(|err| {
+        eprintln!("error: {}: {}", input, err);
+        std::process::exit(3);
+    });
+    println!("{}", part1::solve(&puzzle));
+    println!("{}", part2::solve(&puzzle));
+}
@@ -2,23 +2,17 @@
 //!
 //! This program is meant to be be copied and tweaked for each day of Advent.
 
-use advent2021::ParseError;
-use std::fs::File;
-use std::io::{BufRead as _, BufReader};
-use std::path::Path;
+use advent_common::{input, Error};
 
 pub struct Puzzle {
     numbers: Vec<i32>,
 }
 
 impl Puzzle {
-    fn from_file<P>(input: P) -> Result<Puzzle, ParseError>
-    where
-        P: AsRef<Path>,
-    {
+    fn parse(text: &str) -> Result<Puzzle, Error> {
         let mut numbers = Vec::new();
-        for line in BufReader::new(File::open(input)?).lines() {
-            numbers.push(line?.parse()?);
+        for line in text.lines() {
+            numbers.push(line.parse()?);
         }
         Ok(Puzzle { numbers })
     }
@@ -44,10 +38,13 @@ pub mod part1 {
 }
 
 fn main() {
-    let input = "tests/dayN/input";
-    let puzzle = Puzzle::from_file(input).unwrap_or_else(|err| {
-        eprintln!("error: {}: {}", input, err);
-        std::process::exit(3);
-    });
+    let input = &input::path_or("tests/dayN/input");
+    let puzzle = input::read(input)
+        .map_err(Error::from)
+        .and_then(|text| Puzzle::parse(&text))
+        .unwrap_or_else(|err| {
+            eprintln!("error: {}: {}", input, err);
+            std::process::exit(3);
+        });
     println!("{}", part1::solve(&puzzle));
 }
@@ -0,0 +1,80 @@
+use advent_common::{Error, Solver};
+use std::num::ParseIntError;
+
+pub fn parse_depths(text: &str) -> Result<Vec<u32>, ParseIntError> {
+    text.lines().map(str::parse).collect()
+}
+
+pub mod part1 {
+    // O(N) time, O(1) space
+    pub fn solve<I>(depths: I) -> usize
+    where
+        I: IntoIterator<Item = u32>,
+    {
+        depths
+            .into_iter()
+            .scan(None, |old: &mut Option<u32>, new| {
+                Some(old.replace(new).filter(|&old| new > old).is_some())
+            })
+            .filter(|&b| b)
+            .count()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_depths;
+        use super::solve;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let text = fs::read_to_string("tests/day1/sample").unwrap();
+            let depths = parse_depths(&text).unwrap();
+            assert_eq!(7, solve(depths.iter().cloned()));
+        }
+    }
+}
+
+pub mod part2 {
+    use super::part1;
+
+    const WINDOW_SIZE: usize = 3;
+
+    // O(N) time, O(1) space; but requires input taking O(N) space
+    pub fn solve(depths: &[u32]) -> usize {
+        part1::solve(depths.windows(WINDOW_SIZE).map(|win| win.iter().sum()))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_depths;
+        use super::solve;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let text = fs::read_to_string("tests/day1/sample").unwrap();
+            assert_eq!(5, solve(&parse_depths(&text).unwrap()));
+        }
+    }
+}
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    type Puzzle<'a> = Vec<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<u32>, Error> {
+        Ok(parse_depths(text)?)
+    }
+
+    fn part1(depths: &Vec<u32>) -> Result<usize, Error> {
+        Ok(part1::solve(depths.iter().cloned()))
+    }
+
+    fn part2(depths: &Vec<u32>) -> Result<usize, Error> {
+        Ok(part2::solve(depths))
+    }
+}
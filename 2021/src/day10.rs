@@ -0,0 +1,197 @@
+use advent_common::parse;
+use advent_common::{Error, Solver};
+
+const PAIRS: [(u8, u8); 4] = [(b'(', b')'), (b'[', b']'), (b'{', b'}'), (b'<', b'>')];
+
+fn is_bracket(c: char) -> bool {
+    PAIRS.iter().any(|&(o, d)| c == o as char || c == d as char)
+}
+
+pub fn parse_lines(text: &str) -> Result<Vec<Vec<u8>>, Error> {
+    parse::all(text, |p| {
+        p.lines(|p| {
+            let line = p.take_while(is_bracket);
+            match p.peek() {
+                Some('\n' | '\r') | None => Ok(line.as_bytes().to_vec()),
+                Some(_) => Err(p.error("expected bracket")),
+            }
+        })
+    })
+}
+
+fn find_matching_opener(closer: u8) -> Option<u8> {
+    PAIRS
+        .into_iter()
+        .find(|&(_, c)| c == closer)
+        .map(|(o, _)| o)
+}
+
+fn find_matching_closer(opener: u8) -> Option<u8> {
+    PAIRS
+        .into_iter()
+        .find(|&(o, _)| o == opener)
+        .map(|(_, c)| c)
+}
+
+// To support mapping over sequences of Vec<u8> lines,
+// `find_corrupt_closer` accepts references to them directly, not
+// references to slices.  A fn(&[u8]) can be passed a &Vec<u8> thanks to
+// Borrow, but cannot be passed to Iterator<Item = Vec<u8>>::map.  For that
+// reason, we ignore Clippy's suggestion that `find_corrupt_closer` accept
+// a slice rather than a Vec.
+#[allow(clippy::ptr_arg)]
+fn find_corrupt_closer(line: &Vec<u8>) -> Option<u8> {
+    let mut openers = Vec::new();
+    for &c in line {
+        if let Some(o) = find_matching_opener(c) {
+            if openers.pop() != Some(o) {
+                return Some(c);
+            }
+        } else {
+            openers.push(c);
+        }
+    }
+    None
+}
+
+pub mod part1 {
+    use super::*;
+
+    const SCORES: [u64; 4] = [3, 57, 1197, 25137];
+
+    fn score(closer: u8) -> Option<u64> {
+        let index = PAIRS.iter().position(|&(_, c)| c == closer)?;
+        Some(SCORES[index])
+    }
+
+    pub fn solve(lines: &[Vec<u8>]) -> u64 {
+        lines
+            .iter()
+            .filter_map(find_corrupt_closer)
+            .filter_map(score)
+            .sum()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let lines = parse_lines(&fs::read_to_string("tests/day10/sample").unwrap()).unwrap();
+            assert_eq!(26397, solve(&lines));
+        }
+    }
+}
+
+pub mod part2 {
+    use super::*;
+
+    const CLOSERS: [u8; 4] = *b")]}>";
+
+    fn find_openers(line: &[u8]) -> Vec<u8> {
+        let mut openers = Vec::new();
+        for c in line {
+            if CLOSERS.contains(c) {
+                openers.pop();
+            } else {
+                openers.push(*c);
+            }
+        }
+        openers
+    }
+
+    fn complete(openers: &[u8]) -> impl Iterator<Item = u8> + '_ {
+        openers.iter().cloned().flat_map(find_matching_closer).rev()
+    }
+
+    fn score_closers<I>(closers: I) -> u64
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        closers
+            .into_iter()
+            .flat_map(|b| CLOSERS.iter().position(|&c| b == c))
+            .fold(0, |score, index| score * 5 + index as u64 + 1)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    fn score_line(line: &Vec<u8>) -> u64 {
+        score_closers(complete(&find_openers(line)))
+    }
+
+    pub fn solve(lines: &[Vec<u8>]) -> Result<u64, Error> {
+        let mut scores: Vec<_> = lines
+            .iter()
+            .filter(|v| find_corrupt_closer(v).is_none())
+            .map(score_line)
+            .collect();
+        if scores.is_empty() {
+            return Err(Error::no_solution()); // Every line is corrupt.
+        }
+        let n = scores.len() / 2;
+        Ok(*scores.select_nth_unstable(n).1)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use advent_common::ErrorKind;
+        use std::fs;
+
+        #[test]
+        fn test_score_closers() {
+            assert_eq!(288957, score_closers(*b"}}]])})]"));
+        }
+
+        #[test]
+        fn test_solve() {
+            let lines = parse_lines(&fs::read_to_string("tests/day10/sample").unwrap()).unwrap();
+            assert_eq!(288957, solve(&lines).unwrap());
+        }
+
+        #[test]
+        fn test_all_corrupt() {
+            let lines = parse_lines(")\n(]\n").unwrap();
+            assert_eq!(3 + 57, part1::solve(&lines));
+            let err = solve(&lines).unwrap_err();
+            assert_eq!(ErrorKind::NoSolution, err.kind());
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Puzzle<'a> = Vec<Vec<u8>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<Vec<Vec<u8>>, Error> {
+        parse_lines(text)
+    }
+
+    fn part1(lines: &Vec<Vec<u8>>) -> Result<u64, Error> {
+        Ok(part1::solve(lines))
+    }
+
+    fn part2(lines: &Vec<Vec<u8>>) -> Result<u64, Error> {
+        part2::solve(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_lines() {
+        assert_eq!(
+            vec![b"(]".to_vec(), b"<>".to_vec()],
+            parse_lines("(]\n<>\n").unwrap()
+        );
+        let err = parse_lines("()\n(x)\n").unwrap_err();
+        assert_eq!("2:2: expected bracket", err.to_string());
+    }
+}
@@ -0,0 +1,252 @@
+use advent_common::visualize::{Draw, Recorder, Rgb};
+use advent_common::{grid, Error, Solver};
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+
+type Point = (usize, usize);
+
+const E: u8 = 10; // energy level at which octopuses flash
+
+/// The energy level of an octopus, drawn brighter the more energy it has,
+/// and white just after it flashes.
+#[derive(Clone, Copy)]
+pub struct Energy(u8);
+
+impl Draw for Energy {
+    fn glyph(&self) -> char {
+        char::from_digit(self.0.into(), 16).unwrap_or('?')
+    }
+
+    fn color(&self) -> Rgb {
+        match self.0 {
+            0 => [255, 255, 255],
+            level => {
+                let shade = level.min(E) * 20;
+                [0, shade / 2, shade]
+            }
+        }
+    }
+}
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct Grid(Vec<Vec<u8>>);
+
+impl Grid {
+    fn at(&self, p: Point) -> u8 {
+        self.0[p.0][p.1]
+    }
+
+    fn dim(&self) -> Point {
+        (self.0.len(), self.0[0].len())
+    }
+
+    fn points(&self) -> impl Iterator<Item = Point> {
+        let (m, n) = self.dim();
+        (0..m).flat_map(move |i| (0..n).map(move |j| (i, j)))
+    }
+
+    fn set(&mut self, p: Point, value: u8) {
+        self.0[p.0][p.1] = value;
+    }
+
+    fn flashpoints(&self) -> impl Iterator<Item = Point> + '_ {
+        self.points().filter(|&p| self.at(p) >= E)
+    }
+
+    fn increment_all(&mut self) {
+        self.0
+            .iter_mut()
+            .flat_map(|row| row.iter_mut())
+            .for_each(|cell| *cell += 1);
+    }
+
+    fn increment_neighborhood(&mut self, p: Point) {
+        let (m, n) = self.dim();
+        let (ia, iz) = (p.0.max(1) - 1, (p.0 + 2).min(m));
+        let (ja, jz) = (p.1.max(1) - 1, (p.1 + 2).min(n));
+        self.0[ia..iz]
+            .iter_mut()
+            .flat_map(|row| row[ja..jz].iter_mut())
+            .for_each(|cell| *cell += 1);
+    }
+
+    pub fn parse(text: &str) -> Result<Self, Error> {
+        let levels = grid::Grid::parse(text, |b| match b {
+            b'0'..=b'9' => Ok(b - b'0'),
+            _ => Err(Error::parse(format!("{}: bad energy level", b as char))),
+        })?;
+        if levels.is_empty() {
+            return Err(Error::empty_input());
+        }
+        Ok(Grid(levels.rows().map(<[u8]>::to_vec).collect()))
+    }
+
+    pub fn energies(&self) -> grid::Grid<Energy> {
+        let (m, n) = self.dim();
+        grid::Grid::from_fn(m, n, |pos| Energy(self.at((pos.row, pos.column))))
+    }
+
+    /// Advances the grid, and returns the number of new flashes.
+    fn step(&mut self) -> usize {
+        self.increment_all();
+        let mut seen: HashSet<Point> = HashSet::new();
+        let mut news: Vec<_> = self.flashpoints().collect();
+        while !news.is_empty() {
+            seen.extend(news.iter().cloned());
+            news.iter().for_each(|&p| self.increment_neighborhood(p));
+            news.clear();
+            news.extend(self.flashpoints().filter(|p| !seen.contains(p)));
+        }
+        seen.iter().for_each(|&p| self.set(p, 0));
+        seen.len()
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        for row in &self.0 {
+            for cell in row {
+                write!(f, "{:X}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Records each step of the octopuses' dance, until they all flash at once.
+pub fn visualize(mut grid: Grid, recorder: &mut Recorder) -> Result<(), Error> {
+    let (m, n) = grid.dim();
+    recorder.record(&grid.energies())?;
+    loop {
+        let flashes = grid.step();
+        recorder.record(&grid.energies())?;
+        if flashes == m * n {
+            return Ok(());
+        }
+    }
+}
+
+pub mod part1 {
+    use super::*;
+
+    pub fn solve(mut grid: Grid) -> usize {
+        let mut count = 0;
+        for _ in 0..100 {
+            count += grid.step();
+        }
+        count
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let grid =
+                Grid::parse(&fs::read_to_string("tests/day11/sample10x10").unwrap()).unwrap();
+            assert_eq!(1656, solve(grid));
+        }
+    }
+}
+
+pub mod part2 {
+    use super::*;
+
+    pub fn solve(mut grid: Grid) -> usize {
+        let (m, n) = grid.dim();
+        let mut count = 1;
+        while grid.step() != m * n {
+            count += 1;
+        }
+        count
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let grid =
+                Grid::parse(&fs::read_to_string("tests/day11/sample10x10").unwrap()).unwrap();
+            assert_eq!(195, solve(grid));
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Puzzle<'a> = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Grid, Error> {
+        Grid::parse(text)
+    }
+
+    fn part1(grid: &Grid) -> Result<usize, Error> {
+        Ok(part1::solve(grid.clone()))
+    }
+
+    fn part2(grid: &Grid) -> Result<usize, Error> {
+        Ok(part2::solve(grid.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_grid_parse() {
+        let want = vec![
+            b"11111".map(|b| b - b'0').to_vec(),
+            b"19991".map(|b| b - b'0').to_vec(),
+            b"19191".map(|b| b - b'0').to_vec(),
+            b"19991".map(|b| b - b'0').to_vec(),
+            b"11111".map(|b| b - b'0').to_vec(),
+        ];
+        let grid = Grid::parse(&fs::read_to_string("tests/day11/sample3x3").unwrap()).unwrap();
+        assert_eq!(want, grid.0);
+    }
+
+    #[test]
+    fn test_grid_step3x3() {
+        let mut grid = Grid::parse(&fs::read_to_string("tests/day11/sample3x3").unwrap()).unwrap();
+        for (rows, flash_count) in [
+            ([b"34543", b"40004", b"50005", b"40004", b"34543"], 9),
+            ([b"45654", b"51115", b"61116", b"51115", b"45654"], 0),
+        ] {
+            let rows: Vec<Vec<u8>> = rows
+                .iter()
+                .map(|row| row.iter().map(|b| b - b'0').collect())
+                .collect();
+            assert_eq!(flash_count, grid.step());
+            assert_eq!(rows, grid.0);
+        }
+    }
+
+    #[test]
+    fn test_grid_energies() {
+        let mut grid = Grid::parse(&fs::read_to_string("tests/day11/sample3x3").unwrap()).unwrap();
+        grid.step();
+        let energies = grid.energies();
+        let glyphs: String = energies.row(1).iter().map(Draw::glyph).collect();
+        assert_eq!(glyphs, "40004");
+        assert_eq!(energies.row(1)[1].color(), [255, 255, 255]);
+    }
+
+    #[test]
+    fn test_grid_step10x10() {
+        let mut grid =
+            Grid::parse(&fs::read_to_string("tests/day11/sample10x10").unwrap()).unwrap();
+        assert_eq!(0, grid.step());
+        assert_eq!(35, grid.step());
+    }
+}
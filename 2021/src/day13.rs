@@ -0,0 +1,213 @@
+use advent_common::{Error, Solver};
+use std::collections::HashSet;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+type Point = (usize, usize); // (x, y)
+
+#[derive(Clone, Copy)]
+enum Fold {
+    X(usize), // fold left
+    Y(usize), // fold up
+}
+
+impl FromStr for Fold {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bytes = s.as_bytes();
+        if bytes.len() < 3 || bytes[1] != b'=' {
+            return Err(Error::parse(format!("bad fold: {}", s)));
+        }
+        let (axis, index) = (bytes[0], s[2..].parse()?);
+        Ok(match axis {
+            b'x' => Fold::X(index),
+            b'y' => Fold::Y(index),
+            _ => {
+                let what = format!("bad fold; axis must be x or y: {}", s);
+                return Err(Error::parse(what));
+            }
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct Page {
+    points: HashSet<Point>,
+}
+
+impl Page {
+    fn fold_left(&mut self, x: usize) {
+        let olds: Vec<_> = self.points.iter().cloned().filter(|p| p.0 > x).collect();
+        for p in olds {
+            self.points.insert((x - (p.0 - x), p.1));
+            self.points.remove(&p);
+        }
+    }
+
+    fn fold_up(&mut self, y: usize) {
+        let olds: Vec<_> = self.points.iter().cloned().filter(|p| p.1 > y).collect();
+        for p in olds {
+            self.points.insert((p.0, y - (p.1 - y)));
+            self.points.remove(&p);
+        }
+    }
+
+    fn fold(&mut self, fold: Fold) {
+        match fold {
+            Fold::X(index) => self.fold_left(index),
+            Fold::Y(index) => self.fold_up(index),
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.points
+            .iter()
+            .map(|p| p.1 + 1)
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn width(&self) -> usize {
+        self.points
+            .iter()
+            .map(|p| p.0 + 1)
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl Display for Page {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let (w, h) = (self.width(), self.height());
+        for y in 0..h {
+            for x in 0..w {
+                let c = if self.points.contains(&(x, y)) {
+                    '#'
+                } else {
+                    ' '
+                };
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone)]
+pub struct Puzzle {
+    page: Page,
+    folds: Vec<Fold>,
+}
+
+fn parse_page<'a, I>(lines: &mut I) -> Result<Page, Error>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let mut points = HashSet::new();
+    for line in lines {
+        if line.is_empty() {
+            break;
+        }
+        let (x, y) = line
+            .split_once(',')
+            .ok_or_else(|| Error::parse(format!("bad point: {}", line)))?;
+        points.insert((x.parse()?, y.parse()?));
+    }
+    Ok(Page { points })
+}
+
+fn parse_folds<'a, I>(lines: &mut I) -> Result<Vec<Fold>, Error>
+where
+    I: Iterator<Item = &'a str>,
+{
+    const FOLD_PREFIX: &str = "fold along ";
+    let mut folds = Vec::new();
+    for line in lines {
+        if !line.starts_with(FOLD_PREFIX) {
+            let what = format!("expected fold; got: {}", line);
+            return Err(Error::parse(what));
+        }
+        folds.push(line[FOLD_PREFIX.len()..].parse()?);
+    }
+    Ok(folds)
+}
+
+pub fn parse_puzzle(text: &str) -> Result<Puzzle, Error> {
+    let mut lines = text.lines();
+    Ok(Puzzle {
+        page: parse_page(&mut lines)?,
+        folds: parse_folds(&mut lines)?,
+    })
+}
+
+pub mod part1 {
+    use super::*;
+
+    pub fn solve(mut puzzle: Puzzle) -> usize {
+        let first = *puzzle.folds.first().expect("missing fold");
+        puzzle.page.fold(first);
+        puzzle.page.points.len()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_puzzle;
+        use super::solve;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let puzzle = parse_puzzle(&fs::read_to_string("tests/day13/sample").unwrap()).unwrap();
+            assert_eq!(17, solve(puzzle));
+        }
+    }
+}
+
+pub mod part2 {
+    use super::*;
+
+    pub fn solve(mut puzzle: Puzzle) -> Page {
+        puzzle.folds.iter().for_each(|&f| puzzle.page.fold(f));
+        puzzle.page
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_puzzle;
+        use super::solve;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let want = "#####\n\
+                        #   #\n\
+                        #   #\n\
+                        #   #\n\
+                        #####\n";
+            let puzzle = parse_puzzle(&fs::read_to_string("tests/day13/sample").unwrap()).unwrap();
+            assert_eq!(want, format!("{}", solve(puzzle)));
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    type Puzzle<'a> = Puzzle;
+    type Answer1 = usize;
+    type Answer2 = Page;
+
+    fn parse(text: &str) -> Result<Puzzle, Error> {
+        parse_puzzle(text)
+    }
+
+    fn part1(puzzle: &Puzzle) -> Result<usize, Error> {
+        Ok(part1::solve(puzzle.clone()))
+    }
+
+    fn part2(puzzle: &Puzzle) -> Result<Page, Error> {
+        Ok(part2::solve(puzzle.clone()))
+    }
+}
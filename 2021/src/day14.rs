@@ -0,0 +1,182 @@
+use advent_common::{Error, Solver};
+use std::collections::HashMap;
+
+type Pair = [u8; 2];
+
+pub struct Puzzle {
+    chain: Vec<u8>,
+    rules: HashMap<Pair, u8>,
+}
+
+pub fn parse_puzzle(text: &str) -> Result<Puzzle, Error> {
+    let mut lines = text.lines();
+    let chain = lines
+        .next()
+        .ok_or_else(|| Error::parse("empty input"))?
+        .bytes()
+        .collect();
+    let line = lines
+        .next()
+        .ok_or_else(|| Error::parse("expected empty line"))?;
+    if !line.is_empty() {
+        let what = format!("expected empty line; got {}", line);
+        return Err(Error::parse(what));
+    }
+    let mut rules = HashMap::new();
+    for line in lines {
+        let bytes = line.as_bytes();
+        if bytes.len() != 7 {
+            let what = format!("expected rule; got {}", line);
+            return Err(Error::parse(what));
+        }
+        rules.insert([bytes[0], bytes[1]], bytes[6]);
+    }
+    Ok(Puzzle { chain, rules })
+}
+
+fn count_pairs(chain: &[u8]) -> HashMap<Pair, usize> {
+    let mut counts = HashMap::new();
+    for i in 0..(chain.len() - 1) {
+        let key = [chain[i], chain[i + 1]];
+        *counts.entry(key).or_default() += 1;
+    }
+    counts
+}
+
+fn update(counts: HashMap<Pair, usize>, rules: &HashMap<Pair, u8>) -> HashMap<Pair, usize> {
+    let mut result = HashMap::new();
+    for (key, count) in counts {
+        if rules.contains_key(&key) {
+            let (a, b, c) = (key[0], rules[&key], key[1]);
+            *result.entry([a, b]).or_default() += count;
+            *result.entry([b, c]).or_default() += count;
+        } else {
+            *result.entry(key).or_default() += count;
+        }
+    }
+    result
+}
+
+fn solve(puzzle: &Puzzle, n: usize) -> usize {
+    let mut counts = count_pairs(&puzzle.chain);
+    for _ in 0..n {
+        counts = update(counts, &puzzle.rules);
+    }
+
+    let mut c_counts: HashMap<u8, usize> = HashMap::new();
+    for (k, v) in counts {
+        *c_counts.entry(k[0]).or_default() += v;
+    }
+    *c_counts.entry(*puzzle.chain.last().unwrap()).or_default() += 1;
+    c_counts.values().max().unwrap() - c_counts.values().min().unwrap()
+}
+
+pub mod part1 {
+    use super::*;
+
+    pub fn solve(puzzle: &Puzzle) -> usize {
+        super::solve(puzzle, 10)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_puzzle;
+        use super::solve;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let puzzle = parse_puzzle(&fs::read_to_string("tests/day14/sample").unwrap()).unwrap();
+            assert_eq!(1588, solve(&puzzle));
+        }
+    }
+}
+
+pub mod part2 {
+    use super::*;
+
+    pub fn solve(puzzle: &Puzzle) -> usize {
+        super::solve(puzzle, 40)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_puzzle;
+        use super::solve;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let puzzle = parse_puzzle(&fs::read_to_string("tests/day14/sample").unwrap()).unwrap();
+            assert_eq!(2188189693529, solve(&puzzle));
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    type Puzzle<'a> = Puzzle;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Puzzle, Error> {
+        parse_puzzle(text)
+    }
+
+    fn part1(puzzle: &Puzzle) -> Result<usize, Error> {
+        Ok(part1::solve(puzzle))
+    }
+
+    fn part2(puzzle: &Puzzle) -> Result<usize, Error> {
+        Ok(part2::solve(puzzle))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_common::prop::{self, range};
+
+    const ELEMENTS: &[u8] = b"ABC";
+
+    /// Inserts elements between pairs one step at a time, as the puzzle
+    /// describes, and returns the spread of element counts.
+    fn expand(puzzle: &Puzzle, n: usize) -> usize {
+        let mut chain = puzzle.chain.clone();
+        for _ in 0..n {
+            let mut next = vec![chain[0]];
+            for pair in chain.windows(2) {
+                if let Some(&b) = puzzle.rules.get(&[pair[0], pair[1]]) {
+                    next.push(b);
+                }
+                next.push(pair[1]);
+            }
+            chain = next;
+        }
+        let mut counts: HashMap<u8, usize> = HashMap::new();
+        for b in chain {
+            *counts.entry(b).or_default() += 1;
+        }
+        counts.values().max().unwrap() - counts.values().min().unwrap()
+    }
+
+    #[test]
+    fn test_solve_matches_expansion() {
+        // Each pair of elements has a rule inserting one of them, or none.
+        let chain = prop::vec(range(0u8..=2), 1..=6);
+        let rules = prop::vec(range(0u8..=3), 9..=9);
+        prop::check((chain, rules, range(0usize..=6)), |(chain, rules, n)| {
+            let mut puzzle = Puzzle {
+                chain: chain.iter().map(|&i| ELEMENTS[usize::from(i)]).collect(),
+                rules: HashMap::new(),
+            };
+            for (i, &rule) in rules.iter().enumerate() {
+                if let Some(&b) = ELEMENTS.get(usize::from(rule)) {
+                    puzzle.rules.insert([ELEMENTS[i / 3], ELEMENTS[i % 3]], b);
+                }
+            }
+            solve(&puzzle, *n) == expand(&puzzle, *n)
+        });
+    }
+}
@@ -0,0 +1,113 @@
+use advent_common::grid::{Grid, Position};
+use advent_common::{search, Error, Solver};
+
+type Cave = Grid<u8>;
+
+pub fn parse_cave(text: &str) -> Result<Cave, Error> {
+    Grid::parse(text, |b| match b {
+        b'0'..=b'9' => Ok(b - b'0'),
+        _ => Err(Error::parse(format!("{}: bad risk level", b as char))),
+    })
+}
+
+/// Returns the lowest total risk of any path from the top left corner of the
+/// cave to the bottom right.
+pub fn dijkstra(cave: &Cave) -> usize {
+    let end = Position::new(cave.height() - 1, cave.width() - 1);
+    search::dijkstra(
+        [Position::new(0, 0)],
+        |&p| cave.neighbors4(p).map(|q| (q, cave[q] as usize)),
+        |&p| p == end,
+    )
+    .expect("end is unreachable from start")
+    .cost
+}
+
+pub mod part1 {
+    pub use super::dijkstra as solve;
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_cave;
+        use super::solve;
+        use std::fs;
+
+        #[test]
+        fn test_solve_tiny() {
+            [(2, 6), (3, 20)].into_iter().for_each(|(size, want)| {
+                let file = format!("tests/day15/tiny{}", size);
+                let cave = parse_cave(&fs::read_to_string(file).unwrap()).unwrap();
+                assert_eq!(want, solve(&cave));
+            });
+        }
+
+        #[test]
+        fn test_solve() {
+            let cave = parse_cave(&fs::read_to_string("tests/day15/sample").unwrap()).unwrap();
+            assert_eq!(40, solve(&cave));
+        }
+
+        #[test]
+        fn test_solve_sample2() {
+            let cave = parse_cave(&fs::read_to_string("tests/day15/sample2").unwrap()).unwrap();
+            assert_eq!(315, solve(&cave));
+        }
+    }
+}
+
+pub mod part2 {
+    use super::*;
+
+    const GROWTH_FACTOR: usize = 5;
+
+    fn roll(risk: u8) -> u8 {
+        risk % 9 + 1
+    }
+
+    /// Returns the full cave, of which the scanned cave is only one tile.
+    fn extend(cave: &Cave) -> Cave {
+        let (m, n) = (cave.height(), cave.width());
+        Grid::from_fn(m * GROWTH_FACTOR, n * GROWTH_FACTOR, |p| {
+            let tile = p.row / m + p.column / n;
+            let risk = cave[Position::new(p.row % m, p.column % n)];
+            (0..tile).fold(risk, |risk, _| roll(risk))
+        })
+    }
+
+    pub fn solve(cave: &Cave) -> usize {
+        dijkstra(&extend(cave))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_cave;
+        use super::solve;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let cave = parse_cave(&fs::read_to_string("tests/day15/sample").unwrap()).unwrap();
+            assert_eq!(315, solve(&cave));
+        }
+    }
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    type Puzzle<'a> = Cave;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Cave, Error> {
+        parse_cave(text)
+    }
+
+    fn part1(cave: &Cave) -> Result<usize, Error> {
+        Ok(part1::solve(cave))
+    }
+
+    fn part2(cave: &Cave) -> Result<usize, Error> {
+        Ok(part2::solve(cave))
+    }
+}
@@ -1,16 +1,16 @@
-use advent2021::ParseError;
+use advent_common::Error;
 use std::ops::{Add, Sub};
 use std::str::FromStr;
 
 pub type Offset = (i32, i32, i32); // dx, dy, dz
 
-fn next_coord<'a, I>(coords: &mut I) -> Result<i32, ParseError>
+fn next_coord<'a, I>(coords: &mut I) -> Result<i32, Error>
 where
     I: Iterator<Item = &'a str>,
 {
     Ok(coords
         .next()
-        .ok_or_else(|| ParseError::new("expected coordinate"))?
+        .ok_or_else(|| Error::parse("expected coordinate"))?
         .parse()?)
 }
 
@@ -38,7 +38,7 @@ impl Beacon {
 }
 
 impl FromStr for Beacon {
-    type Err = ParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let coords = &mut s.split(',');
@@ -49,7 +49,7 @@ impl FromStr for Beacon {
         );
         if let Some(extra) = coords.next() {
             let what = format!("expected end of line, not {}", extra);
-            return Err(ParseError::new(what));
+            return Err(Error::parse(what));
         }
         Ok(beacon)
     }
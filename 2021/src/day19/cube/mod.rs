@@ -1,9 +1,9 @@
 mod parse;
 mod rotation;
 
-pub use parse::cubes_from_file;
+pub use parse::parse_cubes;
 
-use crate::beacon::{Beacon, Offset};
+use crate::day19::beacon::{Beacon, Offset};
 use rotation::Rotations;
 use std::collections::HashSet;
 
@@ -27,9 +27,9 @@ impl Cube {
         // https://doc.rust-lang.org/std/primitive.i32.html#method.abs_diff
         let (x0, y0, z0) = self.scanner;
         let (x1, y1, z1) = other.scanner;
-        let dx = (x1 - x0).abs() as usize;
-        let dy = (y1 - y0).abs() as usize;
-        let dz = (z1 - z0).abs() as usize;
+        let dx = (x1 - x0).unsigned_abs() as usize;
+        let dy = (y1 - y0).unsigned_abs() as usize;
+        let dz = (z1 - z0).unsigned_abs() as usize;
         dx + dy + dz
     }
 
@@ -79,6 +79,7 @@ impl Cube {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
 
     /// Beacons shared by scanners 0 and 1 in the sample rotations.
     const TEST_TRANSFORM01_WANT_BEACONS: [Beacon; MIN_OVERLAP] = [
@@ -122,7 +123,8 @@ mod tests {
 
     #[test]
     fn test_rotations_match() {
-        let cubes = cubes_from_file("tests/day19/sample-rotations").unwrap();
+        let cubes =
+            parse_cubes(&fs::read_to_string("tests/day19/sample-rotations").unwrap()).unwrap();
         for i in 0..cubes.len() {
             let got = cubes[i].rotations().find(|cube| cube == &cubes[0]);
             assert!(got.is_some(), "expected matching rotation; i={}", i);
@@ -131,20 +133,21 @@ mod tests {
 
     #[test]
     fn test_rotations_do_not_match() {
-        let cubes = cubes_from_file("tests/day19/sample-rotations").unwrap();
+        let cubes =
+            parse_cubes(&fs::read_to_string("tests/day19/sample-rotations").unwrap()).unwrap();
         let runt = Cube {
             beacons: cubes[0].beacons().skip(1).collect(),
             scanner: (0, 0, 0),
         };
-        for i in 0..cubes.len() {
-            let got = cubes[i].rotations().find(|cube| cube == &runt);
+        for (i, cube) in cubes.iter().enumerate() {
+            let got = cube.rotations().find(|cube| cube == &runt);
             assert!(got.is_none(), "unexpected matching rotation; i={}", i);
         }
     }
 
     #[test]
     fn test_transform01() {
-        let cubes = cubes_from_file("tests/day19/sample").unwrap();
+        let cubes = parse_cubes(&fs::read_to_string("tests/day19/sample").unwrap()).unwrap();
         let cube0 = &cubes[0];
         let cube1 = &cube0.transform(&cubes[1]).expect("failure to transform");
         assert_intersection(cube0, cube1, TEST_TRANSFORM01_WANT_BEACONS);
@@ -152,7 +155,7 @@ mod tests {
 
     #[test]
     fn test_transform14() {
-        let cubes = cubes_from_file("tests/day19/sample").unwrap();
+        let cubes = parse_cubes(&fs::read_to_string("tests/day19/sample").unwrap()).unwrap();
         let cube0 = &cubes[0];
         let cube1 = &cube0.transform(&cubes[1]).expect("failure to transform");
         let cube4 = &cube1.transform(&cubes[4]).expect("failure to transform");
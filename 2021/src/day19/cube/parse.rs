@@ -0,0 +1,46 @@
+use super::Cube;
+use crate::day19::beacon::Beacon;
+use advent_common::Error;
+
+fn parse_beacons<'a, I>(lines: &mut I) -> Result<Vec<Beacon>, Error>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let mut beacons = Vec::new();
+    for line in lines {
+        if line.is_empty() {
+            break;
+        }
+        beacons.push(line.parse()?);
+    }
+    Ok(beacons)
+}
+
+impl Cube {
+    pub fn from_lines<'a, I>(lines: &mut I) -> Result<Option<Cube>, Error>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        if let Some(line) = lines.next() {
+            if !line.starts_with("--- scanner ") {
+                return Err(Error::parse("expected scanner"));
+            }
+            let beacons = parse_beacons(lines)?;
+            Ok(Some(Cube {
+                beacons,
+                scanner: (0, 0, 0),
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+pub fn parse_cubes(text: &str) -> Result<Vec<Cube>, Error> {
+    let mut cubes = Vec::new();
+    let lines = &mut text.lines();
+    while let Some(cube) = Cube::from_lines(lines)? {
+        cubes.push(cube);
+    }
+    Ok(cubes)
+}
@@ -1,4 +1,4 @@
-use crate::beacon::Beacon;
+use crate::day19::beacon::Beacon;
 
 fn fall_left(beacons: &[Beacon]) -> Vec<Beacon> {
     beacons.iter().map(|b| b.orbit_y_right()).collect()
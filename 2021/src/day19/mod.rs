@@ -0,0 +1,92 @@
+mod beacon;
+mod cube;
+
+use advent_common::{debug, Error, ErrorKind, Solver};
+pub use cube::{parse_cubes, Cube};
+use std::collections::HashSet;
+
+fn count_beacons(cubes: &[Cube]) -> usize {
+    let all_beacons: HashSet<_> = cubes.iter().flat_map(|c| c.beacons()).collect();
+    all_beacons.len()
+}
+
+fn max_scanner_distance(cubes: &[Cube]) -> usize {
+    cubes
+        .iter()
+        .flat_map(|a| cubes.iter().map(|b| a.distance(b)))
+        .max()
+        .expect("no scanners")
+}
+
+pub fn solve(cubes: &[Cube]) -> Result<(usize, usize), Error> {
+    let (first, rest) = cubes.split_first().ok_or_else(Error::empty_input)?;
+    let mut unconnected = Vec::from_iter(rest);
+    let mut transformed = vec![first.clone()]; // in scanner 0's frame of reference
+    let mut retired = Vec::new();
+    while let Some(old) = transformed.pop() {
+        let mut still_unconnected = Vec::new();
+        for new in unconnected {
+            if let Some(cube) = old.transform(new) {
+                transformed.push(cube);
+            } else {
+                still_unconnected.push(new);
+            }
+        }
+        unconnected = still_unconnected;
+        retired.push(old);
+        debug!(
+            "{} unconnected, {} transformed",
+            unconnected.len(),
+            transformed.len()
+        );
+    }
+    if !unconnected.is_empty() {
+        let what = "couldn't connect all cubes";
+        return Err(Error::new(ErrorKind::NoSolution, what));
+    }
+    Ok((count_beacons(&retired), max_scanner_distance(&retired)))
+}
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    type Puzzle<'a> = Vec<Cube>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<Cube>, Error> {
+        parse_cubes(text)
+    }
+
+    fn part1(cubes: &Vec<Cube>) -> Result<usize, Error> {
+        Ok(solve(cubes)?.0)
+    }
+
+    fn part2(cubes: &Vec<Cube>) -> Result<usize, Error> {
+        Ok(solve(cubes)?.1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solve() {
+        let cubes = parse_cubes(&fs::read_to_string("tests/day19/sample").unwrap()).unwrap();
+        let (answer1, answer2) = solve(&cubes).unwrap();
+        assert_eq!(79, answer1);
+        assert_eq!(3621, answer2);
+    }
+
+    #[test]
+    fn test_unconnected() {
+        let text = "--- scanner 0 ---\n1,2,3\n\n--- scanner 1 ---\n4,5,6\n";
+        let err = solve(&parse_cubes(text).unwrap()).unwrap_err();
+        assert_eq!(ErrorKind::NoSolution, err.kind());
+        assert_eq!("couldn't connect all cubes", err.message());
+        let err = solve(&[]).unwrap_err();
+        assert_eq!(ErrorKind::EmptyInput, err.kind());
+    }
+}
@@ -0,0 +1,134 @@
+use advent_common::{Error, Solver};
+use std::str::{FromStr, Lines};
+
+#[derive(Clone, Debug)]
+enum Axis {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Clone)]
+pub struct Command(Axis, i32);
+
+impl FromStr for Command {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, distance) = s
+            .split_once(' ')
+            .ok_or_else(|| Error::parse(format!("bad command: {}", s)))?;
+        let distance = distance.parse()?;
+        let command = match direction {
+            "forward" => Command(Axis::Horizontal, distance),
+            "down" => Command(Axis::Vertical, distance),
+            "up" => Command(Axis::Vertical, -distance),
+            _ => return Err(Error::parse(format!("bad direction: {}", direction))),
+        };
+        Ok(command)
+    }
+}
+
+pub struct Commands<'a> {
+    lines: Lines<'a>,
+}
+
+impl Commands<'_> {
+    pub fn parse(text: &str) -> Commands<'_> {
+        Commands {
+            lines: text.lines(),
+        }
+    }
+}
+
+impl Iterator for Commands<'_> {
+    type Item = Command;
+
+    fn next(&mut self) -> Option<Command> {
+        self.lines.next().and_then(|line| line.parse().ok())
+    }
+}
+
+pub mod part1 {
+    use super::{Axis, Command};
+
+    pub fn solve<I>(commands: I) -> i32
+    where
+        I: Iterator<Item = Command>,
+    {
+        let (mut hpos, mut depth) = (0, 0);
+        for Command(axis, offset) in commands {
+            match axis {
+                Axis::Horizontal => hpos += offset,
+                Axis::Vertical => depth += offset,
+            }
+        }
+        hpos * depth
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::Commands;
+        use super::solve;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let text = fs::read_to_string("tests/day2/sample").unwrap();
+            assert_eq!(150, solve(Commands::parse(&text)));
+        }
+    }
+}
+
+pub mod part2 {
+    use super::{Axis, Command};
+
+    pub fn solve<I>(commands: I) -> i32
+    where
+        I: Iterator<Item = Command>,
+    {
+        let (mut aim, mut hpos, mut depth) = (0, 0, 0);
+        for Command(axis, offset) in commands {
+            match axis {
+                Axis::Horizontal => {
+                    hpos += offset;
+                    depth += aim * offset;
+                }
+                Axis::Vertical => aim += offset,
+            }
+        }
+        hpos * depth
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::Commands;
+        use super::solve;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let text = fs::read_to_string("tests/day2/sample").unwrap();
+            assert_eq!(900, solve(Commands::parse(&text)));
+        }
+    }
+}
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    type Puzzle<'a> = Vec<Command>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(text: &str) -> Result<Vec<Command>, Error> {
+        Ok(Commands::parse(text).collect())
+    }
+
+    fn part1(commands: &Vec<Command>) -> Result<i32, Error> {
+        Ok(part1::solve(commands.iter().cloned()))
+    }
+
+    fn part2(commands: &Vec<Command>) -> Result<i32, Error> {
+        Ok(part2::solve(commands.iter().cloned()))
+    }
+}
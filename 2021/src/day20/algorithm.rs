@@ -1,5 +1,5 @@
-use crate::image::Image;
-use advent2021::ParseError;
+use super::image::Image;
+use advent_common::Error;
 use std::collections::HashSet;
 use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
@@ -49,12 +49,12 @@ impl Display for Algorithm {
 }
 
 impl FromStr for Algorithm {
-    type Err = ParseError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if TABLE_SIZE != s.len() {
             let what = format!("want {} bytes; got {}", TABLE_SIZE, s.len());
-            return Err(ParseError::new(what));
+            return Err(Error::parse(what));
         }
         let mut table = [false; TABLE_SIZE];
         let mut bytes = s.bytes();
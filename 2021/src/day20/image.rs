@@ -1,3 +1,4 @@
+use advent_common::grid::Grid;
 use std::collections::HashSet;
 use std::fmt::{self, Display, Formatter};
 use std::fs;
@@ -58,6 +59,17 @@ impl Image {
             .unwrap_or_default()
     }
 
+    /// Returns the pixels of the image within the bounds of its special
+    /// pixels, true for light.
+    pub fn pixels(&self) -> Grid<bool> {
+        let (min_i, min_j) = (self.min_i(), self.min_j());
+        let height = (self.max_i() - min_i + 1) as usize;
+        let width = (self.max_j() - min_j + 1) as usize;
+        Grid::from_fn(height, width, |pos| {
+            self.at(min_i + pos.row as i32, min_j + pos.column as i32)
+        })
+    }
+
     pub fn new(default: bool, special: HashSet<Point>) -> Image {
         Image { default, special }
     }
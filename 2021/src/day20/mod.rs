@@ -0,0 +1,88 @@
+mod algorithm;
+mod image;
+mod puzzle;
+
+pub use puzzle::Puzzle;
+
+use advent_common::visualize::Recorder;
+use advent_common::{Error, Solver};
+
+/// Records the image through the fifty enhancements of part 2.
+pub fn visualize(puzzle: &Puzzle, recorder: &mut Recorder) -> Result<(), Error> {
+    recorder.record(&puzzle.image.pixels())?;
+    let mut image = puzzle.algo.enhance(&puzzle.image);
+    recorder.record(&image.pixels())?;
+    for _ in 0..49 {
+        image = puzzle.algo.enhance(&image);
+        recorder.record(&image.pixels())?;
+    }
+    Ok(())
+}
+
+pub mod part1 {
+    use super::*;
+
+    pub fn solve(puzzle: &Puzzle) -> Result<usize, &'static str> {
+        let algo = &puzzle.algo;
+        let image = algo.enhance(&puzzle.image);
+        let image = algo.enhance(&image);
+        image.count_lights()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{solve, Puzzle};
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let puzzle = Puzzle::parse(&fs::read_to_string("tests/day20/sample").unwrap()).unwrap();
+            assert_eq!(35, solve(&puzzle).unwrap());
+        }
+    }
+}
+
+pub mod part2 {
+    use super::*;
+
+    pub fn solve(puzzle: &Puzzle) -> Result<usize, &'static str> {
+        let algo = &puzzle.algo;
+        let mut image = algo.enhance(&puzzle.image);
+        for _ in 0..49 {
+            image = algo.enhance(&image);
+        }
+        image.count_lights()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::{solve, Puzzle};
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let puzzle = Puzzle::parse(&fs::read_to_string("tests/day20/sample").unwrap()).unwrap();
+            assert_eq!(3351, solve(&puzzle).unwrap());
+        }
+    }
+}
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    type Puzzle<'a> = Puzzle;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Puzzle, Error> {
+        Puzzle::parse(text)
+    }
+
+    fn part1(puzzle: &Puzzle) -> Result<usize, Error> {
+        Ok(part1::solve(puzzle)?)
+    }
+
+    fn part2(puzzle: &Puzzle) -> Result<usize, Error> {
+        Ok(part2::solve(puzzle)?)
+    }
+}
@@ -0,0 +1,49 @@
+use super::algorithm::Algorithm;
+use super::image::Image;
+use advent_common::Error;
+use std::collections::HashSet;
+use std::str::Lines;
+
+fn parse_algo(lines: &mut Lines) -> Result<Algorithm, Error> {
+    lines
+        .next()
+        .ok_or_else(|| Error::parse("want algorithm; got EOF"))?
+        .parse()
+}
+
+fn parse_image(lines: &mut Lines) -> Image {
+    let mut lights = HashSet::new();
+    for (i, line) in lines.enumerate() {
+        for (j, _) in line.bytes().enumerate().filter(|&(_, c)| c == b'#') {
+            lights.insert((i as i32, j as i32));
+        }
+    }
+    let background = false; // dark by default
+    Image::new(background, lights)
+}
+
+fn skip_blank_line(lines: &mut Lines) -> Result<(), Error> {
+    let line = lines
+        .next()
+        .ok_or_else(|| Error::parse("want blank line; got EOF"))?;
+    if !line.is_empty() {
+        let what = format!("want blank line; got {}", line);
+        return Err(Error::parse(what));
+    }
+    Ok(())
+}
+
+pub struct Puzzle {
+    pub algo: Algorithm,
+    pub image: Image,
+}
+
+impl Puzzle {
+    pub fn parse(text: &str) -> Result<Puzzle, Error> {
+        let lines = &mut text.lines();
+        let algo = parse_algo(lines)?;
+        skip_blank_line(lines)?;
+        let image = parse_image(lines);
+        Ok(Puzzle { algo, image })
+    }
+}
@@ -0,0 +1,29 @@
+pub mod part1;
+pub mod part2;
+mod player;
+mod puzzle;
+mod wrap;
+
+pub use puzzle::Puzzle;
+
+use advent_common::{Error, Solver};
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    type Puzzle<'a> = Puzzle;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Puzzle, Error> {
+        Puzzle::parse(text)
+    }
+
+    fn part1(puzzle: &Puzzle) -> Result<usize, Error> {
+        Ok(part1::solve(puzzle))
+    }
+
+    fn part2(puzzle: &Puzzle) -> Result<usize, Error> {
+        Ok(part2::solve(puzzle))
+    }
+}
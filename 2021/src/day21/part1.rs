@@ -1,5 +1,5 @@
-use crate::puzzle::Puzzle;
-use crate::wrap;
+use super::puzzle::Puzzle;
+use super::wrap;
 
 struct Die {
     next: usize,
@@ -1,5 +1,5 @@
-use crate::player::Player;
-use crate::puzzle::Puzzle;
+use super::player::Player;
+use super::puzzle::Puzzle;
 use std::collections::HashMap;
 
 type Tally = [usize; 2]; // scores
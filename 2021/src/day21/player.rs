@@ -1,4 +1,4 @@
-use crate::wrap;
+use super::wrap;
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Player {
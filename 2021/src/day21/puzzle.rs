@@ -1,8 +1,5 @@
-use crate::player::Player;
-use advent2021::ParseError;
-use std::fs::File;
-use std::io::{BufRead as _, BufReader};
-use std::path::Path;
+use super::player::Player;
+use advent_common::Error;
 
 pub struct Puzzle {
     starts: [usize; 2],
@@ -16,25 +13,21 @@ impl Puzzle {
         }
     }
 
-    pub fn from_file<P>(input: P) -> Result<Puzzle, ParseError>
-    where
-        P: AsRef<Path>,
-    {
-        let input = input.as_ref();
+    pub fn parse(text: &str) -> Result<Puzzle, Error> {
         let mut starts = [0; 2];
         let mut output = starts.iter_mut();
-        for line in BufReader::new(File::open(input)?).lines() {
-            let start = line?
+        for line in text.lines() {
+            let start = line
                 .rsplit_once(' ')
-                .ok_or_else(|| ParseError::in_file(input, "bad player; failed to split"))?
+                .ok_or_else(|| Error::parse("bad player; failed to split"))?
                 .1
                 .parse()?;
             *output
                 .next()
-                .ok_or_else(|| ParseError::in_file(input, "not enough players"))? = start;
+                .ok_or_else(|| Error::parse("not enough players"))? = start;
         }
         if output.next().is_some() {
-            return Err(ParseError::in_file(input, "too many players"));
+            return Err(Error::parse("too many players"));
         }
         Ok(Puzzle { starts })
     }
@@ -0,0 +1,221 @@
+use advent_common::{Error, Solver};
+use std::num::ParseIntError;
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Cell {
+    value: u64,
+    stamp: bool,
+}
+
+const BOARD_WIDTH: usize = 5;
+const BOARD_HEIGHT: usize = 5;
+
+type BoardRow = [Cell; BOARD_WIDTH];
+
+#[derive(Clone, Debug)]
+struct Board([BoardRow; BOARD_HEIGHT]);
+
+impl Board {
+    fn parse_row(line: &str, row: &mut BoardRow) -> Result<(), Error> {
+        let mut fields = line.split_ascii_whitespace();
+        for cell in row.iter_mut() {
+            cell.value = fields
+                .next()
+                .ok_or_else(|| Error::parse(format!("too few values in row: {}", line)))?
+                .parse()?;
+        }
+        if fields.next().is_some() {
+            return Err(Error::parse(format!("too many values in row: {}", line)));
+        }
+        Ok(())
+    }
+
+    fn stamp(&mut self, value: u64) {
+        for i in 0..BOARD_HEIGHT {
+            for j in 0..BOARD_WIDTH {
+                let cell = &mut self.0[i][j];
+                cell.stamp |= cell.value == value;
+            }
+        }
+    }
+
+    fn has_won(&self) -> bool {
+        self.0.iter().any(|row| row.iter().all(|cell| cell.stamp))
+            || (0..BOARD_WIDTH).any(|j| self.0.iter().all(|row| row[j].stamp))
+    }
+
+    fn score(&self) -> u64 {
+        self.0
+            .iter()
+            .flat_map(|row| {
+                row.iter()
+                    .filter_map(|cell| if cell.stamp { None } else { Some(cell.value) })
+            })
+            .sum()
+    }
+
+    fn reset(&mut self) {
+        for i in 0..BOARD_HEIGHT {
+            for j in 0..BOARD_WIDTH {
+                self.0[i][j].stamp = false;
+            }
+        }
+    }
+
+    fn try_parse<'a, I>(lines: &mut I) -> Result<Option<Board>, Error>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        Ok(if let Some(line) = lines.next() {
+            let mut rows = [[Cell::default(); BOARD_WIDTH]; BOARD_HEIGHT];
+            Board::parse_row(line, &mut rows[0])?;
+            for row in rows.iter_mut().skip(1) {
+                let line = lines.next().ok_or_else(|| Error::parse("too few rows"))?;
+                Board::parse_row(line, row)?;
+            }
+            Some(Board(rows))
+        } else {
+            None
+        })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Game {
+    values: Vec<u64>,
+    boards: Vec<Board>,
+}
+
+impl Game {
+    pub fn reset(&mut self) {
+        for board in &mut self.boards {
+            board.reset();
+        }
+    }
+}
+
+fn parse_values(line: &str) -> Result<Vec<u64>, ParseIntError> {
+    let mut values = Vec::new();
+    for field in line.split(',') {
+        values.push(field.parse()?);
+    }
+    Ok(values)
+}
+
+fn skip_empty_line<'a, I>(lines: &mut I) -> Result<(), Error>
+where
+    I: Iterator<Item = &'a str>,
+{
+    if let Some(line) = lines.next() {
+        if !line.is_empty() {
+            return Err(Error::parse(format!("unexpected non-empty line: {}", line)));
+        }
+    }
+    Ok(())
+}
+
+fn parse_boards<'a, I>(mut lines: I) -> Result<Vec<Board>, Error>
+where
+    I: Iterator<Item = &'a str>,
+{
+    let mut boards = Vec::new();
+    while let Some(board) = Board::try_parse(&mut lines)? {
+        boards.push(board);
+        skip_empty_line(&mut lines)?;
+    }
+    Ok(boards)
+}
+
+pub fn parse_game(text: &str) -> Result<Game, Error> {
+    let mut lines = text.lines();
+    let values = parse_values(lines.next().ok_or_else(|| Error::parse("empty input"))?)?;
+    skip_empty_line(&mut lines)?;
+    let boards = parse_boards(lines)?;
+    Ok(Game { values, boards })
+}
+
+pub mod part1 {
+    use super::*;
+
+    pub fn solve(game: &mut Game) -> Result<u64, Error> {
+        for &value in &game.values {
+            for board in game.boards.iter_mut() {
+                board.stamp(value);
+                if board.has_won() {
+                    return Ok(board.score() * value);
+                }
+            }
+        }
+        Err(Error::no_solution())
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        fn load_game(input: &str) -> Game {
+            parse_game(&fs::read_to_string(input).unwrap()).unwrap()
+        }
+
+        #[test]
+        fn test_solve() {
+            assert_eq!(Ok(4512), solve(&mut load_game("tests/day4/sample")));
+        }
+    }
+}
+
+pub mod part2 {
+    use super::*;
+
+    pub fn solve(game: &mut Game) -> Result<u64, Error> {
+        let mut last = None;
+        for &value in &game.values {
+            for board in game.boards.iter_mut() {
+                if board.has_won() {
+                    continue;
+                }
+                board.stamp(value);
+                if board.has_won() {
+                    last.replace(board.score() * value);
+                }
+            }
+        }
+        last.ok_or_else(Error::no_solution)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        fn load_game(input: &str) -> Game {
+            parse_game(&fs::read_to_string(input).unwrap()).unwrap()
+        }
+
+        #[test]
+        fn test_solve() {
+            assert_eq!(Ok(1924), solve(&mut load_game("tests/day4/sample")));
+        }
+    }
+}
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Puzzle<'a> = Game;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<Game, Error> {
+        parse_game(text)
+    }
+
+    fn part1(game: &Game) -> Result<u64, Error> {
+        part1::solve(&mut game.clone())
+    }
+
+    fn part2(game: &Game) -> Result<u64, Error> {
+        part2::solve(&mut game.clone())
+    }
+}
@@ -0,0 +1,115 @@
+use advent_common::parse::{self, Parser};
+use advent_common::{Error, Solver};
+use std::collections::HashMap;
+
+type Point = (isize, isize);
+
+fn parse_point(p: &mut Parser) -> Result<Point, Error> {
+    let x = p.signed()?;
+    p.literal(",")?;
+    Ok((x, p.signed()?))
+}
+
+pub type Segment = (Point, Point);
+
+fn parse_segment(p: &mut Parser) -> Result<Segment, Error> {
+    let p1 = parse_point(p)?;
+    p.literal(" -> ")?;
+    Ok((p1, parse_point(p)?))
+}
+
+pub fn parse_segments(text: &str) -> Result<Vec<Segment>, Error> {
+    parse::all(text, |p| p.lines(parse_segment))
+}
+
+pub fn solve(segments: &[Segment]) -> usize {
+    let mut floor: HashMap<(isize, isize), usize> = HashMap::new();
+    for &((x1, y1), (x2, y2)) in segments {
+        let (dx, dy) = ((x2 - x1).signum(), (y2 - y1).signum());
+        let (w, h) = ((x2 - x1).abs() + 1, (y2 - y1).abs() + 1);
+        for i in 0..w.max(h) {
+            let point = (x1 + i * dx, y1 + i * dy);
+            *floor.entry(point).or_default() += 1;
+        }
+    }
+    floor.into_values().filter(|&n| n > 1).count()
+}
+
+fn _print_floor(floor: &HashMap<(isize, isize), usize>) {
+    println!();
+    for i in 0..10 {
+        print!("  ");
+        for j in 0..10 {
+            let c = floor
+                .get(&(i, j))
+                .map_or('.', |&n| ('0' as usize + n) as u8 as char);
+            print!("{}", c);
+        }
+        println!();
+    }
+    println!();
+}
+
+pub mod part1 {
+    use super::*;
+
+    pub fn solve(segments: &[Segment]) -> usize {
+        let segments: Vec<_> = segments
+            .iter()
+            .cloned()
+            .filter(|&((x1, y1), (x2, y2))| x1 == x2 || y1 == y2)
+            .collect();
+        super::solve(&segments)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let text = fs::read_to_string("tests/day5/sample").unwrap();
+            let segments = parse_segments(&text).unwrap();
+            assert_eq!(5, solve(&segments));
+        }
+    }
+}
+
+pub mod part2 {
+    pub use super::solve;
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_segments;
+        use super::solve;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let text = fs::read_to_string("tests/day5/sample").unwrap();
+            let segments = parse_segments(&text).unwrap();
+            assert_eq!(12, solve(&segments));
+        }
+    }
+}
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    type Puzzle<'a> = Vec<Segment>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<Segment>, Error> {
+        parse_segments(text)
+    }
+
+    fn part1(segments: &Vec<Segment>) -> Result<usize, Error> {
+        Ok(part1::solve(segments))
+    }
+
+    fn part2(segments: &Vec<Segment>) -> Result<usize, Error> {
+        Ok(part2::solve(segments))
+    }
+}
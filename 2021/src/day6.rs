@@ -0,0 +1,133 @@
+use advent_common::{Error, Solver};
+use std::collections::HashMap;
+use std::num::ParseIntError;
+
+pub fn parse_fish(text: &str) -> Result<Vec<u8>, ParseIntError> {
+    let mut fish = Vec::new();
+    for line in text.lines() {
+        for field in line.split(',') {
+            fish.push(field.parse()?);
+        }
+    }
+    Ok(fish)
+}
+
+fn next(old: HashMap<u8, usize>) -> HashMap<u8, usize> {
+    let mut new: HashMap<u8, usize> = HashMap::new();
+    for (&timer, &count) in old.iter() {
+        if timer != 0 {
+            new.insert(timer - 1, count);
+        }
+    }
+    if let Some(&noobs) = old.get(&0) {
+        new.insert(8, noobs);
+        new.entry(6)
+            .and_modify(|count| *count += noobs)
+            .or_insert(noobs);
+    }
+    new
+}
+
+fn solve_days(fish: &[u8], days: u32) -> usize {
+    let mut counts: HashMap<u8, usize> = HashMap::new();
+    for &f in fish {
+        *counts.entry(f).or_default() += 1
+    }
+    for _ in 0..days {
+        counts = next(counts)
+    }
+    counts.values().sum()
+}
+
+pub mod part1 {
+    use super::*;
+
+    pub fn solve(fish: &[u8]) -> usize {
+        solve_days(fish, 80)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        fn load_fish(input: &str) -> Vec<u8> {
+            parse_fish(&fs::read_to_string(input).unwrap()).unwrap()
+        }
+
+        #[test]
+        fn test_solve() {
+            assert_eq!(5934, solve(&load_fish("tests/day6/sample")));
+        }
+    }
+}
+
+pub mod part2 {
+    use super::*;
+
+    pub fn solve(fish: &[u8]) -> usize {
+        solve_days(fish, 256)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        fn load_fish(input: &str) -> Vec<u8> {
+            parse_fish(&fs::read_to_string(input).unwrap()).unwrap()
+        }
+
+        #[test]
+        fn test_solve() {
+            assert_eq!(26984457539, solve(&load_fish("tests/day6/sample")));
+        }
+    }
+}
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    type Puzzle<'a> = Vec<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<u8>, Error> {
+        Ok(parse_fish(text)?)
+    }
+
+    fn part1(fish: &Vec<u8>) -> Result<usize, Error> {
+        Ok(part1::solve(fish))
+    }
+
+    fn part2(fish: &Vec<u8>) -> Result<usize, Error> {
+        Ok(part2::solve(fish))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_common::prop::{self, range};
+
+    /// Simulates each fish individually.
+    fn simulate(fish: &[u8], days: u32) -> usize {
+        let mut fish = fish.to_vec();
+        for _ in 0..days {
+            let noobs = fish.iter().filter(|&&timer| timer == 0).count();
+            for timer in &mut fish {
+                *timer = if *timer == 0 { 6 } else { *timer - 1 };
+            }
+            fish.resize(fish.len() + noobs, 8);
+        }
+        fish.len()
+    }
+
+    #[test]
+    fn test_solve_days_matches_simulation() {
+        let fish = prop::vec(range(0u8..=8), 0..=10);
+        prop::check((fish, range(0u32..=40)), |(fish, days)| {
+            solve_days(fish, *days) == simulate(fish, *days)
+        });
+    }
+}
@@ -0,0 +1,85 @@
+use advent_common::{Error, Solver};
+use std::num::ParseIntError;
+
+pub fn parse_crabs(text: &str) -> Result<Vec<i32>, ParseIntError> {
+    let mut crabs = Vec::new();
+    for line in text.lines() {
+        for field in line.split(',') {
+            crabs.push(field.parse()?);
+        }
+    }
+    Ok(crabs)
+}
+
+pub mod part1 {
+    pub fn solve(crabs: &[i32]) -> i32 {
+        assert!(!crabs.is_empty());
+        let lo = *crabs.iter().min().unwrap();
+        let hi = *crabs.iter().max().unwrap();
+        let fuel = |y: i32| crabs.iter().map(|&x| (y - x).abs()).sum();
+        (lo..=hi).map(fuel).min().unwrap()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_crabs;
+        use super::solve;
+        use std::fs;
+
+        fn load_crabs(input: &str) -> Vec<i32> {
+            parse_crabs(&fs::read_to_string(input).unwrap()).unwrap()
+        }
+
+        #[test]
+        fn test_solve() {
+            assert_eq!(37, solve(&load_crabs("tests/day7/sample")));
+        }
+    }
+}
+
+pub mod part2 {
+    pub fn solve(crabs: &[i32]) -> i32 {
+        assert!(!crabs.is_empty());
+        let lo = *crabs.iter().min().unwrap();
+        let hi = *crabs.iter().max().unwrap();
+        let triangle = |d: i32| d * (d + 1) / 2;
+        let fuel = |y: i32| crabs.iter().map(|&x| triangle((y - x).abs())).sum();
+        (lo..=hi).map(fuel).min().unwrap()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_crabs;
+        use super::solve;
+        use std::fs;
+
+        fn load_crabs(input: &str) -> Vec<i32> {
+            parse_crabs(&fs::read_to_string(input).unwrap()).unwrap()
+        }
+
+        #[test]
+        fn test_solve() {
+            assert_eq!(168, solve(&load_crabs("tests/day7/sample")));
+        }
+    }
+}
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    type Puzzle<'a> = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(text: &str) -> Result<Vec<i32>, Error> {
+        Ok(parse_crabs(text)?)
+    }
+
+    fn part1(crabs: &Vec<i32>) -> Result<i32, Error> {
+        Ok(part1::solve(crabs))
+    }
+
+    fn part2(crabs: &Vec<i32>) -> Result<i32, Error> {
+        Ok(part2::solve(crabs))
+    }
+}
@@ -0,0 +1,124 @@
+use advent_common::grid::{Grid, Position};
+use advent_common::{Error, Solver};
+use std::collections::{HashSet, VecDeque};
+
+pub fn parse_heights(text: &str) -> Result<Grid<u32>, Error> {
+    Grid::parse(text, |b| {
+        (b as char)
+            .to_digit(10)
+            .ok_or_else(|| Error::parse(format!("{}: bad height", b as char)))
+    })
+}
+
+fn is_low_point(heights: &Grid<u32>, p: Position) -> bool {
+    let height = heights[p];
+    heights.neighbors4(p).all(|q| height < heights[q])
+}
+
+fn low_points(heights: &Grid<u32>) -> impl Iterator<Item = Position> + '_ {
+    heights.positions().filter(|&p| is_low_point(heights, p))
+}
+
+pub mod part1 {
+    use super::*;
+
+    pub fn solve(heights: &Grid<u32>) -> u32 {
+        low_points(heights).map(|p| heights[p] + 1).sum()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_heights;
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let heights = parse_heights(&fs::read_to_string("tests/day9/sample").unwrap());
+            assert_eq!(15, solve(&heights.unwrap()));
+        }
+    }
+}
+
+pub mod part2 {
+    use super::*;
+
+    fn basin_size(heights: &Grid<u32>, p: Position) -> usize {
+        let mut basin: HashSet<Position> = HashSet::new();
+        let mut queue = VecDeque::from([p]);
+        while let Some(p) = queue.pop_front() {
+            for q in heights.neighbors4(p) {
+                let height = heights[q];
+                if height < 9 && !basin.contains(&q) {
+                    basin.insert(q);
+                    queue.push_back(q);
+                }
+            }
+        }
+        basin.len()
+    }
+
+    fn solve_n(heights: &Grid<u32>, n: usize) -> usize {
+        let mut sizes: Vec<_> = low_points(heights)
+            .map(|p| basin_size(heights, p))
+            .collect();
+        sizes.select_nth_unstable_by(n - 1, |a, b| b.cmp(a));
+        sizes[0..n].iter().product()
+    }
+
+    pub fn solve(heights: &Grid<u32>) -> usize {
+        solve_n(heights, 3)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::super::parse_heights;
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve() {
+            let got =
+                solve(&parse_heights(&fs::read_to_string("tests/day9/sample").unwrap()).unwrap());
+            assert_eq!(1134, got);
+        }
+    }
+}
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    type Puzzle<'a> = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Grid<u32>, Error> {
+        parse_heights(text)
+    }
+
+    fn part1(heights: &Grid<u32>) -> Result<u32, Error> {
+        Ok(part1::solve(heights))
+    }
+
+    fn part2(heights: &Grid<u32>) -> Result<usize, Error> {
+        Ok(part2::solve(heights))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_is_low_point() {
+        let text = fs::read_to_string("tests/day9/sample").unwrap();
+        let heights = parse_heights(&text).unwrap();
+        let lows = [(0, 1), (0, 9), (2, 2), (4, 6)];
+        for p in heights.positions() {
+            let want = lows.contains(&(p.row, p.column));
+            let got = is_low_point(&heights, p);
+            assert_eq!(want, got);
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use advent_common::{Error, Solver};
+use std::str::Lines;
+
+// An iterator over elves' total calorie counts.
+struct Elves<'a> {
+    lines: Lines<'a>,
+}
+
+impl Elves<'_> {
+    fn from_text(text: &str) -> Elves<'_> {
+        let lines = text.lines();
+        Elves { lines }
+    }
+
+    /// Returns the number from the next line, or None if the line is blank or
+    /// there is no next line.  Returns an error if parsing fails.
+    fn parse_next_line(&mut self) -> Option<Result<u64, Error>> {
+        let line = self.lines.next()?;
+        if line.is_empty() {
+            return None;
+        }
+        match line.parse() {
+            Ok(number) => Some(Ok(number)),
+            Err(err) => Some(Err(err.into())),
+        }
+    }
+}
+
+impl Iterator for Elves<'_> {
+    type Item = Result<u64, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.parse_next_line();
+        let Some(Ok(mut sum)) = first else {
+            return first;
+        };
+        while let Some(result) = self.parse_next_line() {
+            let Ok(number) = result else {
+                return Some(result);
+            };
+            sum += number;
+        }
+        Some(Ok(sum))
+    }
+}
+
+pub struct Puzzle {
+    elves: Vec<u64>, // total calorie count of each elf
+}
+
+impl Puzzle {
+    pub fn parse(text: &str) -> Result<Puzzle, Error> {
+        let elves = Elves::from_text(text).collect::<Result<_, _>>()?;
+        Ok(Puzzle { elves })
+    }
+}
+
+pub mod part1 {
+    use super::*;
+
+    pub fn solve(puzzle: &Puzzle) -> Result<u64, Error> {
+        let max = puzzle.elves.iter().max().ok_or_else(Error::empty_input)?;
+        Ok(*max)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve_sample() {
+            let text = fs::read_to_string("tests/day1/sample").unwrap();
+            let puzzle = Puzzle::parse(&text).unwrap();
+            let answer = solve(&puzzle).expect("part 1: sample should be solvable");
+            assert_eq!(24000, answer);
+        }
+
+        #[test]
+        fn test_solve_input() {
+            let text = fs::read_to_string("tests/day1/input").unwrap();
+            let puzzle = Puzzle::parse(&text).unwrap();
+            let answer = solve(&puzzle).expect("part 1: input should be solvable");
+            assert_eq!(67658, answer);
+        }
+    }
+}
+
+pub mod part2 {
+    use super::*;
+
+    pub fn solve(puzzle: &Puzzle) -> Result<u64, Error> {
+        let mut top3 = [0; 3]; // sorted in ascending order
+        for &elf in &puzzle.elves {
+            if elf > top3[2] {
+                top3[0] = top3[1];
+                top3[1] = top3[2];
+                top3[2] = elf;
+            } else if elf > top3[1] {
+                top3[0] = top3[1];
+                top3[1] = elf;
+            } else if elf > top3[0] {
+                top3[0] = elf;
+            }
+        }
+        Ok(top3[0] + top3[1] + top3[2])
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve_sample() {
+            let text = fs::read_to_string("tests/day1/sample").unwrap();
+            let puzzle = Puzzle::parse(&text).unwrap();
+            let answer = solve(&puzzle).expect("part 2: sample should be solvable");
+            assert_eq!(45000, answer);
+        }
+
+        #[test]
+        fn test_solve_input() {
+            let text = fs::read_to_string("tests/day1/input").unwrap();
+            let puzzle = Puzzle::parse(&text).unwrap();
+            let answer = solve(&puzzle).expect("part 2: input should be solvable");
+            assert_eq!(200158, answer);
+        }
+    }
+}
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    type Puzzle<'a> = Puzzle;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<Puzzle, Error> {
+        Puzzle::parse(text)
+    }
+
+    fn part1(puzzle: &Puzzle) -> Result<u64, Error> {
+        part1::solve(puzzle)
+    }
+
+    fn part2(puzzle: &Puzzle) -> Result<u64, Error> {
+        part2::solve(puzzle)
+    }
+}
@@ -0,0 +1,211 @@
+use advent_common::{Error, Solver};
+
+#[derive(Clone, Copy)]
+enum Shape {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Shape {
+    fn from_player1(byte: u8) -> Result<Shape, Error> {
+        match byte {
+            b'A' => Ok(Shape::Rock),
+            b'B' => Ok(Shape::Paper),
+            b'C' => Ok(Shape::Scissors),
+            _ => Err(Error::parse("bad Round::player1 value")),
+        }
+    }
+
+    fn score(self) -> u64 {
+        self as u64 + 1
+    }
+}
+
+/// Returns bytes from the specified line representing the moves of both
+/// players.
+fn to_bytes(line: &str) -> Result<(u8, u8), Error> {
+    let bytes = line.as_bytes();
+    if bytes.len() < 3
+        || bytes.len() > 4
+        || (bytes[1] != b' ')
+        || (bytes.len() == 4 && bytes[3] != b'\n')
+    {
+        return Err(Error::parse("bad Round"));
+    }
+    Ok((bytes[0], bytes[2]))
+}
+
+/// The second column of the strategy guide, which each part interprets
+/// differently.
+#[derive(Clone, Copy)]
+enum Code {
+    X,
+    Y,
+    Z,
+}
+
+impl Code {
+    fn from_byte(byte: u8) -> Result<Code, Error> {
+        match byte {
+            b'X' => Ok(Code::X),
+            b'Y' => Ok(Code::Y),
+            b'Z' => Ok(Code::Z),
+            _ => Err(Error::parse("bad Round::player2 value")),
+        }
+    }
+}
+
+struct Round {
+    player1: Shape,
+    player2: Shape,
+}
+
+impl Round {
+    fn score(&self) -> u64 {
+        let shape_score = self.player2.score();
+        let outcome_score = match (self.player1, self.player2) {
+            (Shape::Rock, Shape::Rock) => 3,
+            (Shape::Rock, Shape::Paper) => 6,
+            (Shape::Rock, Shape::Scissors) => 0,
+            (Shape::Paper, Shape::Rock) => 0,
+            (Shape::Paper, Shape::Paper) => 3,
+            (Shape::Paper, Shape::Scissors) => 6,
+            (Shape::Scissors, Shape::Rock) => 6,
+            (Shape::Scissors, Shape::Paper) => 0,
+            (Shape::Scissors, Shape::Scissors) => 3,
+        };
+        shape_score + outcome_score
+    }
+}
+
+pub struct Puzzle {
+    guide: Vec<(Shape, Code)>,
+}
+
+impl Puzzle {
+    pub fn parse(text: &str) -> Result<Puzzle, Error> {
+        let mut guide = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let parse_line = || {
+                let (byte1, byte2) = to_bytes(line)?;
+                Ok((Shape::from_player1(byte1)?, Code::from_byte(byte2)?))
+            };
+            guide.push(parse_line().map_err(|err: Error| err.at_line(index + 1))?);
+        }
+        Ok(Puzzle { guide })
+    }
+}
+
+pub mod part1 {
+    use super::*;
+
+    fn round(player1: Shape, code: Code) -> Round {
+        let player2 = match code {
+            Code::X => Shape::Rock,
+            Code::Y => Shape::Paper,
+            Code::Z => Shape::Scissors,
+        };
+        Round { player1, player2 }
+    }
+
+    pub fn solve(puzzle: &Puzzle) -> u64 {
+        let rounds = puzzle
+            .guide
+            .iter()
+            .map(|&(player1, code)| round(player1, code));
+        rounds.map(|round| round.score()).sum()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve_sample() {
+            let text = fs::read_to_string("tests/day2/sample").unwrap();
+            let puzzle = Puzzle::parse(&text).expect("sample should be valid");
+            let answer = solve(&puzzle);
+            assert_eq!(15, answer);
+        }
+
+        #[test]
+        fn test_solve_input() {
+            let text = fs::read_to_string("tests/day2/input").unwrap();
+            let puzzle = Puzzle::parse(&text).expect("input should be valid");
+            let answer = solve(&puzzle);
+            assert_eq!(11767, answer);
+        }
+    }
+}
+
+pub mod part2 {
+    use super::*;
+
+    fn round(player1: Shape, code: Code) -> Round {
+        let player2 = match (player1, code) {
+            // Lose
+            (Shape::Rock, Code::X) => Shape::Scissors,
+            (Shape::Paper, Code::X) => Shape::Rock,
+            (Shape::Scissors, Code::X) => Shape::Paper,
+            // Draw
+            (_, Code::Y) => player1,
+            // Win
+            (Shape::Rock, Code::Z) => Shape::Paper,
+            (Shape::Paper, Code::Z) => Shape::Scissors,
+            (Shape::Scissors, Code::Z) => Shape::Rock,
+        };
+        Round { player1, player2 }
+    }
+
+    pub fn solve(puzzle: &Puzzle) -> u64 {
+        let rounds = puzzle
+            .guide
+            .iter()
+            .map(|&(player1, code)| round(player1, code));
+        rounds.map(|round| round.score()).sum()
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve_sample() {
+            let text = fs::read_to_string("tests/day2/sample").unwrap();
+            let puzzle = Puzzle::parse(&text).expect("sample should be valid");
+            let answer = solve(&puzzle);
+            assert_eq!(12, answer);
+        }
+
+        #[test]
+        fn test_solve_input() {
+            let text = fs::read_to_string("tests/day2/input").unwrap();
+            let puzzle = Puzzle::parse(&text).expect("input should be valid");
+            let answer = solve(&puzzle);
+            assert_eq!(13886, answer);
+        }
+    }
+}
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    type Puzzle<'a> = Puzzle;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<Puzzle, Error> {
+        Puzzle::parse(text)
+    }
+
+    fn part1(puzzle: &Puzzle) -> Result<u64, Error> {
+        Ok(part1::solve(puzzle))
+    }
+
+    fn part2(puzzle: &Puzzle) -> Result<u64, Error> {
+        Ok(part2::solve(puzzle))
+    }
+}
@@ -0,0 +1,140 @@
+use advent_common::{Error, Solver};
+use std::collections::HashSet;
+
+fn item_to_index(item: u8) -> Result<usize, Error> {
+    let index = match item {
+        b'a'..=b'z' => item - b'a',
+        b'A'..=b'Z' => item - b'A' + 26,
+        _ => return Err(Error::parse("bad item")),
+    };
+    Ok(index as usize)
+}
+
+pub struct Puzzle {
+    rucksacks: Vec<Vec<usize>>, // item indexes, one less than their priorities
+}
+
+impl Puzzle {
+    pub fn parse(text: &str) -> Result<Puzzle, Error> {
+        let mut rucksacks = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let items = line.bytes().map(item_to_index).collect::<Result<_, _>>();
+            rucksacks.push(items.map_err(|err| err.at_line(index + 1))?);
+        }
+        Ok(Puzzle { rucksacks })
+    }
+}
+
+pub mod part1 {
+    use super::*;
+
+    pub fn solve(puzzle: &Puzzle) -> u64 {
+        let mut sum = 0;
+        for rucksack in &puzzle.rucksacks {
+            let (first_compartment, second_compartment) = rucksack.split_at(rucksack.len() / 2);
+            let second_compartment: HashSet<usize> = second_compartment.iter().cloned().collect();
+            let mut misplaced = [false; 52];
+            for &item in first_compartment {
+                if second_compartment.contains(&item) {
+                    misplaced[item] = true;
+                }
+            }
+            let row_sum: u64 = misplaced
+                .iter()
+                .enumerate()
+                .flat_map(|(index, is_misplaced)| {
+                    let priority = index as u64 + 1;
+                    is_misplaced.then_some(priority)
+                })
+                .sum();
+            sum += row_sum;
+        }
+        sum
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve_sample() {
+            let text = fs::read_to_string("tests/day3/sample").unwrap();
+            let puzzle = Puzzle::parse(&text).expect("sample should be valid");
+            let answer = solve(&puzzle);
+            assert_eq!(157, answer);
+        }
+
+        #[test]
+        fn test_solve_input() {
+            let text = fs::read_to_string("tests/day3/input").unwrap();
+            let puzzle = Puzzle::parse(&text).expect("input should be valid");
+            let answer = solve(&puzzle);
+            assert_eq!(7597, answer);
+        }
+    }
+}
+
+pub mod part2 {
+    use super::*;
+
+    pub fn solve(puzzle: &Puzzle) -> Result<u64, Error> {
+        let mut sum = 0;
+        for group in puzzle.rucksacks.chunks_exact(3) {
+            let s_set: HashSet<usize> = group[0].iter().cloned().collect();
+            let t_set: HashSet<usize> = group[1].iter().cloned().collect();
+            let u_set: HashSet<usize> = group[2].iter().cloned().collect();
+            let Some(&item) = s_set
+                .iter()
+                .find(|b| t_set.contains(b) && u_set.contains(b))
+            else {
+                return Err(Error::no_solution());
+            };
+            let priority = item as u64 + 1;
+            sum += priority;
+        }
+        Ok(sum)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve_sample() {
+            let text = fs::read_to_string("tests/day3/sample").unwrap();
+            let puzzle = Puzzle::parse(&text).expect("sample should be valid");
+            let answer = solve(&puzzle).expect("sample should have an answer");
+            assert_eq!(70, answer);
+        }
+
+        #[test]
+        fn test_solve_input() {
+            let text = fs::read_to_string("tests/day3/input").unwrap();
+            let puzzle = Puzzle::parse(&text).expect("input should be valid");
+            let answer = solve(&puzzle).expect("input should have an answer");
+            assert_eq!(2607, answer);
+        }
+    }
+}
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    type Puzzle<'a> = Puzzle;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<Puzzle, Error> {
+        Puzzle::parse(text)
+    }
+
+    fn part1(puzzle: &Puzzle) -> Result<u64, Error> {
+        Ok(part1::solve(puzzle))
+    }
+
+    fn part2(puzzle: &Puzzle) -> Result<u64, Error> {
+        part2::solve(puzzle)
+    }
+}
@@ -0,0 +1,148 @@
+use advent_common::{Error, Solver};
+
+struct Assignment {
+    first: u64,
+    last: u64,
+}
+
+impl Assignment {
+    fn contains(&self, section: u64) -> bool {
+        self.first <= section && section <= self.last
+    }
+
+    fn fully_contains(&self, other: &Assignment) -> bool {
+        self.first <= other.first && other.last <= self.last
+    }
+
+    fn overlaps(&self, other: &Assignment) -> bool {
+        self.contains(other.first) || self.contains(other.last) || other.fully_contains(self)
+    }
+}
+
+fn parse_range(range: &str) -> Result<Assignment, Error> {
+    let mut split = range.split('-');
+    let pair = match (split.next(), split.next(), split.next()) {
+        (Some(first), Some(last), None) => (first, last),
+        _ => return Err(Error::parse("bad range")),
+    };
+    let first = pair.0.parse()?;
+    let last = pair.1.parse()?;
+    Ok(Assignment { first, last })
+}
+
+fn parse_range_pair(line: &str) -> Result<(Assignment, Assignment), Error> {
+    let mut split = line.split(',');
+    let ranges = match (split.next(), split.next(), split.next()) {
+        (Some(first), Some(second), None) => (first, second),
+        _ => return Err(Error::parse("bad range pair")),
+    };
+    let first = parse_range(ranges.0)?;
+    let second = parse_range(ranges.1)?;
+    Ok((first, second))
+}
+
+pub struct Puzzle {
+    pairs: Vec<(Assignment, Assignment)>,
+}
+
+impl Puzzle {
+    pub fn parse(text: &str) -> Result<Puzzle, Error> {
+        let mut pairs = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            pairs.push(parse_range_pair(line).map_err(|err| err.at_line(index + 1))?);
+        }
+        Ok(Puzzle { pairs })
+    }
+}
+
+pub mod part1 {
+    use super::*;
+
+    pub fn solve(puzzle: &Puzzle) -> u64 {
+        let mut sum = 0;
+        for (first, second) in &puzzle.pairs {
+            if first.fully_contains(second) || second.fully_contains(first) {
+                sum += 1;
+            }
+        }
+        sum
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve_sample() {
+            let text = fs::read_to_string("tests/day4/sample").unwrap();
+            let puzzle = Puzzle::parse(&text).expect("sample should be valid");
+            let answer = solve(&puzzle);
+            assert_eq!(2, answer);
+        }
+
+        #[test]
+        fn test_solve_input() {
+            let text = fs::read_to_string("tests/day4/input").unwrap();
+            let puzzle = Puzzle::parse(&text).expect("input should be valid");
+            let answer = solve(&puzzle);
+            assert_eq!(466, answer);
+        }
+    }
+}
+
+pub mod part2 {
+    use super::*;
+
+    pub fn solve(puzzle: &Puzzle) -> u64 {
+        let mut sum = 0;
+        for (first, second) in &puzzle.pairs {
+            if first.overlaps(second) {
+                sum += 1;
+            }
+        }
+        sum
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use std::fs;
+
+        #[test]
+        fn test_solve_sample() {
+            let text = fs::read_to_string("tests/day4/sample").unwrap();
+            let puzzle = Puzzle::parse(&text).expect("sample should be valid");
+            let answer = solve(&puzzle);
+            assert_eq!(4, answer);
+        }
+
+        #[test]
+        fn test_solve_input() {
+            let text = fs::read_to_string("tests/day4/input").unwrap();
+            let puzzle = Puzzle::parse(&text).expect("input should be valid");
+            let answer = solve(&puzzle);
+            assert_eq!(865, answer);
+        }
+    }
+}
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Puzzle<'a> = Puzzle;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(text: &str) -> Result<Puzzle, Error> {
+        Puzzle::parse(text)
+    }
+
+    fn part1(puzzle: &Puzzle) -> Result<u64, Error> {
+        Ok(part1::solve(puzzle))
+    }
+
+    fn part2(puzzle: &Puzzle) -> Result<u64, Error> {
+        Ok(part2::solve(puzzle))
+    }
+}
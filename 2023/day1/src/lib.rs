@@ -1,2 +1,29 @@
 pub mod part1;
 pub mod part2;
+
+use advent_common::{Error, Solver};
+
+/// Returns the lines of the calibration document.
+pub fn lines(text: &str) -> Vec<&str> {
+    text.lines().collect()
+}
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    type Puzzle<'a> = Vec<&'a str>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Vec<&str>, Error> {
+        Ok(lines(text))
+    }
+
+    fn part1(lines: &Vec<&str>) -> Result<u32, Error> {
+        Ok(part1::solve(lines))
+    }
+
+    fn part2(lines: &Vec<&str>) -> Result<u32, Error> {
+        Ok(part2::solve(lines))
+    }
+}
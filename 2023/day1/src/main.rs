@@ -1,5 +1,12 @@
+use advent2023_day1::{lines, part1, part2};
+use advent_common::input;
+
 fn main() {
-    let text = include_str!("input.txt");
-    println!("{}", day1::part1::solve(text));
-    println!("{}", day1::part2::solve(text));
+    let text = input::text_or(include_str!("input.txt")).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(3);
+    });
+    let lines = lines(&text);
+    println!("{}", part1::solve(&lines));
+    println!("{}", part2::solve(&lines));
 }
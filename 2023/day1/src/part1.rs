@@ -1,8 +1,8 @@
 /// Returns the two-digit value represented by the first and last numeric digits
 /// in the specified line.
 /// ```
-/// assert_eq!(day1::part2::parse2d("a4b2c"), 42);
-/// assert_eq!(day1::part2::parse2d("ab7cd"), 77);
+/// assert_eq!(advent2023_day1::part2::parse2d("a4b2c"), 42);
+/// assert_eq!(advent2023_day1::part2::parse2d("ab7cd"), 77);
 /// ```
 pub fn parse2d(line: &str) -> u32 {
     const BASE: u32 = 10;
@@ -11,13 +11,14 @@ pub fn parse2d(line: &str) -> u32 {
     first.unwrap_or_default() * BASE + last.unwrap_or_default()
 }
 
-pub fn solve(text: &str) -> u32 {
-    text.lines().map(parse2d).sum()
+pub fn solve(lines: &[&str]) -> u32 {
+    lines.iter().cloned().map(parse2d).sum()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lines;
 
     #[test]
     fn sample() {
@@ -27,6 +28,6 @@ mod tests {
             a1b2c3d4e5f
             treb7uchet
         "#;
-        assert_eq!(solve(text), 142);
+        assert_eq!(solve(&lines(text)), 142);
     }
 }
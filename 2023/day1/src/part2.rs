@@ -17,8 +17,8 @@ fn parse_digit(line: &str) -> Option<u32> {
 /// specified line, be they represented as numerals (0, 1, 2...) or words (zero,
 /// one, two...).
 /// ```
-/// assert_eq!(day1::part2::parse2d("aoneandatwo"), 12);
-/// assert_eq!(day1::part2::parse2d("foothreebar"), 33);
+/// assert_eq!(advent2023_day1::part2::parse2d("aoneandatwo"), 12);
+/// assert_eq!(advent2023_day1::part2::parse2d("foothreebar"), 33);
 /// ```
 pub fn parse2d(line: &str) -> u32 {
     const BASE: u32 = 10;
@@ -30,13 +30,14 @@ pub fn parse2d(line: &str) -> u32 {
     first * BASE + last
 }
 
-pub fn solve(text: &str) -> u32 {
-    text.lines().map(parse2d).sum()
+pub fn solve(lines: &[&str]) -> u32 {
+    lines.iter().cloned().map(parse2d).sum()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::lines;
 
     #[test]
     fn sample() {
@@ -49,6 +50,6 @@ mod tests {
             zoneight234
             7pqrstsixteen
         "#;
-        assert_eq!(solve(text), 281);
+        assert_eq!(solve(&lines(text)), 281);
     }
 }
@@ -1,93 +1,131 @@
-use crate::{direction::Direction, position::Position, tile::Tile};
+use advent_common::grid::{self, Direction, Position};
+use advent_common::{Error, ErrorKind};
 
-#[derive(Clone)]
-pub struct Grid(Vec<Vec<Tile>>);
-
-fn expand_east(row: Vec<Tile>) -> Vec<Tile> {
-    row.into_iter()
-        .flat_map(|tile| [tile, tile.expand_east()])
-        .collect()
-}
+use crate::tile::Tile;
 
-fn expand_south(row: Vec<Tile>) -> [Vec<Tile>; 2] {
-    let new_row = row.iter().cloned().map(Tile::expand_south).collect();
-    [row, new_row]
-}
+#[derive(Clone)]
+pub struct Grid(grid::Grid<Tile>);
 
 impl Grid {
-    pub fn from_str(text: &str) -> Grid {
-        Grid(
-            text.lines()
-                .map(|line| line.bytes().map(Tile::from_ascii).collect())
-                .collect(),
-        )
+    pub fn parse(text: &str) -> Result<Grid, Error> {
+        let tiles = grid::Grid::parse(text, Tile::from_ascii)?;
+        let starts: Vec<Position> = tiles
+            .iter()
+            .filter(|&(_, &tile)| tile == Tile::Start)
+            .map(|(pos, _)| pos)
+            .collect();
+        match starts[..] {
+            [] => Err(Error::parse("expected start tile")),
+            [_] => Ok(Grid(tiles)),
+            [_, pos, ..] => {
+                let err = Error::parse("expected only one start tile");
+                Err(err.at(pos.row + 1, pos.column + 1))
+            }
+        }
     }
 
     pub fn start(&self) -> Position {
-        for (i, row) in self.0.iter().enumerate() {
-            for (j, tile) in row.iter().enumerate() {
-                if let Tile::Start = tile {
-                    return Position(i, j);
-                }
-            }
-        }
-        panic!("expected start tile)")
+        self.0
+            .find(|&tile| tile == Tile::Start)
+            .expect("start tile, checked by parse")
     }
 
     pub fn set(&mut self, pos: Position, tile: Tile) {
-        self.0[pos.0][pos.1] = tile;
+        self.0[pos] = tile;
     }
 
     pub fn at(&self, pos: Position) -> Option<Tile> {
-        self.0.get(pos.0).and_then(|row| row.get(pos.1)).cloned()
+        self.0.get(pos).cloned()
     }
 
     pub fn exits(&self, from: Position) -> impl Iterator<Item = Position> + '_ {
-        let tile = self.at(from).expect("valid from position");
-        Direction::iter()
+        let tile = self.0[from];
+        Direction::ALL
+            .into_iter()
             .filter(move |&dir| tile.is_open_to(dir))
             .flat_map(move |dir| {
-                from.go(dir).filter(|&pos| {
+                self.0.step(from, dir).filter(|&pos| {
                     self.at(pos)
                         .is_some_and(|tile| tile.is_open_to(dir.reverse()))
                 })
             })
     }
 
+    /// Returns the positions of the pipe loop through the start tile, in
+    /// order, beginning with the start.
+    pub fn main_loop(&self) -> Result<Vec<Position>, Error> {
+        let start = self.start();
+        let mut main = vec![start];
+        let mut old = start;
+        let mut new = self
+            .exits(start)
+            .next()
+            .ok_or_else(|| Error::new(ErrorKind::NoSolution, "start tile has no exits"))?;
+        while new != start {
+            main.push(new);
+            let pos = self
+                .exits(new)
+                .find(|&pos| pos != old)
+                .ok_or_else(|| Error::new(ErrorKind::NoSolution, "pipe loop is broken"))?;
+            (old, new) = (new, pos);
+        }
+        Ok(main)
+    }
+
     pub fn is_ground(&self, pos: Position) -> bool {
         self.at(pos).is_some_and(Tile::is_ground)
     }
 
+    pub fn neighbors(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.0.neighbors8(pos)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        self.0.positions()
+    }
+
     pub fn enumerate(&self) -> impl Iterator<Item = (Position, Tile)> + '_ {
-        self.0.iter().enumerate().flat_map(|(i, row)| {
-            row.iter()
-                .enumerate()
-                .map(move |(j, tile)| (Position(i, j), *tile))
-        })
+        self.0.iter().map(|(pos, &tile)| (pos, tile))
     }
 
-    pub fn expand(self) -> Grid {
-        Grid(
-            self.0
-                .into_iter()
-                .map(expand_east)
-                .flat_map(expand_south)
-                .collect(),
-        )
+    /// Doubles the grid in each dimension, so that passages between adjacent
+    /// pipes become ground.  The start tile must first be replaced by the
+    /// pipe it stands for.
+    pub fn expand(self) -> Result<Grid, Error> {
+        let tiles = &self.0;
+        let rows = (0..tiles.height() * 2).map(|row| {
+            (0..tiles.width() * 2)
+                .map(|column| {
+                    let tile = tiles[Position::new(row / 2, column / 2)];
+                    let tile = if column % 2 == 1 {
+                        tile.expand_east()?
+                    } else {
+                        tile
+                    };
+                    if row % 2 == 1 {
+                        tile.expand_south()
+                    } else {
+                        Ok(tile)
+                    }
+                })
+                .collect::<Result<Vec<_>, Error>>()
+        });
+        let rows = rows.collect::<Result<Vec<_>, Error>>()?;
+        Ok(Grid(grid::Grid::from_rows(rows)?))
     }
 
     pub fn height(&self) -> usize {
-        self.0.len()
+        self.0.height()
     }
 
     pub fn width(&self) -> usize {
-        self.0.get(0).map(|row| row.len()).unwrap_or_default()
+        self.0.width()
     }
 
     #[cfg(test)]
     pub fn to_ascii(&self) -> Vec<Vec<u8>> {
         self.0
-            .iter()
+            .rows()
             .map(|row| row.iter().map(|tile| tile.to_ascii()).collect())
             .collect()
     }
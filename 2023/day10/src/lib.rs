@@ -1,6 +1,28 @@
-mod direction;
 mod grid;
 pub mod part1;
 pub mod part2;
-mod position;
 mod tile;
+
+pub use grid::Grid;
+
+use advent_common::{Error, Solver};
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    type Puzzle<'a> = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Grid, Error> {
+        Grid::parse(text)
+    }
+
+    fn part1(grid: &Grid) -> Result<usize, Error> {
+        part1::solve(grid)
+    }
+
+    fn part2(grid: &Grid) -> Result<usize, Error> {
+        part2::solve(grid)
+    }
+}
@@ -1,5 +1,27 @@
+use advent2023_day10::{part1, part2, Grid};
+use advent_common::input;
+
 fn main() {
-    let text = include_str!("input.txt");
-    println!("{}", day10::part1::solve(text));
-    println!("{}", day10::part2::solve(text));
+    let text = input::text_or(include_str!("input.txt")).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(3);
+    });
+    let grid = Grid::parse(&text).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(2);
+    });
+    match part1::solve(&grid) {
+        Ok(answer) => println!("{answer}"),
+        Err(err) => {
+            eprintln!("error: {err}");
+            std::process::exit(1);
+        }
+    }
+    match part2::solve(&grid) {
+        Ok(answer) => println!("{answer}"),
+        Err(err) => {
+            eprintln!("error: {err}");
+            std::process::exit(1);
+        }
+    }
 }
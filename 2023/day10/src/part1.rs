@@ -1,25 +1,9 @@
 use crate::grid::Grid;
 
-pub fn solve(text: &str) -> usize {
-    let grid = Grid::from_str(text);
-    let start = grid.start();
+use advent_common::Error;
 
-    let mut old = start;
-    let mut new = grid
-        .exits(start)
-        .next()
-        .expect("somewhere reachable from the start position");
-    let mut len = 1; // We already took one step, from old to new.
-    while new != start {
-        let pos = grid
-            .exits(new)
-            .find(|&pos| pos != old)
-            .expect("somewhere reachable from each reachable position");
-
-        (old, new) = (new, pos);
-        len += 1;
-    }
-    (len + 1) / 2
+pub fn solve(grid: &Grid) -> Result<usize, Error> {
+    Ok(grid.main_loop()?.len().div_ceil(2))
 }
 
 #[cfg(test)]
@@ -29,7 +13,18 @@ mod tests {
     #[test]
     fn sample() {
         for (text, want) in include_str!("sample1.txt").split("\n\n").zip([4, 4, 8, 8]) {
-            assert_eq!(solve(text), want);
+            assert_eq!(solve(&Grid::parse(text).unwrap()).unwrap(), want);
+        }
+    }
+
+    #[test]
+    fn no_loop() {
+        for (text, want) in [
+            ("...\n.S.\n...\n", "start tile has no exits"),
+            (".....\n.S-7.\n.|.|.\n.L-..\n.....\n", "pipe loop is broken"),
+        ] {
+            let err = solve(&Grid::parse(text).unwrap()).unwrap_err();
+            assert_eq!(err.to_string(), want);
         }
     }
 }
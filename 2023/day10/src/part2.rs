@@ -1,80 +1,56 @@
 use std::collections::HashSet;
 
-use crate::{direction::Direction, grid::Grid, position::Position, tile::Tile};
+use advent_common::grid::Position;
+use advent_common::Error;
 
-pub fn solve(text: &str) -> usize {
+use crate::{grid::Grid, tile::Tile};
+
+pub fn solve(grid: &Grid) -> Result<usize, Error> {
     // Remove junk pipe.
     // Expand the grid, so passages become ground.
     // From border ground, infect all exterior.  Infection my be diagonal.
     // Collapse the grid, removing all expanded cells.
     // Count uninfected ground cells.
 
-    let mut grid = Grid::from_str(text);
+    let mut grid = grid.clone();
     let start = grid.start();
 
-    let mut main = HashSet::from([start]);
-    let mut old = start;
-    let mut new = grid
-        .exits(start)
-        .next()
-        .expect("somewhere reachable from the start position");
-
-    while new != start {
-        main.insert(new);
-        let pos = grid
-            .exits(new)
-            .find(|&pos| pos != old)
-            .expect("somewhere reachable from each reachable position");
-        (old, new) = (new, pos);
-    }
+    let main: HashSet<Position> = grid.main_loop()?.into_iter().collect();
 
     // Clear junk.
-    for i in 0..grid.height() {
-        for j in 0..grid.width() {
-            let pos = Position(i, j);
-            if !main.contains(&pos) {
-                grid.set(pos, Tile::Ground);
-            }
+    for pos in grid.positions() {
+        if !main.contains(&pos) {
+            grid.set(pos, Tile::Ground);
         }
     }
 
     // To expand the start node, the grid needs to know what kind of pipe it is.
     grid.set(
         start,
-        Tile::from_exits(grid.exits(start).map(|exit| start.dir(exit))),
+        Tile::from_exits(grid.exits(start).map(|exit| {
+            start
+                .direction_to(exit)
+                .expect("exits are orthogonally adjacent")
+        }))?,
     );
 
     let collapsed = grid.clone();
 
-    let grid = grid.expand();
+    let grid = grid.expand()?;
     let mut exterior = Vec::new();
     let (height, width) = (grid.height(), grid.width());
     exterior.extend(
-        (0..width)
-            .flat_map(|j| [Position(0, j), Position(height - 1, j)])
-            .chain((1..height - 1).flat_map(|i| [Position(i, 0), Position(i, width - 1)]))
+        grid.positions()
+            .filter(|pos| {
+                pos.row == 0 || pos.row == height - 1 || pos.column == 0 || pos.column == width - 1
+            })
             .filter(|&pos| grid.is_ground(pos)),
     );
 
     let mut seen = HashSet::new();
     while let Some(pos) = exterior.pop() {
         seen.insert(pos);
-        let cardinals @ [north, _, south, _] = [
-            pos.go(Direction::North),
-            pos.go(Direction::East),
-            pos.go(Direction::South),
-            pos.go(Direction::West),
-        ];
-        let mut neighbors: Vec<Position> = cardinals.iter().cloned().flatten().collect();
-        if let Some(north) = north {
-            neighbors.extend(north.go(Direction::East));
-            neighbors.extend(north.go(Direction::West));
-        }
-        if let Some(south) = south {
-            neighbors.extend(south.go(Direction::East));
-            neighbors.extend(south.go(Direction::West));
-        }
-        for neighbor in neighbors {
+        for neighbor in grid.neighbors(pos) {
             if grid.is_ground(neighbor) && seen.insert(neighbor) {
                 exterior.push(neighbor);
             }
@@ -84,8 +60,8 @@ pub fn solve(text: &str) -> usize {
     let grid = collapsed;
     let seen: HashSet<Position> = seen
         .into_iter()
-        .filter(|pos| pos.0 % 2 == 0 && pos.1 % 2 == 0)
-        .map(|pos| Position(pos.0 / 2, pos.1 / 2))
+        .filter(|pos| pos.row % 2 == 0 && pos.column % 2 == 0)
+        .map(|pos| Position::new(pos.row / 2, pos.column / 2))
         .collect();
 
     let interior: Vec<Position> = grid
@@ -98,10 +74,10 @@ pub fn solve(text: &str) -> usize {
     {
         let mut ascii = grid.to_ascii();
         for &pos in seen.iter() {
-            ascii[pos.0][pos.1] = b'O';
+            ascii[pos.row][pos.column] = b'O';
         }
         for &pos in interior.iter() {
-            ascii[pos.0][pos.1] = b'I';
+            ascii[pos.row][pos.column] = b'I';
         }
         let lines: Vec<String> = ascii
             .into_iter()
@@ -110,7 +86,7 @@ pub fn solve(text: &str) -> usize {
         eprintln!("{}\n", lines.join("\n"));
     };
 
-    interior.len()
+    Ok(interior.len())
 }
 
 #[cfg(test)]
@@ -121,7 +97,14 @@ mod tests {
     fn sample() {
         let samples = include_str!("sample2.txt").split("\n\n");
         for (text, want) in samples.zip([4, 4, 8, 10]) {
-            assert_eq!(solve(text), want);
+            assert_eq!(solve(&Grid::parse(text).unwrap()).unwrap(), want);
         }
     }
+
+    #[test]
+    fn second_start() {
+        let text = ".....\n.S-7.\n.|.|.\n.L-S.\n.....\n";
+        let err = Grid::parse(text).err().unwrap();
+        assert_eq!(err.to_string(), "4:4: expected only one start tile");
+    }
 }
@@ -1,4 +1,5 @@
-use crate::direction::Direction;
+use advent_common::grid::Direction;
+use advent_common::Error;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 #[repr(u32)]
@@ -14,39 +15,35 @@ pub enum Tile {
 }
 
 impl Tile {
-    pub fn from_ascii(c: u8) -> Tile {
+    pub fn from_ascii(c: u8) -> Result<Tile, Error> {
         match c {
-            b'|' => Tile::VerticalPipe,
-            b'-' => Tile::HorizontalPipe,
-            b'L' => Tile::NorthEastBend,
-            b'J' => Tile::NorthWestBend,
-            b'7' => Tile::SouthWestBend,
-            b'F' => Tile::SouthEastBend,
-            b'.' | b'I' | b'O' => Tile::Ground,
-            b'S' => Tile::Start,
-            _ => panic!("{}: bad tile", c as char),
+            b'|' => Ok(Tile::VerticalPipe),
+            b'-' => Ok(Tile::HorizontalPipe),
+            b'L' => Ok(Tile::NorthEastBend),
+            b'J' => Ok(Tile::NorthWestBend),
+            b'7' => Ok(Tile::SouthWestBend),
+            b'F' => Ok(Tile::SouthEastBend),
+            b'.' | b'I' | b'O' => Ok(Tile::Ground),
+            b'S' => Ok(Tile::Start),
+            _ => Err(Error::parse(format!("{}: bad tile", c.escape_ascii()))),
         }
     }
 
-    pub fn from_exits(exits: impl IntoIterator<Item = Direction>) -> Tile {
-        let mut exits = exits.into_iter();
-        let mut dirs = [
-            exits.next().expect("an exit"),
-            exits.next().expect("another exit"),
-        ];
-        if let Some(exit) = exits.next() {
-            panic!("{exit:?}: unexpected third exit")
-        }
+    /// Returns the pipe with exactly the two given exits.
+    pub fn from_exits(exits: impl IntoIterator<Item = Direction>) -> Result<Tile, Error> {
+        let mut dirs: Vec<Direction> = exits.into_iter().collect();
         dirs.sort();
-        match dirs {
-            [dir1, dir2] if dir1 == dir2 => panic!("exits should differ"),
-            [Direction::North, Direction::East] => Tile::NorthEastBend,
-            [Direction::North, Direction::South] => Tile::VerticalPipe,
-            [Direction::North, Direction::West] => Tile::NorthWestBend,
-            [Direction::East, Direction::South] => Tile::SouthEastBend,
-            [Direction::East, Direction::West] => Tile::HorizontalPipe,
-            [Direction::South, Direction::West] => Tile::SouthWestBend,
-            _ => unreachable!(),
+        match dirs.as_slice() {
+            [Direction::North, Direction::East] => Ok(Tile::NorthEastBend),
+            [Direction::North, Direction::South] => Ok(Tile::VerticalPipe),
+            [Direction::North, Direction::West] => Ok(Tile::NorthWestBend),
+            [Direction::East, Direction::South] => Ok(Tile::SouthEastBend),
+            [Direction::East, Direction::West] => Ok(Tile::HorizontalPipe),
+            [Direction::South, Direction::West] => Ok(Tile::SouthWestBend),
+            [dir1, dir2] if dir1 == dir2 => {
+                Err(Error::parse(format!("{dir1:?}: exits should differ")))
+            }
+            _ => Err(Error::parse(format!("{dirs:?}: expected two exits"))),
         }
     }
 
@@ -64,29 +61,29 @@ impl Tile {
         }
     }
 
-    pub fn expand_east(self) -> Tile {
+    pub fn expand_east(self) -> Result<Tile, Error> {
         match self {
-            Tile::VerticalPipe => Tile::Ground,
-            Tile::HorizontalPipe => Tile::HorizontalPipe,
-            Tile::NorthEastBend => Tile::HorizontalPipe,
-            Tile::NorthWestBend => Tile::Ground,
-            Tile::SouthWestBend => Tile::Ground,
-            Tile::SouthEastBend => Tile::HorizontalPipe,
-            Tile::Ground => Tile::Ground,
-            Tile::Start => panic!("can't expand start tile"),
+            Tile::VerticalPipe => Ok(Tile::Ground),
+            Tile::HorizontalPipe => Ok(Tile::HorizontalPipe),
+            Tile::NorthEastBend => Ok(Tile::HorizontalPipe),
+            Tile::NorthWestBend => Ok(Tile::Ground),
+            Tile::SouthWestBend => Ok(Tile::Ground),
+            Tile::SouthEastBend => Ok(Tile::HorizontalPipe),
+            Tile::Ground => Ok(Tile::Ground),
+            Tile::Start => Err(Error::parse("can't expand start tile")),
         }
     }
 
-    pub fn expand_south(self) -> Tile {
+    pub fn expand_south(self) -> Result<Tile, Error> {
         match self {
-            Tile::VerticalPipe => Tile::VerticalPipe,
-            Tile::HorizontalPipe => Tile::Ground,
-            Tile::NorthEastBend => Tile::Ground,
-            Tile::NorthWestBend => Tile::Ground,
-            Tile::SouthWestBend => Tile::VerticalPipe,
-            Tile::SouthEastBend => Tile::VerticalPipe,
-            Tile::Ground => Tile::Ground,
-            Tile::Start => panic!("can't expand start tile"),
+            Tile::VerticalPipe => Ok(Tile::VerticalPipe),
+            Tile::HorizontalPipe => Ok(Tile::Ground),
+            Tile::NorthEastBend => Ok(Tile::Ground),
+            Tile::NorthWestBend => Ok(Tile::Ground),
+            Tile::SouthWestBend => Ok(Tile::VerticalPipe),
+            Tile::SouthEastBend => Ok(Tile::VerticalPipe),
+            Tile::Ground => Ok(Tile::Ground),
+            Tile::Start => Err(Error::parse("can't expand start tile")),
         }
     }
 
@@ -103,6 +100,49 @@ impl Tile {
 mod tests {
     use super::*;
 
+    #[test]
+    fn tile_from_ascii() {
+        assert_eq!(Tile::from_ascii(b'7').unwrap(), Tile::SouthWestBend);
+        let err = Tile::from_ascii(b'x').unwrap_err();
+        assert_eq!(err.to_string(), "x: bad tile");
+    }
+
+    #[test]
+    fn tile_from_exits() {
+        use Direction::*;
+        assert_eq!(
+            Tile::from_exits([West, North]).unwrap(),
+            Tile::NorthWestBend
+        );
+        assert_eq!(
+            Tile::from_exits([South, East]).unwrap(),
+            Tile::SouthEastBend
+        );
+        let err = Tile::from_exits([]).unwrap_err();
+        assert_eq!(err.to_string(), "[]: expected two exits");
+        let err = Tile::from_exits([North]).unwrap_err();
+        assert_eq!(err.to_string(), "[North]: expected two exits");
+        let err = Tile::from_exits([North, East, South]).unwrap_err();
+        assert_eq!(err.to_string(), "[North, East, South]: expected two exits");
+        let err = Tile::from_exits([East, East]).unwrap_err();
+        assert_eq!(err.to_string(), "East: exits should differ");
+    }
+
+    #[test]
+    fn tile_expand() {
+        assert_eq!(
+            Tile::NorthEastBend.expand_east().unwrap(),
+            Tile::HorizontalPipe
+        );
+        assert_eq!(
+            Tile::SouthWestBend.expand_south().unwrap(),
+            Tile::VerticalPipe
+        );
+        let err = Tile::Start.expand_east().unwrap_err();
+        assert_eq!(err.to_string(), "can't expand start tile");
+        assert!(Tile::Start.expand_south().is_err());
+    }
+
     #[test]
     fn tile_is_open_to() {
         assert!(Tile::VerticalPipe.is_open_to(Direction::North));
@@ -1,4 +1,5 @@
-struct Position(usize, usize);
+use advent_common::grid::{self, Position};
+use advent_common::{Error, Solver};
 
 enum Tile {
     Empty,
@@ -6,11 +7,11 @@ enum Tile {
 }
 
 impl Tile {
-    fn from_ascii(c: u8) -> Tile {
+    fn from_ascii(c: u8) -> Result<Tile, Error> {
         match c {
-            b'.' => Tile::Empty,
-            b'#' => Tile::Galaxy,
-            _ => panic!("{}: bad tile", c as char),
+            b'.' => Ok(Tile::Empty),
+            b'#' => Ok(Tile::Galaxy),
+            _ => Err(Error::parse(format!("{}: bad tile", c as char))),
         }
     }
 
@@ -34,45 +35,21 @@ where
         .collect()
 }
 
-pub struct Grid(Vec<Vec<Tile>>);
+pub struct Grid(grid::Grid<Tile>);
 
 impl Grid {
-    pub fn parse(s: &str) -> Grid {
-        Grid(
-            s.lines()
-                .map(|line| line.bytes().map(Tile::from_ascii).collect())
-                .collect(),
-        )
-    }
-
-    fn rows(&self) -> impl Iterator<Item = &Vec<Tile>> {
-        self.0.iter()
-    }
-
-    fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &Tile>> {
-        (0..self.width()).map(move |j| self.rows().map(move |row| &row[j]))
-    }
-
-    fn height(&self) -> usize {
-        self.0.len()
-    }
-
-    fn width(&self) -> usize {
-        self.0.first().map(|row| row.len()).unwrap_or_default()
-    }
-
-    fn enumerate(&self) -> impl Iterator<Item = (Position, &Tile)> + '_ {
-        (0..self.height())
-            .flat_map(|i| (0..self.width()).map(move |j| Position(i, j)))
-            .map(|p @ Position(i, j)| (p, &self.0[i][j]))
+    pub fn parse(s: &str) -> Result<Grid, Error> {
+        grid::Grid::parse(s, Tile::from_ascii).map(Grid)
     }
 
     fn galaxies_with_expansion(&self, expansion: usize) -> impl Iterator<Item = Position> + '_ {
-        let row_offsets = offsets(self.rows(), expansion);
-        let column_offsets = offsets(self.columns(), expansion);
-        self.enumerate()
-            .filter(move |(_, t)| !t.is_empty())
-            .map(move |(Position(i, j), _)| Position(i + row_offsets[i], j + column_offsets[j]))
+        let row_offsets = offsets(self.0.rows(), expansion);
+        let column_offsets = offsets(self.0.columns(), expansion);
+        self.0.iter().filter(move |(_, t)| !t.is_empty()).map(
+            move |(Position { row, column }, _)| {
+                Position::new(row + row_offsets[row], column + column_offsets[column])
+            },
+        )
     }
 
     pub fn distance_with_expansion(&self, expansion: usize) -> usize {
@@ -83,19 +60,39 @@ impl Grid {
             .flat_map(|(index, p)| {
                 galaxies[index + 1..]
                     .iter()
-                    .map(|q| p.0.abs_diff(q.0) + p.1.abs_diff(q.1))
+                    .map(|q| p.row.abs_diff(q.row) + p.column.abs_diff(q.column))
             })
             .sum()
     }
 }
 
+pub struct Day11;
+
+impl Solver for Day11 {
+    type Puzzle<'a> = Grid;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Grid, Error> {
+        Grid::parse(text)
+    }
+
+    fn part1(grid: &Grid) -> Result<usize, Error> {
+        Ok(grid.distance_with_expansion(2))
+    }
+
+    fn part2(grid: &Grid) -> Result<usize, Error> {
+        Ok(grid.distance_with_expansion(1000000))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn sample() {
-        let grid = Grid::parse(include_str!("sample.txt"));
+        let grid = Grid::parse(include_str!("sample.txt")).unwrap();
         assert_eq!(grid.distance_with_expansion(2), 374);
         assert_eq!(grid.distance_with_expansion(10), 1030);
         assert_eq!(grid.distance_with_expansion(100), 8410);
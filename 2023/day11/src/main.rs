@@ -1,7 +1,15 @@
-use day11::Grid;
+use advent2023_day11::Grid;
+use advent_common::input;
 
 fn main() {
-    let grid = Grid::parse(include_str!("input.txt"));
+    let text = input::text_or(include_str!("input.txt")).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(3);
+    });
+    let grid = Grid::parse(&text).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(2);
+    });
     println!("{}", grid.distance_with_expansion(2));
     println!("{}", grid.distance_with_expansion(1000000));
 }
@@ -1,4 +1,5 @@
-use core::panic;
+use advent_common::parse::{self, Parser};
+use advent_common::Error;
 
 /// Counts of red, green, and blue marbles, respectively.
 #[derive(Debug)]
@@ -11,19 +12,21 @@ impl Sample {
         self.0 <= 12 && self.1 <= 13 && self.2 <= 14
     }
 
-    fn parse(s: &str) -> Option<Sample> {
+    fn parse(p: &mut Parser) -> Result<Sample, Error> {
         let mut sample = Sample(0, 0, 0);
-        for part in s.trim().split(',') {
-            let (count, color) = part.trim().split_once(' ').expect("space");
-            let count: u32 = count.parse().expect("count");
-            match color {
+        p.separated(", ", |p| {
+            let count: u32 = p.unsigned()?;
+            p.literal(" ")?;
+            let start = p.clone();
+            match p.take_while(|c| c.is_ascii_alphabetic()) {
                 "red" => sample.0 += count,
                 "green" => sample.1 += count,
                 "blue" => sample.2 += count,
-                _ => panic!("{color}: bad color"),
+                _ => return Err(start.error("expected red, green, or blue")),
             }
-        }
-        Some(sample)
+            Ok(())
+        })?;
+        Ok(sample)
     }
 }
 
@@ -38,13 +41,16 @@ impl Game {
         self.samples.iter().all(|sample| sample.is_possible())
     }
 
-    pub fn parse(line: &str) -> Game {
-        let (game, samples) = line.split_once(':').expect("colon");
-        let (_, id) = game.split_once(' ').expect("space");
-        Game {
-            id: id.parse().expect("id"),
-            samples: samples.split(';').filter_map(Sample::parse).collect(),
-        }
+    fn parse(p: &mut Parser) -> Result<Game, Error> {
+        let id = p.field("Game", " ", Parser::unsigned)?;
+        p.literal(": ")?;
+        let samples = p.separated("; ", Sample::parse)?;
+        Ok(Game { id, samples })
+    }
+
+    /// Parses one game per line.
+    pub fn parse_all(text: &str) -> Result<Vec<Game>, Error> {
+        parse::all(text, |p| p.lines(Game::parse))
     }
 
     pub fn product(&self) -> u32 {
@@ -54,3 +60,18 @@ impl Game {
         maxes.0 * maxes.1 * maxes.2
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_games() {
+        let err = Game::parse_all("Game 1: 3 blue, 4 purple\n").unwrap_err();
+        assert_eq!(err.to_string(), "1:19: expected red, green, or blue");
+        let err = Game::parse_all("Game 1: 3 blue 4 red\n").unwrap_err();
+        assert_eq!(err.to_string(), "1:15: expected end of line");
+        let err = Game::parse_all("Game one: 3 blue\n").unwrap_err();
+        assert_eq!(err.to_string(), "1:6: expected number");
+    }
+}
@@ -1,3 +1,27 @@
 mod game;
 pub mod part1;
 pub mod part2;
+
+pub use game::Game;
+
+use advent_common::{Error, Solver};
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    type Puzzle<'a> = Vec<Game>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Vec<Game>, Error> {
+        Game::parse_all(text)
+    }
+
+    fn part1(games: &Vec<Game>) -> Result<u32, Error> {
+        Ok(part1::solve(games))
+    }
+
+    fn part2(games: &Vec<Game>) -> Result<u32, Error> {
+        Ok(part2::solve(games))
+    }
+}
@@ -1,5 +1,15 @@
+use advent2023_day2::{part1, part2, Game};
+use advent_common::input;
+
 fn main() {
-    let text = include_str!("input.txt");
-    println!("{}", day2::part1::solve(text));
-    println!("{}", day2::part2::solve(text));
+    let text = input::text_or(include_str!("input.txt")).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(3);
+    });
+    let games = Game::parse_all(&text).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(2);
+    });
+    println!("{}", part1::solve(&games));
+    println!("{}", part2::solve(&games));
 }
@@ -1,8 +1,8 @@
 use crate::game::Game;
 
-pub fn solve(text: &str) -> u32 {
-    text.lines()
-        .map(Game::parse)
+pub fn solve(games: &[Game]) -> u32 {
+    games
+        .iter()
         .filter_map(|game| game.is_possible().then_some(game.id))
         .sum()
 }
@@ -13,6 +13,7 @@ mod tests {
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample.txt")), 8);
+        let games = Game::parse_all(include_str!("sample.txt")).unwrap();
+        assert_eq!(solve(&games), 8);
     }
 }
@@ -1,10 +1,7 @@
 use crate::game::Game;
 
-pub fn solve(text: &str) -> u32 {
-    text.lines()
-        .map(Game::parse)
-        .map(|game| game.product())
-        .sum()
+pub fn solve(games: &[Game]) -> u32 {
+    games.iter().map(Game::product).sum()
 }
 
 #[cfg(test)]
@@ -13,6 +10,7 @@ mod tests {
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample.txt")), 2286);
+        let games = Game::parse_all(include_str!("sample.txt")).unwrap();
+        assert_eq!(solve(&games), 2286);
     }
 }
@@ -1,3 +1,30 @@
 pub mod part1;
 pub mod part2;
 mod span;
+
+use advent_common::{Error, Solver};
+
+/// Returns the bytes of each row of the engine schematic.
+pub fn rows(text: &str) -> Vec<Vec<u8>> {
+    text.lines().map(|line| line.bytes().collect()).collect()
+}
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    type Puzzle<'a> = Vec<Vec<u8>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Vec<Vec<u8>>, Error> {
+        Ok(rows(text))
+    }
+
+    fn part1(rows: &Vec<Vec<u8>>) -> Result<u32, Error> {
+        Ok(part1::solve(rows))
+    }
+
+    fn part2(rows: &Vec<Vec<u8>>) -> Result<u32, Error> {
+        Ok(part2::solve(rows))
+    }
+}
@@ -1,5 +1,12 @@
+use advent2023_day3::{part1, part2, rows};
+use advent_common::input;
+
 fn main() {
-    let text = include_str!("input.txt");
-    println!("{}", day3::part1::solve(text));
-    println!("{}", day3::part2::solve(text));
+    let text = input::text_or(include_str!("input.txt")).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(3);
+    });
+    let rows = rows(&text);
+    println!("{}", part1::solve(&rows));
+    println!("{}", part2::solve(&rows));
 }
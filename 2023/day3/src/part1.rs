@@ -4,30 +4,29 @@ fn is_symbol(c: u8) -> bool {
     c != b'.' && !c.is_ascii_digit()
 }
 
-pub fn solve(text: &str) -> u32 {
-    let rows: Vec<Vec<u8>> = text.lines().map(|line| line.bytes().collect()).collect();
-
+pub fn solve(rows: &[Vec<u8>]) -> u32 {
     // For each row, for each number, compute the number's column index range.
-    let mut spans = spans(&rows);
+    let mut spans = spans(rows);
 
     // Discard spans that don't have adjacent symbols in any direction.
     for (i, row_spans) in spans.iter_mut().enumerate() {
-        row_spans.retain(|span| adjacencies(&rows, i, span).any(|(i, j)| is_symbol(rows[i][j])));
+        row_spans.retain(|span| adjacencies(rows, i, span).any(|(i, j)| is_symbol(rows[i][j])));
     }
 
     // Parse the remaining spans into numbers, and sum them.
-    rows.into_iter()
+    rows.iter()
         .zip(spans)
-        .flat_map(|(row, spans)| spans.into_iter().map(move |span| parse(&row, span)))
+        .flat_map(|(row, spans)| spans.into_iter().map(move |span| parse(row, span)))
         .sum()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rows;
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample.txt")), 4361)
+        assert_eq!(solve(&rows(include_str!("sample.txt"))), 4361)
     }
 }
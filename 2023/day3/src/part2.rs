@@ -2,13 +2,11 @@ use std::collections::HashMap;
 
 use crate::span::{adjacencies, parse, spans};
 
-pub fn solve(text: &str) -> u32 {
-    let rows: Vec<Vec<u8>> = text.lines().map(|line| line.bytes().collect()).collect();
-
+pub fn solve(rows: &[Vec<u8>]) -> u32 {
     let mut star_spans: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
-    for (i, row_spans) in spans(&rows).iter().enumerate() {
+    for (i, row_spans) in spans(rows).iter().enumerate() {
         for span in row_spans {
-            adjacencies(&rows, i, span)
+            adjacencies(rows, i, span)
                 .filter(|&(i, j)| rows[i][j] == b'*')
                 .for_each(|star| {
                     star_spans
@@ -31,9 +29,10 @@ pub fn solve(text: &str) -> u32 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::rows;
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample.txt")), 467835)
+        assert_eq!(solve(&rows(include_str!("sample.txt"))), 467835)
     }
 }
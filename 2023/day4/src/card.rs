@@ -1,27 +1,33 @@
+use advent_common::parse::{self, Parser};
+use advent_common::Error;
 use std::collections::HashSet;
 
-fn parse_numbers(s: &str) -> HashSet<u32> {
-    s.split_ascii_whitespace()
-        .map(|word| word.parse().expect("number"))
-        .collect()
-}
-
+#[derive(Debug)]
 pub struct Card {
     pub id: usize,
     pub count: usize, // how many winning numbers
 }
 
 impl Card {
-    pub fn from_line(line: &str) -> Card {
-        let (head, body) = line.split_once(':').expect("colon");
-        let (want, got) = body.split_once('|').expect("pipe");
-        let id = head[head.find(char::is_numeric).expect("card ID")..]
-            .parse()
-            .expect("card ID to be a number");
-        let count = parse_numbers(want)
-            .intersection(&parse_numbers(got))
-            .count();
-        Card { id, count }
+    fn parse(p: &mut Parser) -> Result<Card, Error> {
+        p.literal("Card")?;
+        p.spaces();
+        let id = p.unsigned()?;
+        p.literal(":")?;
+        let mut want = HashSet::new();
+        p.spaces();
+        while !p.try_literal("|") {
+            want.insert(p.unsigned::<u32>()?);
+            p.spaces();
+        }
+        let got: HashSet<u32> = p.spaced(Parser::unsigned)?.into_iter().collect();
+        let count = want.intersection(&got).count();
+        Ok(Card { id, count })
+    }
+
+    /// Parses one card per line.
+    pub fn parse_all(text: &str) -> Result<Vec<Card>, Error> {
+        parse::all(text, |p| p.lines(Card::parse))
     }
 }
 
@@ -31,14 +37,18 @@ mod tests {
 
     #[test]
     fn sample() {
-        for (index, (line, count)) in include_str!("sample.txt")
-            .lines()
-            .zip([4, 2, 2, 1, 0, 0])
-            .enumerate()
-        {
-            let card = Card::from_line(line);
+        let cards = Card::parse_all(include_str!("sample.txt")).unwrap();
+        for (index, (card, count)) in cards.iter().zip([4, 2, 2, 1, 0, 0]).enumerate() {
             assert_eq!(card.id, index + 1);
             assert_eq!(card.count, count);
         }
     }
+
+    #[test]
+    fn bad_cards() {
+        let err = Card::parse_all("Card 1: 41 48 17\n").unwrap_err();
+        assert_eq!(err.to_string(), "1:17: expected number");
+        let err = Card::parse_all("Card x: 41 | 83\n").unwrap_err();
+        assert_eq!(err.to_string(), "1:6: expected number");
+    }
 }
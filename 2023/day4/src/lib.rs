@@ -1,3 +1,27 @@
 mod card;
 pub mod part1;
 pub mod part2;
+
+pub use card::Card;
+
+use advent_common::{Error, Solver};
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    type Puzzle<'a> = Vec<Card>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<Card>, Error> {
+        Card::parse_all(text)
+    }
+
+    fn part1(cards: &Vec<Card>) -> Result<usize, Error> {
+        Ok(part1::solve(cards))
+    }
+
+    fn part2(cards: &Vec<Card>) -> Result<usize, Error> {
+        Ok(part2::solve(cards))
+    }
+}
@@ -1,5 +1,15 @@
+use advent2023_day4::{part1, part2, Card};
+use advent_common::input;
+
 fn main() {
-    let text = include_str!("input.txt");
-    println!("{}", day4::part1::solve(text));
-    println!("{}", day4::part2::solve(text));
+    let text = input::text_or(include_str!("input.txt")).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(3);
+    });
+    let cards = Card::parse_all(&text).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(2);
+    });
+    println!("{}", part1::solve(&cards));
+    println!("{}", part2::solve(&cards));
 }
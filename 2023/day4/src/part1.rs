@@ -1,9 +1,7 @@
 use crate::card::Card;
 
-pub fn solve(text: &str) -> usize {
-    text.lines()
-        .map(|line| 1 << Card::from_line(line).count >> 1)
-        .sum()
+pub fn solve(cards: &[Card]) -> usize {
+    cards.iter().map(|card| 1 << card.count >> 1).sum()
 }
 
 #[cfg(test)]
@@ -12,6 +10,9 @@ mod tests {
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample.txt")), 13);
+        assert_eq!(
+            solve(&Card::parse_all(include_str!("sample.txt")).unwrap()),
+            13
+        );
     }
 }
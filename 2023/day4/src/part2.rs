@@ -1,9 +1,8 @@
 use crate::card::Card;
 
-pub fn solve(text: &str) -> usize {
-    let cards: Vec<Card> = text.lines().map(Card::from_line).collect();
+pub fn solve(cards: &[Card]) -> usize {
     let mut counts = vec![1; cards.len()];
-    for card in &cards {
+    for card in cards {
         for other in &cards[card.id..card.id + card.count] {
             counts[other.id - 1] += counts[card.id - 1];
         }
@@ -17,6 +16,9 @@ mod tests {
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample.txt")), 30);
+        assert_eq!(
+            solve(&Card::parse_all(include_str!("sample.txt")).unwrap()),
+            30
+        );
     }
 }
@@ -1,3 +1,27 @@
 mod map;
 pub mod part1;
 pub mod part2;
+
+pub use map::Almanac;
+
+use advent_common::{Error, Solver};
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    type Puzzle<'a> = Almanac;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(text: &str) -> Result<Almanac, Error> {
+        Almanac::parse(text)
+    }
+
+    fn part1(almanac: &Almanac) -> Result<i64, Error> {
+        Ok(part1::solve(almanac))
+    }
+
+    fn part2(almanac: &Almanac) -> Result<i64, Error> {
+        part2::solve(almanac)
+    }
+}
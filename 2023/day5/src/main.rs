@@ -1,5 +1,21 @@
+use advent2023_day5::{part1, part2, Almanac};
+use advent_common::input;
+
 fn main() {
-    let text = include_str!("input.txt");
-    println!("{}", day5::part1::solve(text));
-    println!("{}", day5::part2::solve(text));
+    let text = input::text_or(include_str!("input.txt")).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(3);
+    });
+    let almanac = Almanac::parse(&text).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(2);
+    });
+    println!("{}", part1::solve(&almanac));
+    match part2::solve(&almanac) {
+        Ok(answer) => println!("{answer}"),
+        Err(err) => {
+            eprintln!("error: {err}");
+            std::process::exit(1);
+        }
+    }
 }
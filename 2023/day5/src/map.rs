@@ -1,4 +1,6 @@
-use std::{ops::Range, str::FromStr};
+use advent_common::parse::{self, Parser};
+use advent_common::Error;
+use std::ops::Range;
 
 struct MapLine {
     source_range: Range<i64>,
@@ -63,26 +65,13 @@ impl MapLine {
     }
 }
 
-impl FromStr for MapLine {
-    type Err = &'static str;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut words = s.splitn(3, ' ');
-        let target_start: i64 = words
-            .next()
-            .ok_or("expected start of target range")?
-            .parse()
-            .or(Err("target range start should be a number"))?;
-        let source_start: i64 = words
-            .next()
-            .ok_or("expected start of source range")?
-            .parse()
-            .or(Err("source range start should be a number"))?;
-        let range_len: i64 = words
-            .next()
-            .ok_or("expected range length")?
-            .parse()
-            .or(Err("range length should be a number"))?;
-        assert!(words.next().is_none());
+impl MapLine {
+    fn parse(p: &mut Parser) -> Result<MapLine, Error> {
+        let target_start: i64 = p.unsigned()?;
+        p.literal(" ")?;
+        let source_start: i64 = p.unsigned()?;
+        p.literal(" ")?;
+        let range_len: i64 = p.unsigned()?;
         Ok(MapLine {
             target_delta: target_start - source_start,
             source_range: (source_start..source_start + range_len),
@@ -120,10 +109,30 @@ impl Map {
     }
 }
 
-impl FromStr for Map {
-    type Err = &'static str;
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let lines: Result<Vec<MapLine>, _> = s.lines().skip(1).map(|line| line.parse()).collect();
-        Ok(Map { lines: lines? })
+impl Map {
+    fn parse(p: &mut Parser) -> Result<Map, Error> {
+        p.until(" map:")?;
+        p.literal(" map:\n")?;
+        Ok(Map {
+            lines: p.lines(MapLine::parse)?,
+        })
+    }
+}
+
+/// The seed numbers listed on the first line of the almanac, and the maps
+/// that follow them.
+pub struct Almanac {
+    pub seeds: Vec<i64>,
+    pub maps: Vec<Map>,
+}
+
+impl Almanac {
+    pub fn parse(text: &str) -> Result<Almanac, Error> {
+        parse::all(text, |p| {
+            let seeds = p.field("seeds", ":", |p| p.spaced(Parser::unsigned))?;
+            p.literal("\n\n")?;
+            let maps = p.paragraphs(Map::parse)?;
+            Ok(Almanac { seeds, maps })
+        })
     }
 }
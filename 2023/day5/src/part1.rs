@@ -1,20 +1,15 @@
-use crate::map::Map;
+use crate::map::Almanac;
 
-fn parse_seeds(line: &str) -> Vec<i64> {
-    let colon = line.find(':').expect("colon in seed line");
-    line[colon + 1..]
-        .split_ascii_whitespace()
-        .map(|s| s.parse::<i64>().expect("seed number"))
-        .collect()
-}
-
-pub fn solve(text: &str) -> i64 {
-    let mut paragraphs = text.split("\n\n");
-    let seeds = parse_seeds(paragraphs.next().expect("seeds on first line"));
-    let maps: Vec<Map> = paragraphs.map(|s| s.parse().expect("map")).collect();
-    seeds
-        .into_iter()
-        .map(|seed| maps.iter().fold(seed, |source, map| map.apply(source)))
+pub fn solve(almanac: &Almanac) -> i64 {
+    almanac
+        .seeds
+        .iter()
+        .map(|&seed| {
+            almanac
+                .maps
+                .iter()
+                .fold(seed, |source, map| map.apply(source))
+        })
         .min()
         .expect("at least one seed")
 }
@@ -25,6 +20,7 @@ mod tests {
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample.txt")), 35);
+        let almanac = Almanac::parse(include_str!("sample.txt")).unwrap();
+        assert_eq!(solve(&almanac), 35);
     }
 }
@@ -1,34 +1,29 @@
 use std::ops::Range;
 
-use crate::map::Map;
+use advent_common::Error;
 
-fn parse_seed_ranges(line: &str) -> Vec<Range<i64>> {
-    let colon = line.find(':').expect("colon in seed line");
-    let numbers: Vec<i64> = line[colon + 1..]
-        .split_ascii_whitespace()
-        .map(|s| s.parse::<i64>().expect("number in seed line"))
-        .collect();
-    numbers
-        .chunks_exact(2)
-        .map(|chunk| {
-            let &[start, len] = chunk else {
-                panic!("seed line should have an even number of values");
-            };
-            start..start + len
-        })
-        .collect()
+use crate::map::Almanac;
+
+fn seed_ranges(seeds: &[i64]) -> Result<Vec<Range<i64>>, Error> {
+    let chunks = seeds.chunks_exact(2);
+    if !chunks.remainder().is_empty() {
+        return Err(Error::parse(
+            "seed line should have an even number of values",
+        ));
+    }
+    Ok(chunks.map(|chunk| chunk[0]..chunk[0] + chunk[1]).collect())
 }
 
-pub fn solve(text: &str) -> i64 {
-    let mut paragraphs = text.split("\n\n");
-    let ranges = parse_seed_ranges(paragraphs.next().expect("seeds on first line"));
-    paragraphs
-        .map(|s| s.parse::<Map>().expect("map"))
+pub fn solve(almanac: &Almanac) -> Result<i64, Error> {
+    let ranges = seed_ranges(&almanac.seeds)?;
+    almanac
+        .maps
+        .iter()
         .fold(ranges, |sources, map| map.apply_ranges(sources))
         .into_iter()
         .map(|range| range.start)
         .min()
-        .expect("at least one seed")
+        .ok_or_else(Error::no_solution) // Every seed range is empty.
 }
 
 #[cfg(test)]
@@ -37,6 +32,20 @@ mod tests {
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample.txt")), 46);
+        let almanac = Almanac::parse(include_str!("sample.txt")).unwrap();
+        assert_eq!(solve(&almanac).unwrap(), 46);
+    }
+
+    #[test]
+    fn bad_seeds() {
+        let text = include_str!("sample.txt").replacen("seeds: 79 14", "seeds: 79", 1);
+        let err = solve(&Almanac::parse(&text).unwrap()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "seed line should have an even number of values"
+        );
+        let text = include_str!("sample.txt").replacen("79 14 55 13", "79 0", 1);
+        let err = solve(&Almanac::parse(&text).unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "no solution");
     }
 }
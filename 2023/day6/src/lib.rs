@@ -1,3 +1,28 @@
 mod count;
 pub mod part1;
 pub mod part2;
+mod sheet;
+
+pub use sheet::Sheet;
+
+use advent_common::{Error, Solver};
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    type Puzzle<'a> = Sheet;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(text: &str) -> Result<Sheet, Error> {
+        Sheet::parse(text)
+    }
+
+    fn part1(sheet: &Sheet) -> Result<i64, Error> {
+        Ok(part1::solve(sheet))
+    }
+
+    fn part2(sheet: &Sheet) -> Result<i64, Error> {
+        Ok(part2::solve(sheet))
+    }
+}
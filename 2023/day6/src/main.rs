@@ -1,5 +1,15 @@
+use advent2023_day6::{part1, part2, Sheet};
+use advent_common::input;
+
 fn main() {
-    let text = include_str!("input.txt");
-    println!("{}", day6::part1::solve(text));
-    println!("{}", day6::part2::solve(text));
+    let text = input::text_or(include_str!("input.txt")).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(3);
+    });
+    let sheet = Sheet::parse(&text).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(2);
+    });
+    println!("{}", part1::solve(&sheet));
+    println!("{}", part2::solve(&sheet));
 }
@@ -1,17 +1,11 @@
 use crate::count::count;
+use crate::sheet::Sheet;
 
-fn parse_line(line: &str) -> impl Iterator<Item = i64> + '_ {
-    line.split_ascii_whitespace()
-        .skip(1)
-        .map(|s| s.parse().expect("number"))
-}
-
-pub fn solve(text: &str) -> i64 {
-    let (times, distances) = text.split_once('\n').expect("two input lines");
-    let (times, distances) = (parse_line(times), parse_line(distances));
-    times
-        .zip(distances)
-        .map(|(time, distance)| count(time, distance))
+pub fn solve(sheet: &Sheet) -> i64 {
+    sheet
+        .races
+        .iter()
+        .map(|race| count(race.time, race.distance))
         .product()
 }
 
@@ -21,6 +15,7 @@ mod tests {
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample.txt")), 288);
+        let sheet = Sheet::parse(include_str!("sample.txt")).unwrap();
+        assert_eq!(solve(&sheet), 288);
     }
 }
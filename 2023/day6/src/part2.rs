@@ -1,17 +1,8 @@
 use crate::count::count;
+use crate::sheet::Sheet;
 
-fn parse_line(line: &str) -> i64 {
-    line[line.find(':').expect("colon") + 1..]
-        .trim()
-        .replace(' ', "")
-        .parse()
-        .expect("number")
-}
-
-pub fn solve(text: &str) -> i64 {
-    let (time, distance) = text.split_once('\n').expect("two input lines");
-    let (time, distance) = (parse_line(time), parse_line(distance));
-    count(time, distance)
+pub fn solve(sheet: &Sheet) -> i64 {
+    count(sheet.kerned.time, sheet.kerned.distance)
 }
 
 #[cfg(test)]
@@ -20,6 +11,7 @@ mod tests {
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample.txt")), 71503);
+        let sheet = Sheet::parse(include_str!("sample.txt")).unwrap();
+        assert_eq!(solve(&sheet), 71503);
     }
 }
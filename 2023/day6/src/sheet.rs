@@ -0,0 +1,63 @@
+use advent_common::parse::{self, Parser};
+use advent_common::Error;
+
+pub struct Race {
+    pub time: i64,
+    pub distance: i64, // the record to beat
+}
+
+impl Race {
+    fn parse(time: &str, distance: &str) -> Result<Race, Error> {
+        let (time, distance) = (time.parse()?, distance.parse()?);
+        Ok(Race { time, distance })
+    }
+}
+
+/// The races listed on the sheet, read both as separate races and, ignoring
+/// the spaces between them, as a single race.
+pub struct Sheet {
+    pub races: Vec<Race>,
+    pub kerned: Race,
+}
+
+fn digits<'a>(p: &mut Parser<'a>) -> Result<&'a str, Error> {
+    match p.take_while(|c| c.is_ascii_digit()) {
+        "" => Err(p.error("expected number")),
+        digits => Ok(digits),
+    }
+}
+
+impl Sheet {
+    pub fn parse(text: &str) -> Result<Sheet, Error> {
+        let (times, distances) = parse::all(text, |p| {
+            let times = p.field("Time", ":", |p| p.spaced(digits))?;
+            p.end_of_line()?;
+            let start = p.clone();
+            let distances = p.field("Distance", ":", |p| p.spaced(digits))?;
+            if distances.len() != times.len() {
+                return Err(start.error("expected a distance for each time"));
+            }
+            Ok((times, distances))
+        })?;
+        let races = times
+            .iter()
+            .zip(&distances)
+            .map(|(time, distance)| Race::parse(time, distance))
+            .collect::<Result<_, _>>()?;
+        let kerned = Race::parse(&times.concat(), &distances.concat())?;
+        Ok(Sheet { races, kerned })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_sheets() {
+        let err = Sheet::parse("Time: 7 15\nDistance: 9\n").err().unwrap();
+        assert_eq!(err.to_string(), "2:1: expected a distance for each time");
+        let err = Sheet::parse("Time: 7 x\nDistance: 9 40\n").err().unwrap();
+        assert_eq!(err.to_string(), "1:9: expected number");
+    }
+}
@@ -1,3 +1,6 @@
+use advent_common::parse::Parser;
+use advent_common::Error;
+
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Card {
     #[default]
@@ -19,23 +22,28 @@ pub enum Card {
 
 impl Card {
     /// Assumes J to mean Jack.  If you want Jokers, call with_jokers.
-    pub fn from_ascii(c: u8) -> Card {
-        match c {
-            b'2' => Card::Two,
-            b'3' => Card::Three,
-            b'4' => Card::Four,
-            b'5' => Card::Five,
-            b'6' => Card::Six,
-            b'7' => Card::Seven,
-            b'8' => Card::Eight,
-            b'9' => Card::Nine,
-            b'T' => Card::Ten,
-            b'J' => Card::Jack,
-            b'Q' => Card::Queen,
-            b'K' => Card::King,
-            b'A' => Card::Ace,
-            _ => panic!("bad card"),
+    pub fn parse(p: &mut Parser) -> Result<Card, Error> {
+        let labels = [
+            ("2", Card::Two),
+            ("3", Card::Three),
+            ("4", Card::Four),
+            ("5", Card::Five),
+            ("6", Card::Six),
+            ("7", Card::Seven),
+            ("8", Card::Eight),
+            ("9", Card::Nine),
+            ("T", Card::Ten),
+            ("J", Card::Jack),
+            ("Q", Card::Queen),
+            ("K", Card::King),
+            ("A", Card::Ace),
+        ];
+        for (label, card) in labels {
+            if p.try_literal(label) {
+                return Ok(card);
+            }
         }
+        Err(p.error("expected card"))
     }
 
     pub fn with_jokers(self) -> Card {
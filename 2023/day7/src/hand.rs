@@ -1,4 +1,6 @@
 use crate::card::Card;
+use advent_common::parse::Parser;
+use advent_common::Error;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum HandType {
@@ -11,7 +13,7 @@ pub enum HandType {
     FiveOfAKind,
 }
 
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct Hand(pub [Card; 5]);
 
 impl Hand {
@@ -79,12 +81,12 @@ impl Hand {
         self
     }
 
-    pub fn parse(s: &str) -> Hand {
-        let mut cards = s.bytes().map(Card::from_ascii);
+    pub fn parse(p: &mut Parser) -> Result<Hand, Error> {
         let mut hand = Hand::default();
-        hand.0
-            .fill_with(|| cards.next().expect("enough cards per hand"));
-        hand
+        for card in hand.0.iter_mut() {
+            *card = Card::parse(p)?;
+        }
+        Ok(hand)
     }
 }
 
@@ -113,10 +115,15 @@ impl PartialOrd for Hand {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_common::parse;
+
+    fn hand(s: &str) -> Hand {
+        parse::all(s, Hand::parse).unwrap()
+    }
 
     #[test]
     fn hand_cmp() {
-        let hands = ["32T3K", "KTJJT", "KK677", "T55J5", "QQQJA"].map(Hand::parse);
+        let hands = ["32T3K", "KTJJT", "KK677", "T55J5", "QQQJA"].map(hand);
         for (index, hand) in hands.iter().enumerate() {
             assert_eq!(hand, hand);
             for other in &hands[index + 1..] {
@@ -128,7 +135,7 @@ mod tests {
     #[test]
     fn hand_cmp_with_jokers() {
         let hands = ["32T3K", "KK677", "T55J5", "QQQJA", "KTJJT"]
-            .map(Hand::parse)
+            .map(hand)
             .map(Hand::with_jokers);
         for (index, hand) in hands.iter().enumerate() {
             assert_eq!(hand, hand);
@@ -147,7 +154,7 @@ mod tests {
             ("T55J5", HandType::ThreeOfAKind, HandType::FourOfAKind),
             ("QQQJA", HandType::ThreeOfAKind, HandType::FourOfAKind),
         ] {
-            let hand = Hand::parse(cards);
+            let hand = hand(cards);
             assert_eq!(hand.hand_type(), want);
             assert_eq!(hand.with_jokers().hand_type(), want_jokers)
         }
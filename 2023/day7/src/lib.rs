@@ -3,3 +3,27 @@ mod hand;
 mod line;
 pub mod part1;
 pub mod part2;
+
+pub use line::Line;
+
+use advent_common::{Error, Solver};
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    type Puzzle<'a> = Vec<Line>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(text: &str) -> Result<Vec<Line>, Error> {
+        Line::parse_all(text)
+    }
+
+    fn part1(lines: &Vec<Line>) -> Result<i64, Error> {
+        Ok(part1::solve(lines))
+    }
+
+    fn part2(lines: &Vec<Line>) -> Result<i64, Error> {
+        Ok(part2::solve(lines))
+    }
+}
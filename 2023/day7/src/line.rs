@@ -1,7 +1,9 @@
 use crate::hand::Hand;
+use advent_common::parse::{self, Parser};
+use advent_common::Error;
 
 /// Cards and bid.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Line {
     pub hand: Hand,
     pub bid: i64,
@@ -13,12 +15,16 @@ impl Line {
         self
     }
 
-    pub fn parse(s: &str) -> Line {
-        let (hand, bid) = s.split_once(' ').expect("hand and bid");
-        Line {
-            hand: Hand::parse(hand),
-            bid: bid.parse().expect("numeric bid"),
-        }
+    fn parse(p: &mut Parser) -> Result<Line, Error> {
+        let hand = Hand::parse(p)?;
+        p.literal(" ")?;
+        let bid = p.unsigned()?;
+        Ok(Line { hand, bid })
+    }
+
+    /// Parses one line per hand.
+    pub fn parse_all(text: &str) -> Result<Vec<Line>, Error> {
+        parse::all(text, |p| p.lines(Line::parse))
     }
 }
 
@@ -57,3 +63,18 @@ impl<I: IntoIterator<Item = Line>> IntoWinnings for I {
             .sum()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_lines() {
+        let err = Line::parse_all("32T3K 765\nT55X5 684\n").unwrap_err();
+        assert_eq!(err.to_string(), "2:4: expected card");
+        let err = Line::parse_all("32T3 765\n").unwrap_err();
+        assert_eq!(err.to_string(), "1:5: expected card");
+        let err = Line::parse_all("32T3KK 765\n").unwrap_err();
+        assert_eq!(err.to_string(), "1:6: expected \" \"");
+    }
+}
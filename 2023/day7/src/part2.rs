@@ -1,10 +1,7 @@
 use crate::line::{IntoWinnings, Line};
 
-pub fn solve(text: &str) -> i64 {
-    text.lines()
-        .map(Line::parse)
-        .map(Line::with_jokers)
-        .into_winnings()
+pub fn solve(lines: &[Line]) -> i64 {
+    lines.iter().cloned().map(Line::with_jokers).into_winnings()
 }
 
 #[cfg(test)]
@@ -13,6 +10,7 @@ mod tests {
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample.txt")), 5905);
+        let lines = Line::parse_all(include_str!("sample.txt")).unwrap();
+        assert_eq!(solve(&lines), 5905);
     }
 }
@@ -1,3 +1,27 @@
 mod node;
 pub mod part1;
 pub mod part2;
+
+pub use node::NodeMap;
+
+use advent_common::{Error, Solver};
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    type Puzzle<'a> = NodeMap<'a>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<NodeMap<'_>, Error> {
+        NodeMap::parse(text)
+    }
+
+    fn part1(map: &NodeMap) -> Result<usize, Error> {
+        part1::solve(map)
+    }
+
+    fn part2(map: &NodeMap) -> Result<usize, Error> {
+        part2::solve(map)
+    }
+}
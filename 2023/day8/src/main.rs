@@ -1,5 +1,27 @@
+use advent2023_day8::{part1, part2, NodeMap};
+use advent_common::input;
+
 fn main() {
-    let text = include_str!("input.txt");
-    println!("{}", day8::part1::solve(text));
-    println!("{}", day8::part2::solve(text));
+    let text = input::text_or(include_str!("input.txt")).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(3);
+    });
+    let map = NodeMap::parse(&text).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(2);
+    });
+    match part1::solve(&map) {
+        Ok(answer) => println!("{answer}"),
+        Err(err) => {
+            eprintln!("error: {err}");
+            std::process::exit(1);
+        }
+    }
+    match part2::solve(&map) {
+        Ok(answer) => println!("{answer}"),
+        Err(err) => {
+            eprintln!("error: {err}");
+            std::process::exit(1);
+        }
+    }
 }
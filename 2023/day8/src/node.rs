@@ -1,3 +1,5 @@
+use advent_common::parse::{self, Parser};
+use advent_common::{search, Error, ErrorKind};
 use std::collections::HashMap;
 
 #[derive(Clone, Copy)]
@@ -7,12 +9,23 @@ pub enum Direction {
 }
 
 impl Direction {
-    fn from_ascii(c: u8) -> Direction {
-        match c {
-            b'L' => Direction::Left,
-            b'R' => Direction::Right,
-            _ => unreachable!(),
+    fn parse_all(p: &mut Parser) -> Result<Vec<Direction>, Error> {
+        let directions = p.take_while(|c| c == 'L' || c == 'R');
+        if directions.is_empty() {
+            return Err(p.error("expected L or R"));
         }
+        let direction = |c| match c {
+            'L' => Direction::Left,
+            _ => Direction::Right,
+        };
+        Ok(directions.chars().map(direction).collect())
+    }
+}
+
+fn node_name<'a>(p: &mut Parser<'a>) -> Result<&'a str, Error> {
+    match p.take_while(|c| c.is_ascii_alphanumeric()) {
+        "" => Err(p.error("expected node name")),
+        name => Ok(name),
     }
 }
 
@@ -22,7 +35,17 @@ pub struct Node<'a> {
     right: &'a str,
 }
 
-impl Node<'_> {
+impl<'a> Node<'a> {
+    fn parse(p: &mut Parser<'a>) -> Result<Node<'a>, Error> {
+        let name = node_name(p)?;
+        p.literal(" = (")?;
+        let left = node_name(p)?;
+        p.literal(", ")?;
+        let right = node_name(p)?;
+        p.literal(")")?;
+        Ok(Node { name, left, right })
+    }
+
     pub fn next(&self, direction: Direction) -> &str {
         match direction {
             Direction::Left => self.left,
@@ -38,40 +61,67 @@ pub struct NodeMap<'a> {
 }
 
 impl NodeMap<'_> {
-    pub fn from_str(s: &str) -> NodeMap {
-        let (first, rest) = s.split_once("\n\n").expect("the second line to be blank");
-        let directions: Vec<Direction> = first.bytes().map(Direction::from_ascii).collect();
-        let nodes: Vec<Node> = rest
-            .lines()
-            .map(|line| Node {
-                name: &line[..3],
-                left: &line[7..10],
-                right: &line[12..15],
-            })
-            .collect();
+    /// Parses a line of directions, then a blank line, then one node per line.
+    /// Every node named as the left or right of another must have a line.
+    pub fn parse(text: &str) -> Result<NodeMap<'_>, Error> {
+        let (directions, nodes) = parse::all(text, |p| {
+            let directions = Direction::parse_all(p)?;
+            p.end_of_line()?;
+            p.end_of_line()?;
+            Ok((directions, p.lines(Node::parse)?))
+        })?;
         let indexes: HashMap<&str, usize> = nodes
             .iter()
             .enumerate()
             .map(|(index, node)| (node.name, index))
             .collect();
-        NodeMap {
+        for (index, node) in nodes.iter().enumerate() {
+            for next in [node.left, node.right] {
+                if !indexes.contains_key(next) {
+                    let what = format!("node {} has no line", next);
+                    return Err(Error::parse(what).at_line(index + 3));
+                }
+            }
+        }
+        Ok(NodeMap {
             directions,
             nodes,
             indexes,
-        }
+        })
     }
 
     /// Returns the path length from the specified start node to the first node
-    /// for which the specified is_final predicate returns true.
-    pub fn distance<F: Fn(&str) -> bool>(&self, start: &str, is_final: F) -> usize {
-        let mut index = self.indexes[start];
-        for (count, &direction) in self.directions.iter().cycle().enumerate() {
-            let node = &self.nodes[index];
-            if is_final(node.name) {
-                return count;
-            }
-            index = self.indexes[node.next(direction)];
-        }
-        unreachable!()
+    /// for which the specified is_final predicate returns true.  Each step of
+    /// a path depends on where we are in the cycle of directions, as well as
+    /// on the current node.  Returns an error if there is no such path.
+    pub fn distance<F: Fn(&str) -> bool>(&self, start: &str, is_final: F) -> Result<usize, Error> {
+        let start = *self
+            .indexes
+            .get(start)
+            .ok_or_else(|| Error::new(ErrorKind::NoSolution, format!("no node {}", start)))?;
+        let step = |&(index, turn): &(usize, usize)| {
+            let next = self.nodes[index].next(self.directions[turn]);
+            [(self.indexes[next], (turn + 1) % self.directions.len())]
+        };
+        let path = search::bfs([(start, 0)], step, |&(index, _)| {
+            is_final(self.nodes[index].name)
+        });
+        Ok(path.ok_or_else(Error::no_solution)?.cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bad_maps() {
+        let err = NodeMap::parse("LX\n\nAAA = (AAA, AAA)\n").err().unwrap();
+        assert_eq!(err.to_string(), "1:2: expected end of line");
+        let err = NodeMap::parse("L\n\nAAA = (AAA, ZZZ)\n").err().unwrap();
+        assert_eq!(err.to_string(), "3: node ZZZ has no line");
+        let map = NodeMap::parse("L\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\n").unwrap();
+        let err = map.distance("AAA", |name| name == "ZZZ").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NoSolution);
     }
 }
@@ -1,7 +1,8 @@
 use crate::node::NodeMap;
+use advent_common::Error;
 
-pub fn solve(text: &str) -> usize {
-    NodeMap::from_str(text).distance("AAA", |name| name == "ZZZ")
+pub fn solve(map: &NodeMap) -> Result<usize, Error> {
+    map.distance("AAA", |name| name == "ZZZ")
 }
 
 #[cfg(test)]
@@ -10,6 +11,7 @@ mod tests {
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample1.txt")), 2);
+        let map = NodeMap::parse(include_str!("sample1.txt")).unwrap();
+        assert_eq!(solve(&map).unwrap(), 2);
     }
 }
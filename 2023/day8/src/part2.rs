@@ -1,4 +1,5 @@
 use crate::node::NodeMap;
+use advent_common::Error;
 
 /// Returns the Greatest Common Divisor of a and b.
 fn gcd(mut a: usize, mut b: usize) -> usize {
@@ -13,14 +14,15 @@ fn lcm(a: usize, b: usize) -> usize {
     a * b / gcd(a, b)
 }
 
-pub fn solve(text: &str) -> usize {
-    let map = NodeMap::from_str(text);
-    map.nodes
-        .iter()
-        .map(|node| node.name)
-        .filter(|name| name.ends_with('A'))
-        .map(|start| map.distance(start, |name| name.ends_with('Z')))
-        .fold(1, lcm)
+pub fn solve(map: &NodeMap) -> Result<usize, Error> {
+    let mut multiple = 1;
+    for node in map.nodes.iter().filter(|node| node.name.ends_with('A')) {
+        multiple = lcm(
+            multiple,
+            map.distance(node.name, |name| name.ends_with('Z'))?,
+        );
+    }
+    Ok(multiple)
 }
 
 #[cfg(test)]
@@ -29,6 +31,7 @@ mod tests {
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample2.txt")), 6);
+        let map = NodeMap::parse(include_str!("sample2.txt")).unwrap();
+        assert_eq!(solve(&map).unwrap(), 6);
     }
 }
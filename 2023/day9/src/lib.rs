@@ -1,3 +1,27 @@
 pub mod part1;
 pub mod part2;
 mod row;
+
+pub use row::Row;
+
+use advent_common::{Error, Solver};
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    type Puzzle<'a> = Vec<Row>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(text: &str) -> Result<Vec<Row>, Error> {
+        Row::parse_all(text)
+    }
+
+    fn part1(rows: &Vec<Row>) -> Result<i32, Error> {
+        Ok(part1::solve(rows))
+    }
+
+    fn part2(rows: &Vec<Row>) -> Result<i32, Error> {
+        Ok(part2::solve(rows))
+    }
+}
@@ -1,5 +1,15 @@
+use advent2023_day9::{part1, part2, Row};
+use advent_common::input;
+
 fn main() {
-    let text = include_str!("input.txt");
-    println!("{}", day9::part1::solve(text));
-    println!("{}", day9::part2::solve(text));
+    let text = input::text_or(include_str!("input.txt")).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(3);
+    });
+    let rows = Row::parse_all(&text).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(2);
+    });
+    println!("{}", part1::solve(&rows));
+    println!("{}", part2::solve(&rows));
 }
@@ -1,7 +1,7 @@
 use crate::row::Row;
 
-pub fn solve(text: &str) -> i32 {
-    text.lines().map(Row::from_line).map(Row::solve).sum()
+pub fn solve(rows: &[Row]) -> i32 {
+    rows.iter().cloned().map(Row::solve).sum()
 }
 
 #[cfg(test)]
@@ -10,6 +10,7 @@ mod tests {
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample.txt")), 114);
+        let rows = Row::parse_all(include_str!("sample.txt")).unwrap();
+        assert_eq!(solve(&rows), 114);
     }
 }
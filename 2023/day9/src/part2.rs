@@ -1,8 +1,8 @@
 use crate::row::Row;
 
-pub fn solve(text: &str) -> i32 {
-    text.lines()
-        .map(Row::from_line)
+pub fn solve(rows: &[Row]) -> i32 {
+    rows.iter()
+        .cloned()
         .map(Row::backward)
         .map(Row::solve)
         .sum()
@@ -14,6 +14,7 @@ mod tests {
 
     #[test]
     fn sample() {
-        assert_eq!(solve(include_str!("sample.txt")), 2);
+        let rows = Row::parse_all(include_str!("sample.txt")).unwrap();
+        assert_eq!(solve(&rows), 2);
     }
 }
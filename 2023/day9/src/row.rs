@@ -1,3 +1,7 @@
+use advent_common::parse::{self, Parser};
+use advent_common::Error;
+
+#[derive(Clone, Debug)]
 pub struct Row(Vec<i32>);
 
 fn all_zeroes(values: &[i32]) -> bool {
@@ -14,11 +18,13 @@ fn into_deltas(mut values: Vec<i32>) -> (Vec<i32>, Option<i32>) {
 }
 
 impl Row {
-    pub fn from_line(line: &str) -> Row {
-        Row(line
-            .split(' ')
-            .map(|s| s.parse().expect("number"))
-            .collect())
+    fn parse(p: &mut Parser) -> Result<Row, Error> {
+        Ok(Row(p.separated(" ", Parser::signed)?))
+    }
+
+    /// Parses one row per line.
+    pub fn parse_all(text: &str) -> Result<Vec<Row>, Error> {
+        parse::all(text, |p| p.lines(Row::parse))
     }
 
     pub fn backward(mut self) -> Row {
@@ -45,15 +51,25 @@ mod tests {
 
     #[test]
     fn sample() {
-        for (line, want) in SAMPLE.lines().zip([18, 28, 68]) {
-            assert_eq!(Row::from_line(line).solve(), want);
+        let rows = Row::parse_all(SAMPLE).unwrap();
+        for (row, want) in rows.into_iter().zip([18, 28, 68]) {
+            assert_eq!(row.solve(), want);
         }
     }
 
     #[test]
     fn sample_backward() {
-        for (line, want) in SAMPLE.lines().zip([-3, 0, 5]) {
-            assert_eq!(Row::from_line(line).backward().solve(), want);
+        let rows = Row::parse_all(SAMPLE).unwrap();
+        for (row, want) in rows.into_iter().zip([-3, 0, 5]) {
+            assert_eq!(row.backward().solve(), want);
         }
     }
+
+    #[test]
+    fn bad_rows() {
+        let err = Row::parse_all("0 3 x\n").unwrap_err();
+        assert_eq!(err.to_string(), "1:5: expected number");
+        let err = Row::parse_all("0  3\n").unwrap_err();
+        assert_eq!(err.to_string(), "1:3: expected number");
+    }
 }
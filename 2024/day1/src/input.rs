@@ -1,29 +1,22 @@
+use advent_common::parse;
+use advent_common::Error;
 use std::str::FromStr;
 
 pub struct Input(pub Vec<u32>, pub Vec<u32>);
 
-#[derive(Debug)]
-pub enum Error {
-    BadLine,
-}
-
 impl FromStr for Input {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut xs = Vec::new();
-        let mut ys = Vec::new();
-        for line in s.lines() {
-            let mut parts = line.split_ascii_whitespace();
-            let (Some(x), Some(y), None) = (parts.next(), parts.next(), parts.next()) else {
-                return Err(Error::BadLine);
-            };
-            let (Ok(x), Ok(y)) = (x.parse::<u32>(), y.parse::<u32>()) else {
-                return Err(Error::BadLine);
-            };
-            xs.push(x);
-            ys.push(y);
-        }
+        let pairs: Vec<(u32, u32)> = parse::all(s, |p| {
+            p.lines(|p| {
+                let x = p.unsigned()?;
+                p.spaces();
+                let y = p.unsigned()?;
+                Ok((x, y))
+            })
+        })?;
+        let (xs, ys) = pairs.into_iter().unzip();
         Ok(Input(xs, ys))
     }
 }
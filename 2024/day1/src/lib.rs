@@ -1,7 +1,28 @@
 mod input;
 
-pub use input::Error as InputError;
 pub use input::Input;
 
 pub mod part1;
 pub mod part2;
+
+use advent_common::{Error, Solver};
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    type Puzzle<'a> = Input;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Input, Error> {
+        text.parse()
+    }
+
+    fn part1(Input(xs, ys): &Input) -> Result<u32, Error> {
+        Ok(part1::distance(xs.iter().copied(), ys.iter().copied()))
+    }
+
+    fn part2(Input(xs, ys): &Input) -> Result<usize, Error> {
+        Ok(part2::similarity(xs.iter().copied(), ys.iter().copied()))
+    }
+}
@@ -1,8 +1,15 @@
-use day1::{part1::distance, part2::similarity, Input};
+use advent2024_day1::{part1::distance, part2::similarity, Input};
+use advent_common::input;
 
 fn main() {
-    let s = include_str!("input");
-    let Input(xs, ys) = s.parse().unwrap();
+    let text = input::text_or(include_str!("input")).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(3);
+    });
+    let Input(xs, ys) = text.parse().unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(2);
+    });
     println!("{}", distance(xs.iter().cloned(), ys.iter().cloned()));
     println!("{}", similarity(xs, ys));
 }
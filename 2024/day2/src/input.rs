@@ -1,20 +1,8 @@
-use std::{num::ParseIntError, str::FromStr};
+use advent_common::Error;
+use std::str::FromStr;
 
 pub type Level = u8;
 
-#[derive(Debug)]
-pub enum LevelError {
-    Missing,
-    Parse(ParseIntError),
-    Extra,
-}
-
-impl From<ParseIntError> for LevelError {
-    fn from(value: ParseIntError) -> Self {
-        LevelError::Parse(value)
-    }
-}
-
 #[derive(Clone)]
 pub struct Report(
     /// Levels.
@@ -32,7 +20,7 @@ impl Report {
 }
 
 impl FromStr for Report {
-    type Err = LevelError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let levels = s
@@ -66,7 +54,7 @@ impl Input {
 }
 
 impl FromStr for Input {
-    type Err = LevelError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let reports = s
@@ -1,3 +1,27 @@
 mod input;
+
+pub use input::Input;
 pub mod part1;
 pub mod part2;
+
+use advent_common::{Error, Solver};
+
+pub struct Day2;
+
+impl Solver for Day2 {
+    type Puzzle<'a> = Input;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Input, Error> {
+        text.parse()
+    }
+
+    fn part1(input: &Input) -> Result<usize, Error> {
+        Ok(part1::count_safe(input))
+    }
+
+    fn part2(input: &Input) -> Result<usize, Error> {
+        Ok(part2::count_safe(input))
+    }
+}
@@ -1,7 +1,15 @@
-use day2::{part1, part2};
+use advent2024_day2::{part1, part2};
+use advent_common::input;
 
 fn main() {
-    let input = include_str!("input").parse().expect("input");
+    let text = input::text_or(include_str!("input")).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(3);
+    });
+    let input = text.parse().unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(2);
+    });
     println!("{}", part1::count_safe(&input));
     println!("{}", part2::count_safe(&input));
 }
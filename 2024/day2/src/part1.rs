@@ -1,8 +1,5 @@
 use crate::input::{Input, Report};
 
-pub type Error = crate::input::LevelError;
-pub type Result<T> = std::result::Result<T, Error>;
-
 pub fn is_safe(Report(levels): &Report) -> bool {
     let is_increasing = levels[0] < levels[1];
     levels[0..levels.len() - 1]
@@ -3,9 +3,6 @@ use crate::{
     part1,
 };
 
-pub type Error = crate::input::LevelError;
-pub type Result<T> = std::result::Result<T, Error>;
-
 fn is_safe(report: &Report) -> bool {
     let remove = |index| {
         let mut report = report.clone();
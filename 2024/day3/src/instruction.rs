@@ -0,0 +1,50 @@
+use advent_common::parse::Parser;
+use advent_common::Error;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Instruction {
+    Mul(u32, u32),
+    Do,
+    Dont,
+}
+
+impl Instruction {
+    fn parse_mul(p: &mut Parser) -> Result<Instruction, Error> {
+        p.literal("mul(")?;
+        let x = p.unsigned()?;
+        p.literal(",")?;
+        let y = p.unsigned()?;
+        p.literal(")")?;
+        Ok(Instruction::Mul(x, y))
+    }
+
+    /// Returns the well-formed instructions in the corrupted memory, skipping
+    /// everything else.
+    pub fn parse_all(memory: &str) -> Vec<Instruction> {
+        let mut instructions = Vec::new();
+        for (start, _) in memory.char_indices() {
+            let mut p = Parser::new(&memory[start..]);
+            if p.try_literal("do()") {
+                instructions.push(Instruction::Do);
+            } else if p.try_literal("don't()") {
+                instructions.push(Instruction::Dont);
+            } else if let Ok(mul) = Instruction::parse_mul(&mut p) {
+                instructions.push(mul);
+            }
+        }
+        instructions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_all() {
+        use Instruction::*;
+        let memory = "mul(2,4)don't()mul[3,7]mul(5,5do()mul(11,8)mul(2,+3)";
+        let want = [Mul(2, 4), Dont, Do, Mul(11, 8)];
+        assert_eq!(Instruction::parse_all(memory), want);
+    }
+}
@@ -1,2 +1,27 @@
+mod instruction;
 pub mod part1;
 pub mod part2;
+
+pub use instruction::Instruction;
+
+use advent_common::{Error, Solver};
+
+pub struct Day3;
+
+impl Solver for Day3 {
+    type Puzzle<'a> = Vec<Instruction>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(text: &str) -> Result<Vec<Instruction>, Error> {
+        Ok(Instruction::parse_all(text))
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> Result<u32, Error> {
+        Ok(part1::solve(instructions))
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> Result<u32, Error> {
+        Ok(part2::solve(instructions))
+    }
+}
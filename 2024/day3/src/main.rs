@@ -1,7 +1,12 @@
-use day3::{part1, part2};
+use advent2024_day3::{part1, part2, Instruction};
+use advent_common::input;
 
 fn main() {
-    let input = include_str!("input");
-    println!("{}", part1::solve(input));
-    println!("{}", part2::solve(input));
+    let text = input::text_or(include_str!("input")).unwrap_or_else(|err| {
+        eprintln!("error: {err}");
+        std::process::exit(3);
+    });
+    let instructions = Instruction::parse_all(&text);
+    println!("{}", part1::solve(&instructions));
+    println!("{}", part2::solve(&instructions));
 }
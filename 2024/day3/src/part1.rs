@@ -1,16 +1,12 @@
-const PREFIX: &str = "mul(";
-const SUFFIX: &str = ")";
+use crate::instruction::Instruction;
 
-pub fn solve(input: &str) -> u32 {
-    (0..input.len() - PREFIX.len() + 1)
-        .flat_map(|start| {
-            input[start..]
-                .starts_with(PREFIX)
-                .then_some(&input[start + PREFIX.len()..])
+pub fn solve(instructions: &[Instruction]) -> u32 {
+    instructions
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Mul(x, y) => x * y,
+            _ => 0,
         })
-        .flat_map(|tail| tail.find(SUFFIX).map(|len| &tail[..len]))
-        .flat_map(|args| args.split_once(','))
-        .flat_map(|(x, y)| Some(x.parse::<u32>().ok()? * y.parse::<u32>().ok()?))
         .sum()
 }
 
@@ -22,6 +18,6 @@ mod tests {
 
     #[test]
     fn test_solve() {
-        assert_eq!(solve(SAMPLE), 161);
+        assert_eq!(solve(&Instruction::parse_all(SAMPLE)), 161);
     }
 }
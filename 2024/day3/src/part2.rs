@@ -1,11 +1,17 @@
-use crate::part1;
+use crate::instruction::Instruction;
 
-pub fn solve(input: &str) -> u32 {
-    input
-        .split("do()")
-        .map(|part| &part[..part.find("don't()").unwrap_or(part.len())])
-        .map(part1::solve)
-        .sum()
+pub fn solve(instructions: &[Instruction]) -> u32 {
+    let mut enabled = true;
+    let mut sum = 0;
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mul(x, y) if enabled => sum += x * y,
+            Instruction::Mul(..) => (),
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+        }
+    }
+    sum
 }
 
 #[cfg(test)]
@@ -17,6 +23,6 @@ mod tests {
 
     #[test]
     fn test_solve() {
-        assert_eq!(solve(SAMPLE), 48);
+        assert_eq!(solve(&Instruction::parse_all(SAMPLE)), 48);
     }
 }
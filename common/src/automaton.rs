@@ -0,0 +1,477 @@
+//! Cellular automata over infinite, N-dimensional lattices in which every
+//! cell is either live or dead, and all but finitely many cells are dead.
+//!
+//! An [`Automaton`] combines a set of live cells, a [`Neighborhood`] saying
+//! which cells are adjacent to which, and a [`Rule`] saying which cells live
+//! in the next generation.  The live cells are stored by a [`Cells`] backend:
+//! [`Sparse`] keeps a set of live points, which suits patterns that are large
+//! and mostly empty, whereas [`Dense`] keeps a flag for every point inside
+//! the pattern's bounding box, which suits compact patterns in few dimensions.
+
+use crate::Error;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+/// Integer coordinates of a cell.
+pub type Point<const N: usize> = [isize; N];
+
+fn add<const N: usize>(p: Point<N>, q: Point<N>) -> Point<N> {
+    let mut sum = p;
+    for (x, y) in sum.iter_mut().zip(q) {
+        *x += y;
+    }
+    sum
+}
+
+/// Which cells count as neighbors of a cell.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Neighborhood {
+    /// Every cell differing by at most one in each coordinate: 8 neighbors
+    /// in two dimensions, 26 in three, and so on.
+    Moore,
+    /// Every cell differing by one in exactly one coordinate: 4 neighbors in
+    /// two dimensions, 6 in three, and so on.
+    VonNeumann,
+    /// The 6 neighbors of a two-dimensional hexagonal grid in axial
+    /// coordinates: east is (1, 0), and southeast is (0, 1).
+    Hex,
+}
+
+impl Neighborhood {
+    /// Returns the displacements from a cell to each of its neighbors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the neighborhood is [`Neighborhood::Hex`] and N isn't 2.
+    pub fn offsets<const N: usize>(self) -> Vec<Point<N>> {
+        match self {
+            Neighborhood::Moore => {
+                let count = 3usize.pow(N as u32);
+                (0..count)
+                    .map(|mut index| {
+                        let mut offset = [0; N];
+                        for x in offset.iter_mut() {
+                            *x = (index % 3) as isize - 1;
+                            index /= 3;
+                        }
+                        offset
+                    })
+                    .filter(|offset| offset.iter().any(|&x| x != 0))
+                    .collect()
+            }
+            Neighborhood::VonNeumann => (0..N)
+                .flat_map(|axis| {
+                    [-1, 1].iter().map(move |&x| {
+                        let mut offset = [0; N];
+                        offset[axis] = x;
+                        offset
+                    })
+                })
+                .collect(),
+            Neighborhood::Hex => {
+                assert_eq!(N, 2, "hex neighborhoods are two-dimensional");
+                [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)]
+                    .iter()
+                    .map(|&(q, r)| {
+                        let mut offset = [0; N];
+                        offset[0] = q;
+                        offset[1] = r;
+                        offset
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// A birth/survival rule: a dead cell becomes live if its number of live
+/// neighbors is one of the birth counts, and a live cell stays live if its
+/// number is one of the survival counts.  All other cells are dead in the
+/// next generation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rule {
+    birth: Vec<usize>,
+    survival: Vec<usize>,
+}
+
+impl Rule {
+    /// Returns the rule having the specified birth and survival counts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the birth counts include zero, since that would bring
+    /// infinitely many cells to life.
+    pub fn new(
+        birth: impl IntoIterator<Item = usize>,
+        survival: impl IntoIterator<Item = usize>,
+    ) -> Rule {
+        let birth: Vec<usize> = birth.into_iter().collect();
+        assert!(!birth.contains(&0), "cells can't be born without neighbors");
+        Rule {
+            birth,
+            survival: survival.into_iter().collect(),
+        }
+    }
+
+    /// Conway's Game of Life, B3/S23.
+    pub fn life() -> Rule {
+        Rule::new([3], [2, 3])
+    }
+
+    /// Returns whether a cell is live in the next generation, given whether
+    /// it is live now and how many of its neighbors are.
+    pub fn next(&self, live: bool, neighbors: usize) -> bool {
+        if live {
+            self.survival.contains(&neighbors)
+        } else {
+            self.birth.contains(&neighbors)
+        }
+    }
+}
+
+fn parse_counts(text: &str, prefix: char) -> Result<Vec<usize>, Error> {
+    let digits = text
+        .strip_prefix(prefix)
+        .ok_or_else(|| Error::parse(format!("{}: expected {}", text, prefix)))?;
+    digits
+        .chars()
+        .map(|c| {
+            c.to_digit(10)
+                .map(|d| d as usize)
+                .ok_or_else(|| Error::parse(format!("{}: bad neighbor count", c)))
+        })
+        .collect()
+}
+
+/// Parses rules in the usual B/S notation, like `B3/S23`.
+impl FromStr for Rule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Rule, Error> {
+        let (birth, survival) = s
+            .split_once('/')
+            .ok_or_else(|| Error::parse(format!("{}: expected B/S rule", s)))?;
+        let birth = parse_counts(birth, 'B')?;
+        if birth.contains(&0) {
+            return Err(Error::parse("cells can't be born without neighbors"));
+        }
+        Ok(Rule::new(birth, parse_counts(survival, 'S')?))
+    }
+}
+
+/// Storage for the live cells of an automaton.
+pub trait Cells<const N: usize>: Sized {
+    fn from_live(live: impl IntoIterator<Item = Point<N>>) -> Self;
+
+    fn is_live(&self, point: Point<N>) -> bool;
+
+    fn live(&self) -> Box<dyn Iterator<Item = Point<N>> + '_>;
+
+    fn population(&self) -> usize;
+
+    /// Returns the next generation, given the neighbor offsets and rule.
+    fn next(&self, offsets: &[Point<N>], rule: &Rule) -> Self;
+}
+
+/// The live cells of an automaton, as a set of points.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Sparse<const N: usize> {
+    live: HashSet<Point<N>>,
+}
+
+impl<const N: usize> Cells<N> for Sparse<N> {
+    fn from_live(live: impl IntoIterator<Item = Point<N>>) -> Self {
+        Sparse {
+            live: live.into_iter().collect(),
+        }
+    }
+
+    fn is_live(&self, point: Point<N>) -> bool {
+        self.live.contains(&point)
+    }
+
+    fn live(&self) -> Box<dyn Iterator<Item = Point<N>> + '_> {
+        Box::new(self.live.iter().copied())
+    }
+
+    fn population(&self) -> usize {
+        self.live.len()
+    }
+
+    fn next(&self, offsets: &[Point<N>], rule: &Rule) -> Self {
+        let mut counts: HashMap<Point<N>, usize> = HashMap::new();
+        for &point in &self.live {
+            for &offset in offsets {
+                *counts.entry(add(point, offset)).or_default() += 1;
+            }
+        }
+        // Live cells without live neighbors are missing from the counts.
+        let lonely = self
+            .live
+            .iter()
+            .filter(|point| !counts.contains_key(*point) && rule.next(true, 0));
+        let live = counts
+            .iter()
+            .filter(|&(&point, &count)| rule.next(self.is_live(point), count))
+            .map(|(&point, _)| point)
+            .chain(lonely.copied())
+            .collect();
+        Sparse { live }
+    }
+}
+
+/// The live cells of an automaton, as a flag for every point of the smallest
+/// box containing them all.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dense<const N: usize> {
+    min: Point<N>,
+    extent: [usize; N],
+    cells: Vec<bool>,
+    population: usize,
+}
+
+impl<const N: usize> Dense<N> {
+    fn index(&self, point: Point<N>) -> Option<usize> {
+        let mut index = 0;
+        for ((&x, &min), &extent) in point.iter().zip(&self.min).zip(&self.extent) {
+            let x = x - min;
+            if x < 0 || x as usize >= extent {
+                return None;
+            }
+            index = index * extent + x as usize;
+        }
+        Some(index)
+    }
+
+    fn point(&self, mut index: usize) -> Point<N> {
+        let mut point = self.min;
+        for (x, &extent) in point.iter_mut().zip(&self.extent).rev() {
+            *x += (index % extent) as isize;
+            index /= extent;
+        }
+        point
+    }
+
+    /// Returns this box grown by `margin` in every direction, with all new
+    /// cells dead.
+    fn grow(&self, margin: usize) -> Dense<N> {
+        let mut min = self.min;
+        let mut extent = self.extent;
+        for (lo, len) in min.iter_mut().zip(extent.iter_mut()) {
+            *lo -= margin as isize;
+            *len += 2 * margin;
+        }
+        let mut grown = Dense {
+            min,
+            extent,
+            cells: vec![false; extent.iter().product()],
+            population: self.population,
+        };
+        for point in self.live() {
+            let index = grown.index(point).expect("point within grown box");
+            grown.cells[index] = true;
+        }
+        grown
+    }
+}
+
+impl<const N: usize> Cells<N> for Dense<N> {
+    fn from_live(live: impl IntoIterator<Item = Point<N>>) -> Self {
+        let live: Vec<Point<N>> = live.into_iter().collect();
+        let mut min = [0; N];
+        let mut extent = [0; N];
+        if !live.is_empty() {
+            for (axis, (lo, len)) in min.iter_mut().zip(extent.iter_mut()).enumerate() {
+                *lo = live.iter().map(|p| p[axis]).min().unwrap();
+                let hi = live.iter().map(|p| p[axis]).max().unwrap();
+                *len = (hi - *lo) as usize + 1;
+            }
+        }
+        let mut dense = Dense {
+            min,
+            extent,
+            cells: vec![false; extent.iter().product()],
+            population: 0,
+        };
+        for point in live {
+            let index = dense.index(point).expect("point within bounds");
+            if !dense.cells[index] {
+                dense.cells[index] = true;
+                dense.population += 1;
+            }
+        }
+        dense
+    }
+
+    fn is_live(&self, point: Point<N>) -> bool {
+        self.index(point).map_or(false, |index| self.cells[index])
+    }
+
+    fn live(&self) -> Box<dyn Iterator<Item = Point<N>> + '_> {
+        Box::new(
+            self.cells
+                .iter()
+                .enumerate()
+                .filter(|&(_, &live)| live)
+                .map(|(index, _)| self.point(index)),
+        )
+    }
+
+    fn population(&self) -> usize {
+        self.population
+    }
+
+    fn next(&self, offsets: &[Point<N>], rule: &Rule) -> Self {
+        if self.population == 0 {
+            return self.clone();
+        }
+        let reach = offsets
+            .iter()
+            .flat_map(|offset| offset.iter().map(|x| x.unsigned_abs()))
+            .max()
+            .unwrap_or_default();
+        let grown = self.grow(reach);
+        let live = (0..grown.cells.len())
+            .map(|index| grown.point(index))
+            .filter(|&point| {
+                let count = offsets
+                    .iter()
+                    .filter(|&&offset| grown.is_live(add(point, offset)))
+                    .count();
+                rule.next(grown.is_live(point), count)
+            });
+        Dense::from_live(live)
+    }
+}
+
+/// A cellular automaton in N dimensions, storing its live cells in `C`.
+#[derive(Clone, Debug)]
+pub struct Automaton<const N: usize, C = Sparse<N>> {
+    cells: C,
+    offsets: Vec<Point<N>>,
+    rule: Rule,
+}
+
+impl<const N: usize, C: Cells<N>> Automaton<N, C> {
+    /// Returns an automaton in which exactly the specified cells are live.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the neighborhood doesn't apply to N dimensions.
+    pub fn new(
+        live: impl IntoIterator<Item = Point<N>>,
+        neighborhood: Neighborhood,
+        rule: Rule,
+    ) -> Automaton<N, C> {
+        Automaton {
+            cells: C::from_live(live),
+            offsets: neighborhood.offsets(),
+            rule,
+        }
+    }
+
+    pub fn is_live(&self, point: Point<N>) -> bool {
+        self.cells.is_live(point)
+    }
+
+    /// Returns the number of live neighbors of the specified cell.
+    pub fn count_neighbors(&self, point: Point<N>) -> usize {
+        self.offsets
+            .iter()
+            .filter(|&&offset| self.is_live(add(point, offset)))
+            .count()
+    }
+
+    /// Returns the live cells, in no particular order.
+    pub fn live(&self) -> impl Iterator<Item = Point<N>> + '_ {
+        self.cells.live()
+    }
+
+    /// Returns the number of live cells.
+    pub fn population(&self) -> usize {
+        self.cells.population()
+    }
+
+    /// Returns the least and greatest value of each coordinate of any live
+    /// cell, or None if no cells are live.
+    pub fn bounds(&self) -> Option<(Point<N>, Point<N>)> {
+        let mut live = self.live();
+        let first = live.next()?;
+        Some(live.fold((first, first), |(mut min, mut max), point| {
+            for ((lo, hi), x) in min.iter_mut().zip(max.iter_mut()).zip(point) {
+                *lo = x.min(*lo);
+                *hi = x.max(*hi);
+            }
+            (min, max)
+        }))
+    }
+
+    /// Advances the automaton by one generation.
+    pub fn step(&mut self) {
+        self.cells = self.cells.next(&self.offsets, &self.rule);
+    }
+
+    /// Returns the automaton the specified number of generations later.
+    pub fn advance(mut self, generations: usize) -> Automaton<N, C> {
+        for _ in 0..generations {
+            self.step();
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted<const N: usize>(live: impl Iterator<Item = Point<N>>) -> Vec<Point<N>> {
+        let mut live: Vec<_> = live.collect();
+        live.sort_unstable();
+        live
+    }
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(Neighborhood::Moore.offsets::<2>().len(), 8);
+        assert_eq!(Neighborhood::Moore.offsets::<4>().len(), 80);
+        assert_eq!(
+            sorted(Neighborhood::VonNeumann.offsets::<2>().into_iter()),
+            [[-1, 0], [0, -1], [0, 1], [1, 0]]
+        );
+        assert_eq!(Neighborhood::VonNeumann.offsets::<3>().len(), 6);
+        assert_eq!(Neighborhood::Hex.offsets::<2>().len(), 6);
+    }
+
+    #[test]
+    fn test_rule() {
+        let rule: Rule = "B3/S23".parse().unwrap();
+        assert_eq!(rule, Rule::life());
+        assert!(rule.next(false, 3) && !rule.next(false, 2));
+        assert!(rule.next(true, 2) && !rule.next(true, 4));
+        assert!("B0/S".parse::<Rule>().is_err());
+        assert!("B3S23".parse::<Rule>().is_err());
+    }
+
+    fn glider<C: Cells<2>>() -> Automaton<2, C> {
+        let live = [[1, 0], [2, 1], [0, 2], [1, 2], [2, 2]];
+        Automaton::new(live, Neighborhood::Moore, Rule::life())
+    }
+
+    #[test]
+    fn test_glider() {
+        // A glider moves one cell diagonally every four generations.
+        let sparse: Automaton<2, Sparse<2>> = glider().advance(4);
+        let dense: Automaton<2, Dense<2>> = glider().advance(4);
+        let want = [[1, 3], [2, 1], [2, 3], [3, 2], [3, 3]];
+        assert_eq!(sorted(sparse.live()), want);
+        assert_eq!(sorted(dense.live()), want);
+        assert_eq!(dense.bounds(), Some(([1, 1], [3, 3])));
+    }
+
+    #[test]
+    fn test_lonely_survivor() {
+        let rule = Rule::new([], [0]);
+        let sparse: Automaton<3> = Automaton::new([[0, 0, 0]], Neighborhood::VonNeumann, rule);
+        assert_eq!(sparse.advance(2).population(), 1);
+    }
+}
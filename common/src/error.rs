@@ -0,0 +1,353 @@
+use std::convert::Infallible;
+use std::error::Error as StdError;
+use std::fmt::{self, Display, Formatter};
+use std::io;
+use std::num::{ParseFloatError, ParseIntError, TryFromIntError};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::str::Utf8Error;
+use std::sync::Arc;
+
+/// The broad category of an [`Error`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorKind {
+    /// The puzzle input is malformed.
+    Parse,
+    /// The puzzle input is empty.
+    EmptyInput,
+    /// The puzzle input is well formed, but has no answer.
+    NoSolution,
+    /// The puzzle input could not be read.
+    Io,
+    /// Anything else.
+    Other,
+}
+
+/// The error type shared by every year's solutions.
+///
+/// Besides a kind and a message, an error may record where in the input it
+/// was detected, and the lower level error that caused it.  Errors display as
+/// `path:line:column: message: cause`, omitting whatever parts are unknown,
+/// and with one `: cause` per link in the cause chain.  Given the input text,
+/// an error can also show the offending line, with carets under the span of
+/// bytes at fault; see [`Error::snippet`].  Clones share their cause.
+#[derive(Clone, Debug)]
+pub struct Error {
+    kind: ErrorKind,
+    message: String,
+    path: Option<PathBuf>,
+    line: Option<usize>,
+    column: Option<usize>,
+    span: Option<Box<Range<usize>>>, // boxed to keep results small
+    source: Option<Arc<dyn StdError + Send + Sync>>,
+}
+
+/// A `Result` whose error type defaults to [`Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    pub fn new<S: ToString>(kind: ErrorKind, message: S) -> Error {
+        Error {
+            kind,
+            message: message.to_string(),
+            path: None,
+            line: None,
+            column: None,
+            span: None,
+            source: None,
+        }
+    }
+
+    /// Returns an error reporting malformed input.
+    pub fn parse<S: ToString>(message: S) -> Error {
+        Error::new(ErrorKind::Parse, message)
+    }
+
+    /// Returns an error reporting that the input is empty.
+    pub fn empty_input() -> Error {
+        Error::new(ErrorKind::EmptyInput, "empty input")
+    }
+
+    /// Returns an error reporting that the input has no answer.
+    pub fn no_solution() -> Error {
+        Error::new(ErrorKind::NoSolution, "no solution")
+    }
+
+    fn wrap<E: StdError + Send + Sync + 'static>(kind: ErrorKind, source: E) -> Error {
+        Error::new(kind, "").because(source)
+    }
+
+    /// Records the file in which this error was detected.
+    pub fn in_file<P: AsRef<Path>>(mut self, path: P) -> Error {
+        self.path = Some(path.as_ref().to_owned());
+        self
+    }
+
+    /// Records the 1-based line number at which this error was detected.
+    pub fn at_line(mut self, line: usize) -> Error {
+        self.line = Some(line);
+        self
+    }
+
+    /// Records the 1-based line and column numbers at which this error was
+    /// detected.
+    pub fn at(mut self, line: usize, column: usize) -> Error {
+        self.line = Some(line);
+        self.column = Some(column);
+        self
+    }
+
+    /// Records the range of bytes of `text` at which this error was detected,
+    /// along with the line and column at which the range starts.  A range
+    /// that doesn't start at a character boundary of `text` is ignored, so
+    /// the error shows no snippet.
+    pub fn spanning(mut self, text: &str, span: Range<usize>) -> Error {
+        let Some(before) = text.get(..span.start) else {
+            return self;
+        };
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+        self.line = Some(before.matches('\n').count() + 1);
+        self.column = Some(before[line_start..].chars().count() + 1);
+        self.span = Some(Box::new(span));
+        self
+    }
+
+    /// Records the lower level error that caused this one.
+    pub fn because<E: StdError + Send + Sync + 'static>(mut self, source: E) -> Error {
+        self.source = Some(Arc::new(source));
+        self
+    }
+
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn column(&self) -> Option<usize> {
+        self.column
+    }
+
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.as_deref().cloned()
+    }
+
+    /// Returns the line of `text` at which this error was detected, with
+    /// carets under the offending bytes, like so:
+    ///
+    /// ```text
+    ///   |
+    /// 2 | 3,4;5
+    ///   |    ^
+    /// ```
+    ///
+    /// The carets underline the error's span, or else its column.  Returns
+    /// None if the error's line is unknown or not in the text.
+    pub fn snippet(&self, text: &str) -> Option<String> {
+        let number = self.line?;
+        let line = text.lines().nth(number.checked_sub(1)?)?;
+        let line_start = line.as_ptr() as usize - text.as_ptr() as usize;
+        let (start, end) = match (self.span.as_deref(), self.column) {
+            (Some(span), _) => {
+                let start = span.start.saturating_sub(line_start).min(line.len());
+                let end = span.end.saturating_sub(line_start).clamp(start, line.len());
+                let width = line.get(start..end).map_or(0, |s| s.chars().count());
+                let start = line.get(..start).map_or(0, |s| s.chars().count());
+                (start, start + width.max(1))
+            }
+            (None, Some(column)) => (column - 1, column),
+            (None, None) => (0, 0),
+        };
+        let gutter = " ".repeat(number.to_string().len());
+        let mut snippet = format!("{gutter} |\n{number} | {line}\n");
+        if end > 0 {
+            let carets = "^".repeat(end - start);
+            snippet += &format!("{gutter} | {}{carets}\n", " ".repeat(start));
+        }
+        Some(snippet)
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}:", path.display())?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "{}:", line)?;
+        }
+        if let Some(column) = self.column {
+            write!(f, "{}:", column)?;
+        }
+        let located = self.path.is_some() || self.line.is_some();
+        let mut sep = if located { " " } else { "" };
+        if !self.message.is_empty() {
+            write!(f, "{}{}", sep, self.message)?;
+            sep = ": ";
+        }
+        let mut cause = StdError::source(self);
+        while let Some(err) = cause {
+            write!(f, "{}{}", sep, err)?;
+            if err.is::<Error>() {
+                // It has already displayed the rest of the chain.
+                break;
+            }
+            sep = ": ";
+            cause = err.source();
+        }
+        Ok(())
+    }
+}
+
+/// Errors are equal if they have the same kind, message, and location, and
+/// their causes display the same way.  Spans are ignored, since the line and
+/// column already locate an error.
+impl PartialEq for Error {
+    fn eq(&self, other: &Error) -> bool {
+        let cause = |err: &Error| err.source.as_ref().map(ToString::to_string);
+        self.kind == other.kind
+            && self.message == other.message
+            && self.path == other.path
+            && self.line == other.line
+            && self.column == other.column
+            && cause(self) == cause(other)
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        let source = self.source.as_deref()?;
+        Some(source)
+    }
+}
+
+impl From<ParseIntError> for Error {
+    fn from(err: ParseIntError) -> Self {
+        Error::wrap(ErrorKind::Parse, err)
+    }
+}
+
+impl From<ParseFloatError> for Error {
+    fn from(err: ParseFloatError) -> Self {
+        Error::wrap(ErrorKind::Parse, err)
+    }
+}
+
+impl From<TryFromIntError> for Error {
+    fn from(err: TryFromIntError) -> Self {
+        Error::wrap(ErrorKind::Parse, err)
+    }
+}
+
+impl From<Utf8Error> for Error {
+    fn from(err: Utf8Error) -> Self {
+        Error::wrap(ErrorKind::Parse, err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::wrap(ErrorKind::Io, err)
+    }
+}
+
+impl From<Infallible> for Error {
+    fn from(err: Infallible) -> Self {
+        match err {}
+    }
+}
+
+impl From<&str> for Error {
+    fn from(message: &str) -> Self {
+        Error::new(ErrorKind::Other, message)
+    }
+}
+
+impl From<String> for Error {
+    fn from(message: String) -> Self {
+        Error::new(ErrorKind::Other, message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_message() {
+        assert_eq!(Error::parse("bad tile").to_string(), "bad tile");
+    }
+
+    #[test]
+    fn test_display_location() {
+        let err = Error::parse("bad tile").in_file("input").at(3, 7);
+        assert_eq!(err.to_string(), "input:3:7: bad tile");
+        let err = Error::parse("bad tile").at_line(3);
+        assert_eq!(err.to_string(), "3: bad tile");
+    }
+
+    #[test]
+    fn test_display_causes() {
+        let cause = "x".parse::<u32>().unwrap_err();
+        let err = Error::from(cause);
+        assert_eq!(err.kind(), ErrorKind::Parse);
+        assert_eq!(err.to_string(), "invalid digit found in string");
+        let err = Error::parse("bad card").at_line(2).because(err);
+        assert_eq!(
+            err.to_string(),
+            "2: bad card: invalid digit found in string"
+        );
+        assert!(err.source().is_some());
+        assert_eq!(err.clone(), err);
+    }
+
+    #[test]
+    fn test_snippet() {
+        let text = "1,2\n3,4;5\n";
+        let err = Error::parse("expected \",\"").spanning(text, 7..8);
+        assert_eq!(err.to_string(), "2:4: expected \",\"");
+        assert_eq!(err.snippet(text).unwrap(), "  |\n2 | 3,4;5\n  |    ^\n");
+        let err = Error::parse("bad row").spanning(text, 4..9);
+        assert_eq!(err.snippet(text).unwrap(), "  |\n2 | 3,4;5\n  | ^^^^^\n");
+        let err = Error::parse("bad row").at_line(1);
+        assert_eq!(err.snippet(text).unwrap(), "  |\n1 | 1,2\n");
+        assert_eq!(Error::parse("bad row").snippet(text), None);
+        assert_eq!(Error::parse("bad row").at_line(9).snippet(text), None);
+    }
+
+    #[test]
+    fn test_spanning_outside_text() {
+        let text = "caf\u{e9}\n";
+        for span in [4..5, 9..10] {
+            let err = Error::parse("bad byte").spanning(text, span);
+            assert_eq!(err, Error::parse("bad byte"));
+            assert_eq!(err.snippet(text), None);
+        }
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Error::parse("bad tile"), Error::parse("bad tile"));
+        assert_ne!(
+            Error::parse("bad tile"),
+            Error::parse("bad tile").at_line(1)
+        );
+        assert_ne!(Error::parse("no solution"), Error::no_solution());
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Error>();
+    }
+}
@@ -0,0 +1,323 @@
+//! Fuzzing: feeding mutated inputs to a parser to find any that make it
+//! panic, rather than return an error.
+//!
+//! A [`Fuzzer`] starts from a corpus of known inputs, usually the puzzle
+//! inputs and samples in a day's `tests/dayN` directory, and repeatedly
+//! mutates them by flipping bits, deleting, duplicating, or splicing runs of
+//! bytes, and inserting characters that parsers tend to mishandle.  Each
+//! mutant is passed to a target function.  If the target panics, the fuzzer
+//! shrinks the input to a minimal one that still panics, and reports it:
+//!
+//! ```
+//! use advent_common::fuzz::Fuzzer;
+//!
+//! Fuzzer::new("parse u8").seed("42").run(|text| {
+//!     let _ = text.trim().parse::<u8>();
+//! });
+//! ```
+//!
+//! Most targets are parsers, which [`parser`] fuzzes with inputs mutated from
+//! the files in a directory:
+//!
+//! ```no_run
+//! advent_common::fuzz::parser("tests/day1", &[], |text| text.parse::<u32>());
+//! ```
+//!
+//! The fuzzers run as ordinary tests, making a few hundred runs each.  Set
+//! `ADVENT_FUZZ_RUNS` to make more, and `ADVENT_FUZZ_SEED` to try different
+//! mutations:
+//!
+//! ```text
+//! ADVENT_FUZZ_RUNS=100000 cargo test --release fuzz
+//! ```
+
+use crate::prop::Rng;
+use std::any::{type_name, Any};
+use std::cell::Cell;
+use std::env;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::Once;
+
+/// The environment variable setting the number of runs per target.
+pub const RUNS_VAR: &str = "ADVENT_FUZZ_RUNS";
+
+/// The environment variable setting the seed of the mutations.
+pub const SEED_VAR: &str = "ADVENT_FUZZ_SEED";
+
+/// Bytes worth trying in place of others: delimiters and digits common in
+/// puzzle inputs, and bytes that begin multibyte characters.
+const INTERESTING: &[u8] = b"\0\t\n -+,:;=#.()[]0189XL\x7f\xc3\xe2\xf0\xff";
+
+/// Characters that trip up code slicing strings at byte offsets.
+const MULTIBYTE: [&str; 3] = ["\u{e9}", "\u{20ac}", "\u{1f384}"];
+
+/// The most runs of the target to spend shrinking a panicking input.
+const MAX_SHRINKS: usize = 10_000;
+
+/// Feeds mutated inputs to a target, looking for panics.
+#[derive(Clone, Debug)]
+pub struct Fuzzer {
+    name: String,
+    corpus: Vec<Vec<u8>>,
+    runs: usize,
+    seed: u64,
+}
+
+impl Fuzzer {
+    /// Returns a fuzzer whose corpus holds only the empty input.  The name
+    /// identifies the target in reports.
+    pub fn new(name: impl Into<String>) -> Fuzzer {
+        let var = |name, default| {
+            env::var(name)
+                .ok()
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(default)
+        };
+        Fuzzer {
+            name: name.into(),
+            corpus: vec![Vec::new()],
+            runs: var(RUNS_VAR, 500) as usize,
+            seed: var(SEED_VAR, 0xF0_2220),
+        }
+    }
+
+    /// Adds an input to the corpus.
+    pub fn seed(mut self, input: impl Into<Vec<u8>>) -> Fuzzer {
+        self.corpus.push(input.into());
+        self
+    }
+
+    /// Adds every file in `dir`, such as a day's inputs and samples, to the
+    /// corpus.
+    pub fn seed_dir(mut self, dir: impl AsRef<Path>) -> io::Result<Fuzzer> {
+        let mut paths: Vec<_> = fs::read_dir(dir)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<_>>()?;
+        paths.sort();
+        for path in paths.into_iter().filter(|path| path.is_file()) {
+            self.corpus.push(fs::read(path)?);
+        }
+        Ok(self)
+    }
+
+    /// Sets the number of mutated inputs to try.
+    pub fn runs(mut self, runs: usize) -> Fuzzer {
+        self.runs = runs;
+        self
+    }
+
+    /// Returns the first input found that makes `target` panic, shrunk to a
+    /// minimal one, along with the panic message.  Inputs that are not valid
+    /// UTF-8 are passed to the target with invalid bytes replaced.
+    pub fn find_panic(&self, target: impl Fn(&str)) -> Option<(String, String)> {
+        let mut rng = Rng::new(self.seed);
+        let corpus = self.corpus.iter().cloned();
+        let mutants = (0..self.runs).map(|_| self.mutant(&mut rng));
+        let (input, message) = corpus
+            .chain(mutants)
+            .map(|bytes| String::from_utf8_lossy(&bytes).into_owned())
+            .find_map(|input| try_target(&target, &input).map(|message| (input, message)))?;
+        Some(shrink(input, message, &target))
+    }
+
+    /// Panics, reporting a minimal input, if any mutated input makes `target`
+    /// panic.
+    #[track_caller]
+    pub fn run(&self, target: impl Fn(&str)) {
+        if let Some((input, message)) = self.find_panic(target) {
+            panic!(
+                "{}: panicked on {:?}: {} ({}={})",
+                self.name, input, message, SEED_VAR, self.seed
+            );
+        }
+    }
+
+    /// Returns a random corpus entry, mutated one or more times.
+    fn mutant(&self, rng: &mut Rng) -> Vec<u8> {
+        let mut bytes = self.pick(rng).to_vec();
+        for _ in 0..rng.in_range(1..=4) {
+            self.mutate(&mut bytes, rng);
+        }
+        bytes
+    }
+
+    fn pick(&self, rng: &mut Rng) -> &[u8] {
+        &self.corpus[rng.in_range(0..=self.corpus.len() as u64 - 1) as usize]
+    }
+
+    fn mutate(&self, bytes: &mut Vec<u8>, rng: &mut Rng) {
+        let index = |rng: &mut Rng, len: usize| rng.in_range(0..=len as u64) as usize;
+        let at = index(rng, bytes.len());
+        let end = (at + index(rng, 16)).min(bytes.len());
+        let op = rng.in_range(0..=7);
+        match op {
+            0 if at < bytes.len() => bytes[at] ^= 1 << rng.in_range(0..=7),
+            1 if at < bytes.len() => {
+                bytes[at] = INTERESTING[index(rng, INTERESTING.len() - 1)];
+            }
+            2 => {
+                bytes.drain(at..end);
+            }
+            3 => {
+                let run = bytes[at..end].to_vec();
+                bytes.splice(at..at, run);
+            }
+            4 | 5 => {
+                // Insert a multibyte character, or overwrite as many bytes
+                // with it, keeping the length of the input unchanged.
+                let c = MULTIBYTE[index(rng, MULTIBYTE.len() - 1)];
+                let end = if op == 4 {
+                    at
+                } else {
+                    (at + c.len()).min(bytes.len())
+                };
+                bytes.splice(at..end, c.bytes());
+            }
+            6 => bytes.truncate(at),
+            _ => {
+                let other = self.pick(rng);
+                let from = index(rng, other.len());
+                bytes.truncate(at);
+                bytes.extend(&other[from..]);
+            }
+        }
+    }
+}
+
+/// Fuzzes a parser with inputs mutated from the files in `dir`, and from
+/// `seeds`.  Whatever `parse` returns, such as an error for a malformed input,
+/// is ignored; it just mustn't panic.  Reports name the parser by the type of
+/// `parse`, and so by its path if it's a function rather than a closure.
+#[track_caller]
+pub fn parser<T, F: Fn(&str) -> T>(dir: impl AsRef<Path>, seeds: &[&str], parse: F) {
+    let dir = dir.as_ref();
+    let mut fuzzer = Fuzzer::new(type_name::<F>())
+        .seed_dir(dir)
+        .unwrap_or_else(|err| panic!("{}: {}", dir.display(), err));
+    for &seed in seeds {
+        fuzzer = fuzzer.seed(seed);
+    }
+    fuzzer.run(|text| {
+        let _ = parse(text);
+    });
+}
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `target`, returning its panic message if it panics.
+fn try_target(target: &impl Fn(&str), input: &str) -> Option<String> {
+    // Silence the default report of each panic, which would otherwise be
+    // printed for every input tried while shrinking.
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let report = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.with(Cell::get) {
+                report(info);
+            }
+        }));
+    });
+    QUIET.with(|quiet| quiet.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(|| target(input)));
+    QUIET.with(|quiet| quiet.set(false));
+    result.err().map(|payload| panic_message(payload.as_ref()))
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "non-string panic".to_owned()
+    }
+}
+
+/// Removes ever smaller runs of lines, and then of characters, from a
+/// panicking input, for as long as it still panics.
+fn shrink(mut input: String, mut message: String, target: &impl Fn(&str)) -> (String, String) {
+    let mut tries = 0;
+    for split in [split_lines, split_chars] {
+        let mut len = split(&input).len() / 2;
+        while len > 0 && tries < MAX_SHRINKS {
+            let mut start = 0;
+            while start < split(&input).len() && tries < MAX_SHRINKS {
+                tries += 1;
+                let pieces = split(&input);
+                let shorter = [&pieces[..start], &pieces[(start + len).min(pieces.len())..]]
+                    .concat()
+                    .concat();
+                match try_target(target, &shorter) {
+                    Some(panic) => (input, message) = (shorter, panic),
+                    None => start += len,
+                }
+            }
+            len /= 2;
+        }
+    }
+    (input, message)
+}
+
+fn split_lines(s: &str) -> Vec<&str> {
+    s.split_inclusive('\n').collect()
+}
+
+fn split_chars(s: &str) -> Vec<&str> {
+    s.char_indices()
+        .map(|(i, c)| &s[i..i + c.len_utf8()])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the digit after the first colon, panicking if there is no
+    /// colon or if a multibyte character follows it.
+    fn fragile(text: &str) -> Option<u32> {
+        let colon = text.find(':')?;
+        text[colon + 1..colon + 2].parse().ok()
+    }
+
+    #[test]
+    fn test_finds_minimal_panic() {
+        let fuzzer = Fuzzer::new("fragile").seed("id: 7\nid: 8\n").runs(2000);
+        let (input, message) = fuzzer
+            .find_panic(|text| {
+                let _ = fragile(text);
+            })
+            .unwrap();
+        assert_eq!(input, ":");
+        assert!(message.contains("out of bounds"), "{message}");
+    }
+
+    #[test]
+    fn test_passes() {
+        Fuzzer::new("checked").seed("id: 7").run(|text| {
+            let _ = text.find(':').map(|colon| text.get(colon + 1..colon + 2));
+        });
+    }
+
+    #[test]
+    fn test_parser() {
+        parser("src", &["12", "-3"], |text| text.trim().parse::<i32>());
+    }
+
+    #[test]
+    #[should_panic(expected = "fuzz::tests::fragile: panicked on \":\"")]
+    fn test_parser_panics() {
+        parser("src", &["id: 7", "x:"], fragile);
+    }
+
+    #[test]
+    fn test_seed_dir() {
+        let fuzzer = Fuzzer::new("sources").seed_dir("src").unwrap();
+        assert!(fuzzer.corpus.len() > 5);
+        assert!(Fuzzer::new("missing").seed_dir("no/such/dir").is_err());
+    }
+}
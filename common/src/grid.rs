@@ -0,0 +1,512 @@
+//! A dense, rectangular two-dimensional grid, as found in many puzzles.
+
+use crate::Error;
+use std::fmt::{self, Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A row and column index into a [`Grid`].  Rows grow downward (south), and
+/// columns rightward (east).
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Position {
+    pub row: usize,
+    pub column: usize,
+}
+
+impl Position {
+    pub const fn new(row: usize, column: usize) -> Position {
+        Position { row, column }
+    }
+
+    /// Returns the position `offset` away from this one, or None if either
+    /// index would be negative.
+    pub fn offset(self, offset: Offset) -> Option<Position> {
+        Some(Position {
+            row: add_signed(self.row, offset.rows)?,
+            column: add_signed(self.column, offset.columns)?,
+        })
+    }
+
+    /// Returns the adjacent position in the specified direction, or None if
+    /// either index would be negative.
+    pub fn step(self, dir: Direction) -> Option<Position> {
+        self.offset(dir.into())
+    }
+
+    /// Returns the direction from this position to `other`, if `other` is
+    /// orthogonally adjacent to it.
+    pub fn direction_to(self, other: Position) -> Option<Direction> {
+        Direction::ALL
+            .into_iter()
+            .find(|&dir| self.step(dir) == Some(other))
+    }
+}
+
+// Like usize::checked_add_signed, which is too new for our minimum Rust.
+fn add_signed(index: usize, delta: isize) -> Option<usize> {
+    if delta < 0 {
+        index.checked_sub(delta.unsigned_abs())
+    } else {
+        index.checked_add(delta as usize)
+    }
+}
+
+/// A displacement between positions, in rows and columns.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Offset {
+    pub rows: isize,
+    pub columns: isize,
+}
+
+impl Offset {
+    /// The offsets to the four orthogonally adjacent positions, clockwise
+    /// from north.
+    pub const ORTHOGONAL: [Offset; 4] = [
+        Offset::new(-1, 0),
+        Offset::new(0, 1),
+        Offset::new(1, 0),
+        Offset::new(0, -1),
+    ];
+
+    /// The offsets to all eight adjacent positions, including diagonals,
+    /// clockwise from north.
+    pub const ADJACENT: [Offset; 8] = [
+        Offset::new(-1, 0),
+        Offset::new(-1, 1),
+        Offset::new(0, 1),
+        Offset::new(1, 1),
+        Offset::new(1, 0),
+        Offset::new(1, -1),
+        Offset::new(0, -1),
+        Offset::new(-1, -1),
+    ];
+
+    pub const fn new(rows: isize, columns: isize) -> Offset {
+        Offset { rows, columns }
+    }
+}
+
+/// One of the four cardinal directions.  Directions are ordered clockwise
+/// from north.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn reverse(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+}
+
+impl From<Direction> for Offset {
+    fn from(dir: Direction) -> Offset {
+        Offset::ORTHOGONAL[dir as usize]
+    }
+}
+
+/// A rectangular grid of values, stored in row-major order.
+///
+/// Grids are indexed by [`Position`]; indexing out of bounds panics, whereas
+/// [`get`](Grid::get) and the neighbor and ray iterators check bounds.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Returns a grid of the specified size, with every cell set to `value`.
+    pub fn new(height: usize, width: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            height,
+            width,
+            cells: vec![value; height * width],
+        }
+    }
+
+    /// Returns a grid of the specified size, computing each cell's value from
+    /// its position.
+    pub fn from_fn(height: usize, width: usize, mut f: impl FnMut(Position) -> T) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |column| Position { row, column }))
+            .map(&mut f)
+            .collect();
+        Grid {
+            height,
+            width,
+            cells,
+        }
+    }
+
+    fn empty() -> Grid<T> {
+        Grid {
+            height: 0,
+            width: 0,
+            cells: Vec::new(),
+        }
+    }
+
+    /// Counts a row just appended to the cells, checking that it's as long
+    /// as the rows before it.
+    fn end_row(&mut self) -> Result<(), Error> {
+        if self.height == 0 {
+            self.width = self.cells.len();
+        } else if self.cells.len() != (self.height + 1) * self.width {
+            return Err(Error::parse("jagged rows").at_line(self.height + 1));
+        }
+        self.height += 1;
+        Ok(())
+    }
+
+    /// Returns a grid having the specified rows, which must all have the same
+    /// length.
+    pub fn from_rows<I, R>(rows: I) -> Result<Grid<T>, Error>
+    where
+        I: IntoIterator<Item = R>,
+        R: IntoIterator<Item = T>,
+    {
+        let mut grid = Grid::empty();
+        for row in rows {
+            grid.cells.extend(row);
+            grid.end_row()?;
+        }
+        Ok(grid)
+    }
+
+    /// Parses one cell from each byte of each line of `text`, which may end
+    /// its lines with `\r\n`.  Errors from `f` span the offending byte, and
+    /// errors about jagged rows span the offending line.
+    pub fn parse(text: &str, mut f: impl FnMut(u8) -> Result<T, Error>) -> Result<Grid<T>, Error> {
+        let mut grid = Grid::empty();
+        for line in text.lines() {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let start = line.as_ptr() as usize - text.as_ptr() as usize;
+            for (index, byte) in line.bytes().enumerate() {
+                let span = start + index..start + index + 1;
+                grid.cells
+                    .push(f(byte).map_err(|err| err.spanning(text, span))?);
+            }
+            grid.end_row()
+                .map_err(|err| err.spanning(text, start..start + line.len()))?;
+        }
+        Ok(grid)
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Position) -> bool {
+        pos.row < self.height && pos.column < self.width
+    }
+
+    pub fn get(&self, pos: Position) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.width + pos.column])
+    }
+
+    pub fn get_mut(&mut self, pos: Position) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.row * self.width + pos.column])
+    }
+
+    /// Returns the position `offset` away from `pos`, if it's in the grid.
+    pub fn offset(&self, pos: Position, offset: Offset) -> Option<Position> {
+        pos.offset(offset).filter(|&pos| self.contains(pos))
+    }
+
+    /// Returns the adjacent position in the specified direction, if it's in
+    /// the grid.
+    pub fn step(&self, pos: Position, dir: Direction) -> Option<Position> {
+        self.offset(pos, dir.into())
+    }
+
+    /// Returns the in-bounds positions at each of the specified offsets.
+    fn neighbors<'a>(
+        &'a self,
+        pos: Position,
+        offsets: &'a [Offset],
+    ) -> impl Iterator<Item = Position> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    /// Returns the orthogonally adjacent positions within the grid, clockwise
+    /// from north.
+    pub fn neighbors4(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbors(pos, &Offset::ORTHOGONAL)
+    }
+
+    /// Returns the orthogonally and diagonally adjacent positions within the
+    /// grid, clockwise from north.
+    pub fn neighbors8(&self, pos: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbors(pos, &Offset::ADJACENT)
+    }
+
+    /// Returns the positions reached by repeatedly moving `offset` from `pos`
+    /// (exclusive) until leaving the grid.
+    pub fn ray(&self, pos: Position, offset: Offset) -> impl Iterator<Item = Position> + '_ {
+        assert_ne!(offset, Offset::new(0, 0), "ray would never end");
+        let mut pos = Some(pos);
+        std::iter::from_fn(move || {
+            pos = self.offset(pos?, offset);
+            pos
+        })
+    }
+
+    /// Returns every position in the grid, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |column| Position { row, column }))
+    }
+
+    /// Returns every cell in the grid, in row-major order.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Returns every position in the grid with its cell, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Returns the position of the first cell (in row-major order) that
+    /// satisfies `pred`.
+    pub fn find(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find_map(|(pos, value)| pred(value).then_some(pos))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..][..self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, column: usize) -> impl Iterator<Item = &T> {
+        assert!(column < self.width, "column out of bounds");
+        self.cells.iter().skip(column).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|column| self.column(column))
+    }
+
+    /// Returns a grid of the same size, whose cells are computed from the
+    /// cells of this one.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            height: self.height,
+            width: self.width,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Returns this grid reflected across its main diagonal, so that rows
+    /// become columns.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.width, self.height, |Position { row, column }| {
+            self[Position::new(column, row)].clone()
+        })
+    }
+
+    /// Returns this grid rotated a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        Grid::from_fn(self.width, height, |Position { row, column }| {
+            self[Position::new(height - 1 - column, row)].clone()
+        })
+    }
+
+    /// Returns this grid rotated a quarter turn counterclockwise.
+    pub fn rotate_left(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        Grid::from_fn(width, self.height, |Position { row, column }| {
+            self[Position::new(column, width - 1 - row)].clone()
+        })
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos).expect("position out of bounds")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        self.get_mut(pos).expect("position out of bounds")
+    }
+}
+
+/// Displays each row on its own line, with no separators between cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                value.fmt(f)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ErrorKind;
+
+    fn digits(text: &str) -> Grid<u32> {
+        Grid::parse(text, |b| {
+            (b as char)
+                .to_digit(10)
+                .ok_or_else(|| Error::parse("bad digit"))
+        })
+        .unwrap()
+    }
+
+    fn positions(iter: impl Iterator<Item = Position>) -> Vec<(usize, usize)> {
+        iter.map(|pos| (pos.row, pos.column)).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("123\n456\n");
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid[Position::new(1, 0)], 4);
+        assert_eq!(grid.get(Position::new(2, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert!(Grid::parse("", Ok).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_crlf() {
+        let grid = digits("123\r\n456\r\n");
+        assert_eq!((grid.height(), grid.width()), (2, 3));
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert_eq!(digits("12\r\n34\r"), digits("12\n34\n"));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = Grid::parse("12\n3", Ok).unwrap_err();
+        assert_eq!(err, Error::parse("jagged rows").at(2, 1));
+        assert_eq!(err.span(), Some(3..4));
+        let err = Grid::<u8>::parse("12\n3x", |_| Err(Error::parse("bad digit"))).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::Parse);
+        assert_eq!((err.line(), err.column()), (Some(1), Some(1)));
+        let err = Grid::parse("12\n3x", |b| match b {
+            b'x' => Err(Error::parse("bad digit")),
+            _ => Ok(b),
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "2:2: bad digit");
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 3, ());
+        let corner = Position::new(0, 0);
+        let center = Position::new(1, 1);
+        assert_eq!(positions(grid.neighbors4(corner)), [(0, 1), (1, 0)]);
+        assert_eq!(positions(grid.neighbors8(corner)), [(0, 1), (1, 1), (1, 0)]);
+        assert_eq!(grid.neighbors4(center).count(), 4);
+        assert_eq!(grid.neighbors8(center).count(), 8);
+    }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::new(3, 4, ());
+        let pos = Position::new(2, 1);
+        assert_eq!(
+            positions(grid.ray(pos, Offset::new(-1, 1))),
+            [(1, 2), (0, 3)]
+        );
+        assert_eq!(positions(grid.ray(pos, Direction::West.into())), [(2, 0)]);
+        assert_eq!(grid.ray(pos, Direction::South.into()).count(), 0);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("123\n456");
+        let rows: Vec<&[u32]> = grid.rows().collect();
+        assert_eq!(rows, [[1, 2, 3], [4, 5, 6]]);
+        let columns: Vec<Vec<u32>> = grid.columns().map(|c| c.cloned().collect()).collect();
+        assert_eq!(columns, [[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn test_transform() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.transpose().to_string(), "14\n25\n36\n");
+        assert_eq!(grid.rotate_right().to_string(), "41\n52\n63\n");
+        assert_eq!(grid.rotate_left().to_string(), "36\n25\n14\n");
+        assert_eq!(grid.rotate_left().rotate_right(), grid);
+        assert_eq!(grid.map(|d| d * 2).to_string(), "246\n81012\n");
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.find(|&d| d > 4), Some(Position::new(1, 1)));
+        assert_eq!(grid.find(|&d| d > 6), None);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.reverse(), Direction::West);
+        let pos = Position::new(1, 1);
+        assert_eq!(pos.step(Direction::North), Some(Position::new(0, 1)));
+        assert_eq!(Position::new(0, 0).step(Direction::West), None);
+        assert_eq!(pos.direction_to(Position::new(1, 2)), Some(Direction::East));
+        assert_eq!(pos.direction_to(Position::new(2, 2)), None);
+    }
+}
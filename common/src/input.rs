@@ -0,0 +1,65 @@
+//! Reading puzzle input named on the command line.
+//!
+//! Each solution program takes an optional argument: the path of its input
+//! file, or `-` to read standard input.  Without one, the program falls back
+//! to the input it has always used, either a file relative to its crate or
+//! text embedded in the program.  Arguments beginning with `--` are options,
+//! such as `--visualize=ascii`, and never name the input.
+
+use std::env;
+use std::fs;
+use std::io::{self, Read as _};
+
+/// The argument requesting input from standard input.
+pub const STDIN: &str = "-";
+
+/// Returns the input path given as the program's first argument other than
+/// an option, or `default` if there is none.
+pub fn path_or(default: &str) -> String {
+    path_arg(env::args().skip(1)).unwrap_or_else(|| default.to_owned())
+}
+
+fn path_arg(mut args: impl Iterator<Item = String>) -> Option<String> {
+    args.find(|arg| !arg.starts_with("--"))
+}
+
+/// Reads the input at `path`, which is read from standard input if it is
+/// [`STDIN`].
+pub fn read(path: &str) -> io::Result<String> {
+    if path == STDIN {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        Ok(text)
+    } else {
+        fs::read_to_string(path)
+    }
+}
+
+/// Reads the input named by the program's first argument other than an
+/// option, or returns `embedded`, the input compiled into the program, if
+/// there is none.
+pub fn text_or(embedded: &str) -> io::Result<String> {
+    match path_arg(env::args().skip(1)) {
+        Some(path) => read(&path),
+        None => Ok(embedded.to_owned()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_arg() {
+        let path = |args: &[&str]| path_arg(args.iter().map(|arg| arg.to_string()));
+        assert_eq!(path(&[]), None);
+        assert_eq!(path(&["-"]).unwrap(), STDIN);
+        assert_eq!(path(&["--visualize=ascii", "x"]).unwrap(), "x");
+    }
+
+    #[test]
+    fn test_read() {
+        assert!(read("Cargo.toml").unwrap().contains("advent-common"));
+        assert!(read("no/such/file").is_err());
+    }
+}
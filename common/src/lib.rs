@@ -0,0 +1,17 @@
+//! Code shared by the solutions for every year of Advent of Code.
+
+mod error;
+mod solver;
+
+pub mod automaton;
+pub mod fuzz;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod prop;
+pub mod search;
+pub mod trace;
+pub mod visualize;
+
+pub use error::{Error, ErrorKind, Result};
+pub use solver::Solver;
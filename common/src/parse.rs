@@ -0,0 +1,384 @@
+//! A small toolkit for parsing puzzle input.
+//!
+//! A [`Parser`] is a cursor over the input text.  Its methods each consume a
+//! piece of the text, such as a literal, a number, or a list of items, and
+//! report any failure as an [`Error`] spanning the unexpected text, so that
+//! the error can show the offending line of input.  Parsers for larger pieces
+//! of input are built from closures calling these methods:
+//!
+//! ```
+//! use advent_common::parse::{self, Parser};
+//!
+//! fn point(p: &mut Parser) -> advent_common::Result<(u32, u32)> {
+//!     let x = p.unsigned()?;
+//!     p.literal(",")?;
+//!     Ok((x, p.unsigned()?))
+//! }
+//!
+//! let points = parse::all("1,2\n3,4\n", |p| p.lines(point)).unwrap();
+//! assert_eq!(points, [(1, 2), (3, 4)]);
+//!
+//! let err = parse::all("1,2\n3;4\n", |p| p.lines(point)).unwrap_err();
+//! assert_eq!(err.to_string(), "2:2: expected \",\"");
+//! assert_eq!(err.snippet("1,2\n3;4\n").unwrap(), "  |\n2 | 3;4\n  |  ^\n");
+//! ```
+
+use crate::{Error, Result};
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+/// Parses all of `text` with `f`, which must consume everything but trailing
+/// whitespace.
+pub fn all<'a, T>(text: &'a str, f: impl FnOnce(&mut Parser<'a>) -> Result<T>) -> Result<T> {
+    let mut parser = Parser::new(text);
+    let value = f(&mut parser)?;
+    parser.end()?;
+    Ok(value)
+}
+
+/// A cursor over some input text.
+#[derive(Clone, Debug)]
+pub struct Parser<'a> {
+    text: &'a str, // the entire input, for locating errors
+    pos: usize,    // the byte offset of the next unparsed character
+    end: usize,    // the byte offset just past the text this parser may read
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(text: &'a str) -> Parser<'a> {
+        Parser {
+            text,
+            pos: 0,
+            end: text.len(),
+        }
+    }
+
+    /// Returns the text not yet parsed.
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.end
+    }
+
+    /// Returns the next character, without consuming it.
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Returns an error spanning the next character.
+    pub fn error<S: ToString>(&self, message: S) -> Error {
+        let len = self.peek().map_or(0, char::len_utf8);
+        Error::parse(message).spanning(self.text, self.pos..self.pos + len)
+    }
+
+    fn advance(&mut self, len: usize) -> &'a str {
+        let taken = &self.text[self.pos..self.pos + len];
+        self.pos += len;
+        taken
+    }
+
+    /// Consumes `literal` if the text continues with it, returning whether
+    /// it did.
+    pub fn try_literal(&mut self, literal: &str) -> bool {
+        let found = self.rest().starts_with(literal);
+        if found {
+            self.advance(literal.len());
+        }
+        found
+    }
+
+    /// Consumes `literal`, which the text must continue with.
+    pub fn literal(&mut self, literal: &str) -> Result<()> {
+        if self.try_literal(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected {:?}", literal)))
+        }
+    }
+
+    /// Consumes the longest prefix of characters satisfying `pred`, which may
+    /// be empty.
+    pub fn take_while(&mut self, mut pred: impl FnMut(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !pred(c)).unwrap_or(rest.len());
+        self.advance(len)
+    }
+
+    /// Consumes any spaces and tabs.
+    pub fn spaces(&mut self) {
+        self.take_while(|c| c == ' ' || c == '\t');
+    }
+
+    /// Consumes a nonempty run of characters other than whitespace.
+    pub fn word(&mut self) -> Result<&'a str> {
+        match self.take_while(|c| !c.is_whitespace()) {
+            "" => Err(self.error("expected word")),
+            word => Ok(word),
+        }
+    }
+
+    /// Consumes text up to, but not including, the next occurrence of
+    /// `delimiter`, which must occur.
+    pub fn until(&mut self, delimiter: &str) -> Result<&'a str> {
+        match self.rest().find(delimiter) {
+            Some(len) => Ok(self.advance(len)),
+            None => Err(self.error(format!("expected {:?}", delimiter))),
+        }
+    }
+
+    fn number<T>(&mut self, digits: &'a str, start: Parser<'a>) -> Result<T>
+    where
+        T: FromStr<Err = ParseIntError>,
+    {
+        if !digits.ends_with(|c: char| c.is_ascii_digit()) {
+            *self = start;
+            return Err(self.error("expected number"));
+        }
+        digits.parse().map_err(|err| {
+            *self = start;
+            let span = self.pos..self.pos + digits.len();
+            Error::parse("number out of range")
+                .spanning(self.text, span)
+                .because(err)
+        })
+    }
+
+    /// Consumes a nonempty run of decimal digits, and returns its value.
+    pub fn unsigned<T>(&mut self) -> Result<T>
+    where
+        T: FromStr<Err = ParseIntError>,
+    {
+        let start = self.clone();
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        self.number(digits, start)
+    }
+
+    /// Consumes a decimal number with an optional sign, and returns its value.
+    pub fn signed<T>(&mut self) -> Result<T>
+    where
+        T: FromStr<Err = ParseIntError>,
+    {
+        let start = self.clone();
+        let sign = if self.peek() == Some('-') || self.peek() == Some('+') {
+            1
+        } else {
+            0
+        };
+        self.advance(sign);
+        let len = sign + self.take_while(|c| c.is_ascii_digit()).len();
+        let digits = &self.text[start.pos..start.pos + len];
+        self.number(digits, start)
+    }
+
+    /// Consumes `key`, followed by `separator`, and then a value parsed by
+    /// `value`; for example, `x=` followed by a number.
+    pub fn field<T>(
+        &mut self,
+        key: &str,
+        separator: &str,
+        value: impl FnOnce(&mut Parser<'a>) -> Result<T>,
+    ) -> Result<T> {
+        self.literal(key)?;
+        self.literal(separator)?;
+        value(self)
+    }
+
+    /// Consumes one or more items parsed by `item`, separated by `separator`.
+    pub fn separated<T>(
+        &mut self,
+        separator: &str,
+        mut item: impl FnMut(&mut Parser<'a>) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = vec![item(self)?];
+        while self.try_literal(separator) {
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Consumes one or more items parsed by `item`, separated by runs of
+    /// spaces, and ignoring spaces at either end.
+    pub fn spaced<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Parser<'a>) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        self.spaces();
+        let mut items = vec![item(self)?];
+        loop {
+            self.spaces();
+            if self.is_empty() || self.at_line_end() {
+                return Ok(items);
+            }
+            items.push(item(self)?);
+        }
+    }
+
+    /// Returns whether the text continues with a line ending.
+    fn at_line_end(&self) -> bool {
+        let rest = self.rest();
+        rest.starts_with('\n') || rest.starts_with("\r\n")
+    }
+
+    /// Consumes a line ending, either `\n` or `\r\n`, or nothing at the end
+    /// of the text.
+    pub fn end_of_line(&mut self) -> Result<()> {
+        if self.is_empty() || self.try_literal("\r\n") || self.try_literal("\n") {
+            Ok(())
+        } else {
+            Err(self.error("expected end of line"))
+        }
+    }
+
+    /// Consumes every remaining line, each of which must be entirely
+    /// consumed by `item`.  Stops at a blank line, leaving it unconsumed.
+    pub fn lines<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Parser<'a>) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        while !self.is_empty() && !self.at_line_end() {
+            items.push(item(self)?);
+            self.end_of_line()?;
+        }
+        Ok(items)
+    }
+
+    /// Consumes every remaining paragraph: a run of lines ending at a blank
+    /// line or at the end of the text.  Each paragraph is parsed by `item`
+    /// from a parser limited to that paragraph, which must be consumed except
+    /// for its final newline.
+    pub fn paragraphs<T>(
+        &mut self,
+        mut item: impl FnMut(&mut Parser<'a>) -> Result<T>,
+    ) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        loop {
+            let rest = self.rest();
+            let len = ["\n\n", "\r\n\r\n"]
+                .iter()
+                .filter_map(|blank| rest.find(blank))
+                .min()
+                .unwrap_or(rest.len());
+            let mut paragraph = Parser {
+                end: self.pos + len,
+                ..self.clone()
+            };
+            items.push(item(&mut paragraph)?);
+            paragraph.end_of_line()?;
+            paragraph.end()?;
+            self.pos = paragraph.pos;
+            let blank = self.try_literal("\n\n") || self.try_literal("\r\n\r\n");
+            if !blank || self.rest().trim().is_empty() {
+                return Ok(items);
+            }
+        }
+    }
+
+    /// Consumes trailing whitespace, which must be all that remains.
+    pub fn end(&mut self) -> Result<()> {
+        self.take_while(char::is_whitespace);
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("expected end of input"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers() {
+        let mut p = Parser::new("12 -3 +4 x");
+        assert_eq!(p.unsigned::<u8>().unwrap(), 12);
+        p.spaces();
+        assert!(p.unsigned::<u8>().is_err());
+        assert_eq!(p.signed::<i8>().unwrap(), -3);
+        p.spaces();
+        assert_eq!(p.signed::<i8>().unwrap(), 4);
+        p.spaces();
+        let err = p.signed::<i8>().unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(1), Some(10)));
+        assert_eq!(p.rest(), "x");
+
+        let err = Parser::new("300").unsigned::<u8>().unwrap_err();
+        assert_eq!(err.span(), Some(0..3));
+        assert_eq!(
+            err.to_string(),
+            "1:1: number out of range: number too large to fit in target type"
+        );
+    }
+
+    #[test]
+    fn test_literals() {
+        let mut p = Parser::new("x=5, y=-2 rest");
+        assert_eq!(p.field("x", "=", Parser::signed::<i32>).unwrap(), 5);
+        p.literal(", ").unwrap();
+        assert_eq!(p.field("y", "=", Parser::signed::<i32>).unwrap(), -2);
+        assert!(p.try_literal(" "));
+        assert_eq!(p.word().unwrap(), "rest");
+        assert!(p.word().is_err());
+        let mut p = Parser::new("name: value");
+        assert_eq!(p.until(": ").unwrap(), "name");
+        assert!(p.until("?").is_err());
+    }
+
+    #[test]
+    fn test_lists() {
+        let list = all("1,2,3\n", |p| p.separated(",", Parser::unsigned::<u32>)).unwrap();
+        assert_eq!(list, [1, 2, 3]);
+        let list = all("  4  5 6\n", |p| p.spaced(Parser::unsigned::<u32>)).unwrap();
+        assert_eq!(list, [4, 5, 6]);
+        let err = all("1,2,\n", |p| p.separated(",", Parser::unsigned::<u32>)).unwrap_err();
+        assert_eq!((err.line(), err.column()), (Some(1), Some(5)));
+    }
+
+    #[test]
+    fn test_paragraphs() {
+        let text = "a\n1\n2\n\nb\n3\n";
+        let paragraphs = all(text, |p| {
+            p.paragraphs(|p| {
+                let name = p.word()?;
+                p.end_of_line()?;
+                Ok((name, p.lines(Parser::unsigned::<u32>)?))
+            })
+        })
+        .unwrap();
+        assert_eq!(paragraphs, [("a", vec![1, 2]), ("b", vec![3])]);
+
+        let err = all("1\n\n2\nx\n", |p| {
+            p.paragraphs(|p| p.lines(Parser::unsigned::<u32>))
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "4:1: expected number");
+    }
+
+    #[test]
+    fn test_crlf() {
+        let list = all("1,2\r\n3\r\n", |p| {
+            p.lines(|p| p.separated(",", Parser::unsigned::<u32>))
+        })
+        .unwrap();
+        assert_eq!(list, [vec![1, 2], vec![3]]);
+        let list = all("4 5\r\n", |p| {
+            p.lines(|p| p.spaced(Parser::unsigned::<u32>))
+        });
+        assert_eq!(list.unwrap(), [vec![4, 5]]);
+        let text = "a\r\n1\r\n\r\nb\r\n2\r\n3\r\n";
+        let paragraphs = all(text, |p| {
+            p.paragraphs(|p| {
+                let name = p.word()?;
+                p.end_of_line()?;
+                Ok((name, p.lines(Parser::unsigned::<u32>)?))
+            })
+        })
+        .unwrap();
+        assert_eq!(paragraphs, [("a", vec![1]), ("b", vec![2, 3])]);
+        let err = all("1\r2\n", |p| p.lines(Parser::unsigned::<u32>)).unwrap_err();
+        assert_eq!(err.to_string(), "1:2: expected end of line");
+    }
+}
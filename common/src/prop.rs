@@ -0,0 +1,321 @@
+//! Property-based testing: checking that a property holds for many randomly
+//! generated inputs, and shrinking any counterexample to a minimal one.
+//!
+//! A [`Strategy`] generates random values and proposes smaller variants of
+//! each.  [`check`] tests a property against generated values, and if one
+//! fails, repeatedly replaces it with a smaller variant that also fails, so
+//! that the reported counterexample is as simple as possible:
+//!
+//! ```
+//! use advent_common::prop::{self, range, vec};
+//!
+//! // Summing in either order gives the same total.
+//! prop::check(vec(range(0u32..=100), 0..=10), |xs| {
+//!     xs.iter().sum::<u32>() == xs.iter().rev().sum::<u32>()
+//! });
+//! ```
+//!
+//! Runs are deterministic, so failures are reproducible; set `Config::seed`
+//! to explore different inputs.
+
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+
+/// A small, fast pseudorandom number generator (SplitMix64).  It is not
+/// suitable for cryptography.
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in `range`, which must not be empty.
+    pub fn in_range(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (lo, hi) = range.into_inner();
+        assert!(lo <= hi, "empty range");
+        match (hi - lo).checked_add(1) {
+            Some(len) => lo + self.next_u64() % len,
+            None => self.next_u64(),
+        }
+    }
+}
+
+/// Generates random values of some type, and shrinks them.
+pub trait Strategy {
+    type Value: Clone + Debug;
+
+    /// Returns a random value.
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    /// Returns values that are simpler than `value`, simplest first.
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value>;
+}
+
+/// Generates integers in a range, shrinking toward its low end.
+#[derive(Clone, Debug)]
+pub struct Range<T>(RangeInclusive<T>);
+
+/// Returns a strategy generating integers in `range`.
+pub fn range<T>(range: RangeInclusive<T>) -> Range<T> {
+    Range(range)
+}
+
+macro_rules! impl_range {
+    ($($t:ty)*) => {$(
+        impl Strategy for Range<$t> {
+            type Value = $t;
+
+            fn generate(&self, rng: &mut Rng) -> $t {
+                rng.in_range(*self.0.start() as u64..=*self.0.end() as u64) as $t
+            }
+
+            fn shrink(&self, &value: &$t) -> Vec<$t> {
+                let lo = *self.0.start();
+                let mut smaller = Vec::new();
+                let mut step = value - lo;
+                while step > 0 {
+                    smaller.push(value - step);
+                    step /= 2;
+                }
+                smaller
+            }
+        }
+    )*};
+}
+
+impl_range!(u8 u16 u32 u64 usize);
+
+/// Generates vectors of values generated by another strategy, shrinking by
+/// removing elements and then by shrinking each element.
+#[derive(Clone, Debug)]
+pub struct Vector<S> {
+    element: S,
+    len: RangeInclusive<usize>,
+}
+
+/// Returns a strategy generating vectors having lengths in `len`.
+pub fn vec<S: Strategy>(element: S, len: RangeInclusive<usize>) -> Vector<S> {
+    Vector { element, len }
+}
+
+impl<S: Strategy> Strategy for Vector<S> {
+    type Value = Vec<S::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Vec<S::Value> {
+        let len = rng.in_range(*self.len.start() as u64..=*self.len.end() as u64);
+        (0..len).map(|_| self.element.generate(rng)).collect()
+    }
+
+    fn shrink(&self, value: &Vec<S::Value>) -> Vec<Vec<S::Value>> {
+        let mut smaller = Vec::new();
+        if value.len() > *self.len.start() {
+            let min = *self.len.start();
+            smaller.push(value[..min.max(value.len() / 2)].to_vec());
+            for i in 0..value.len() {
+                let mut shorter = value.clone();
+                shorter.remove(i);
+                smaller.push(shorter);
+            }
+        }
+        for (i, element) in value.iter().enumerate() {
+            for simpler in self.element.shrink(element) {
+                let mut variant = value.clone();
+                variant[i] = simpler;
+                smaller.push(variant);
+            }
+        }
+        smaller
+    }
+}
+
+/// Generates permutations of a list of values, shrinking toward the
+/// original order.
+#[derive(Clone, Debug)]
+pub struct Shuffle<T>(Vec<T>);
+
+/// Returns a strategy generating permutations of `items`.
+pub fn shuffle<T: Clone + Debug>(items: Vec<T>) -> Shuffle<T> {
+    Shuffle(items)
+}
+
+impl<T: Clone + Debug + PartialEq> Strategy for Shuffle<T> {
+    type Value = Vec<T>;
+
+    fn generate(&self, rng: &mut Rng) -> Vec<T> {
+        let mut items = self.0.clone();
+        for i in (1..items.len()).rev() {
+            items.swap(i, rng.in_range(0..=i as u64) as usize);
+        }
+        items
+    }
+
+    fn shrink(&self, value: &Vec<T>) -> Vec<Vec<T>> {
+        // Each variant puts one more item back where it was originally.
+        let mut smaller = Vec::new();
+        for i in (0..value.len()).filter(|&i| value[i] != self.0[i]) {
+            let j = (0..value.len())
+                .find(|&j| value[j] == self.0[i])
+                .expect("permutation of the original items");
+            let mut variant = value.clone();
+            variant.swap(i, j);
+            smaller.push(variant);
+        }
+        smaller
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($s:ident $v:ident $i:tt),*) => {
+        impl<$($s: Strategy),*> Strategy for ($($s,)*) {
+            type Value = ($($s::Value,)*);
+
+            fn generate(&self, rng: &mut Rng) -> Self::Value {
+                ($(self.$i.generate(rng),)*)
+            }
+
+            fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+                let mut smaller = Vec::new();
+                $(
+                    for $v in self.$i.shrink(&value.$i) {
+                        let mut variant = value.clone();
+                        variant.$i = $v;
+                        smaller.push(variant);
+                    }
+                )*
+                smaller
+            }
+        }
+    };
+}
+
+impl_tuple!(A a 0, B b 1);
+impl_tuple!(A a 0, B b 1, C c 2);
+
+/// Settings for a property check.
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// The number of random values to test.
+    pub cases: usize,
+    /// The seed of the random number generator.
+    pub seed: u64,
+    /// The most shrinking steps to take before reporting a counterexample.
+    pub max_shrinks: usize,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            cases: 256,
+            seed: 0x00AD_7E27,
+            max_shrinks: 10_000,
+        }
+    }
+}
+
+impl Config {
+    /// Returns a minimal counterexample to `property` among values generated
+    /// by `strategy`, or None if every case passes.
+    pub fn find_counterexample<S: Strategy>(
+        &self,
+        strategy: &S,
+        property: impl Fn(&S::Value) -> bool,
+    ) -> Option<S::Value> {
+        let mut rng = Rng::new(self.seed);
+        let failure = (0..self.cases)
+            .map(|_| strategy.generate(&mut rng))
+            .find(|value| !property(value))?;
+        Some(shrink(strategy, failure, &property, self.max_shrinks))
+    }
+
+    /// Panics with a minimal counterexample if `property` fails for any
+    /// value generated by `strategy`.
+    #[track_caller]
+    pub fn check<S: Strategy>(&self, strategy: S, property: impl Fn(&S::Value) -> bool) {
+        if let Some(value) = self.find_counterexample(&strategy, property) {
+            panic!(
+                "property failed for {:?} (seed {:#x}, {} cases)",
+                value, self.seed, self.cases
+            );
+        }
+    }
+}
+
+/// Checks `property` with the default configuration.
+#[track_caller]
+pub fn check<S: Strategy>(strategy: S, property: impl Fn(&S::Value) -> bool) {
+    Config::default().check(strategy, property)
+}
+
+fn shrink<S: Strategy>(
+    strategy: &S,
+    mut value: S::Value,
+    property: impl Fn(&S::Value) -> bool,
+    max_shrinks: usize,
+) -> S::Value {
+    for _ in 0..max_shrinks {
+        match strategy
+            .shrink(&value)
+            .into_iter()
+            .find(|simpler| !property(simpler))
+        {
+            Some(simpler) => value = simpler,
+            None => break,
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(1);
+        let strategy = range(3u8..=7);
+        for _ in 0..100 {
+            assert!((3..=7).contains(&strategy.generate(&mut rng)));
+        }
+        assert_eq!(strategy.shrink(&7), [3, 5, 6]);
+        assert_eq!(strategy.shrink(&3), []);
+        assert!(range(0..=u64::MAX).generate(&mut rng) > 0);
+    }
+
+    #[test]
+    fn test_shrinks_to_minimum() {
+        let config = Config::default();
+        let found = config.find_counterexample(&range(0u32..=1000), |&n| n < 437);
+        assert_eq!(found, Some(437));
+
+        let strategy = vec(range(0u32..=100), 0..=20);
+        let found = config.find_counterexample(&strategy, |xs| xs.iter().sum::<u32>() < 150);
+        let found = found.unwrap();
+        assert_eq!(found.iter().sum::<u32>(), 150, "{:?}", found);
+        assert!(found.len() <= 3, "{:?}", found);
+
+        let strategy = (range(0u8..=9), shuffle(vec![1, 2, 3, 4]));
+        let found = config.find_counterexample(&strategy, |(n, xs)| *n < 5 || xs[0] == 1);
+        let (n, xs) = found.unwrap();
+        let misplaced = (0..4).filter(|&i| xs[i] != i + 1).count();
+        assert_eq!((n, misplaced), (5, 2), "{:?}", xs);
+    }
+
+    #[test]
+    fn test_passes() {
+        check(shuffle((0..10).collect::<Vec<u32>>()), |xs| {
+            xs.iter().sum::<u32>() == 45
+        });
+        let config = Config::default();
+        assert_eq!(config.find_counterexample(&range(0u8..=9), |_| true), None);
+    }
+}
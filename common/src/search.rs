@@ -0,0 +1,275 @@
+//! Shortest-path searches over implicit graphs.
+//!
+//! Each search takes the nodes to start from, a function returning the
+//! successors of a node, and a predicate recognizing goal nodes.  Nodes are
+//! any hashable values, such as grid positions or tuples of puzzle state, so
+//! graphs need never be built explicitly.  Each returns the cheapest path
+//! from any start node to any goal node, or None if no goal is reachable.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A path found by a search: its nodes, from start to goal inclusive, and
+/// the total cost of its edges.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+impl<N, C> Path<N, C> {
+    /// Returns the node at the end of the path.
+    pub fn goal(&self) -> &N {
+        self.nodes.last().expect("paths are nonempty")
+    }
+}
+
+/// The nodes discovered by a search, each with the index of the node from
+/// which it was first reached.
+struct Tree<N> {
+    nodes: Vec<(N, Option<usize>)>,
+    indexes: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Tree<N> {
+    fn new() -> Tree<N> {
+        Tree {
+            nodes: Vec::new(),
+            indexes: HashMap::new(),
+        }
+    }
+
+    /// Returns the index of the node, and whether it is newly discovered.
+    fn insert(&mut self, node: N, parent: Option<usize>) -> (usize, bool) {
+        match self.indexes.entry(node) {
+            Entry::Occupied(entry) => (*entry.get(), false),
+            Entry::Vacant(entry) => {
+                let index = self.nodes.len();
+                self.nodes.push((entry.key().clone(), parent));
+                entry.insert(index);
+                (index, true)
+            }
+        }
+    }
+
+    fn path_to(&self, mut index: usize) -> Vec<N> {
+        let mut path = Vec::new();
+        loop {
+            let (node, parent) = &self.nodes[index];
+            path.push(node.clone());
+            match parent {
+                Some(parent) => index = *parent,
+                None => break,
+            }
+        }
+        path.reverse();
+        path
+    }
+}
+
+/// Breadth-first search, for graphs whose edges all cost the same.  The cost
+/// of the returned path is its number of edges.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut tree = Tree::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let (index, true) = tree.insert(start, None) {
+            queue.push_back((index, 0));
+        }
+    }
+    while let Some((index, cost)) = queue.pop_front() {
+        let node = tree.nodes[index].0.clone();
+        if is_goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: tree.path_to(index),
+            });
+        }
+        for next in successors(&node) {
+            if let (next, true) = tree.insert(next, Some(index)) {
+                queue.push_back((next, cost + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Dijkstra's algorithm, for graphs whose edges have nonnegative costs.  The
+/// successor function returns each successor along with the cost of the edge
+/// to it.  `C::default()` must be zero.
+///
+/// <https://en.wikipedia.org/wiki/Dijkstra's_algorithm>
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// A* search, which is Dijkstra's algorithm guided by a heuristic estimate of
+/// the remaining cost from each node to the nearest goal.  The result is only
+/// guaranteed to be cheapest if the heuristic never overestimates.  A node
+/// already expanded is expanded again if a cheaper path to it turns up, which
+/// can happen when the heuristic is inconsistent: when it drops by more than
+/// the cost of some edge.
+///
+/// <https://en.wikipedia.org/wiki/A*_search_algorithm>
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut tree = Tree::new();
+    let mut costs: Vec<C> = Vec::new(); // least known cost to each node
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if let (index, true) = tree.insert(start, None) {
+            costs.push(C::default());
+            let estimate = heuristic(&tree.nodes[index].0);
+            queue.push(Reverse((estimate, index)));
+        }
+    }
+    let mut done = vec![false; costs.len()];
+    while let Some(Reverse((_, index))) = queue.pop() {
+        if done[index] {
+            continue; // This entry is stale; the node was expanded since.
+        }
+        done[index] = true;
+        let node = tree.nodes[index].0.clone();
+        let cost = costs[index];
+        if is_goal(&node) {
+            return Some(Path {
+                cost,
+                nodes: tree.path_to(index),
+            });
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            let (next_index, is_new) = tree.insert(next, Some(index));
+            if is_new {
+                costs.push(next_cost);
+                done.push(false);
+            } else if next_cost < costs[next_index] {
+                costs[next_index] = next_cost;
+                tree.nodes[next_index].1 = Some(index);
+                done[next_index] = false; // Reopen it.
+            } else {
+                continue;
+            }
+            let estimate = next_cost + heuristic(&tree.nodes[next_index].0);
+            queue.push(Reverse((estimate, next_index)));
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A small weighted graph:
+    //
+    //     0 --1-- 1 --1-- 2
+    //     |               |
+    //     5               1
+    //     |               |
+    //     3 ------1------ 4
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (3, 5)],
+            1 => vec![(0, 1), (2, 1)],
+            2 => vec![(1, 1), (4, 1)],
+            3 => vec![(0, 5), (4, 1)],
+            4 => vec![(2, 1), (3, 1)],
+            _ => vec![],
+        }
+    }
+
+    fn neighbors(node: &u32) -> Vec<u32> {
+        edges(node).into_iter().map(|(next, _)| next).collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs([0], neighbors, |&n| n == 3).unwrap();
+        assert_eq!(path.nodes, [0, 3]);
+        assert_eq!(path.cost, 1);
+        assert_eq!(*path.goal(), 3);
+        let path = bfs([2, 3], neighbors, |&n| n == 0).unwrap();
+        assert_eq!(path.cost, 1);
+        assert_eq!(bfs([0], neighbors, |&n| n == 9), None);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra([0], edges, |&n| n == 3).unwrap();
+        assert_eq!(path.nodes, [0, 1, 2, 4, 3]);
+        assert_eq!(path.cost, 4);
+        let path = dijkstra([0], edges, |&n| n == 0).unwrap();
+        assert_eq!((path.cost, path.nodes), (0, vec![0]));
+        let path = dijkstra([0], edges, |&n| n == 3 || n == 2).unwrap();
+        assert_eq!(path.nodes, [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_astar() {
+        // Walk a 10x10 grid from corner to corner, with Manhattan distance as
+        // the heuristic.
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x, y + 1), (x - 1, y), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..10).contains(&x) && (0..10).contains(&y))
+                .map(|p| (p, 1))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (9 - x) + (9 - y);
+        let path = astar([(0, 0)], successors, heuristic, |&p| p == (9, 9)).unwrap();
+        assert_eq!(path.cost, 18);
+        assert_eq!(path.nodes.len(), 19);
+    }
+
+    #[test]
+    fn test_astar_inconsistent_heuristic() {
+        // The heuristic never overestimates, but it drops by 3 along the edge
+        // from 'b' to 'c', which costs 1.  So 'c' is first expanded by way of
+        // 'a', and must be expanded again when the cheaper way through 'b' is
+        // found.
+        //
+        //     s --1-- a --3-- c --3-- g
+        //     |               |
+        //     2 ----- b --1---+
+        let successors = |node: &char| match node {
+            's' => vec![('a', 1), ('b', 2)],
+            'a' => vec![('c', 3)],
+            'b' => vec![('c', 1)],
+            'c' => vec![('g', 3)],
+            _ => vec![],
+        };
+        let heuristic = |node: &char| if *node == 'b' { 3 } else { 0 };
+        let path = astar(['s'], successors, heuristic, |&n| n == 'g').unwrap();
+        assert_eq!(path.nodes, ['s', 'b', 'c', 'g']);
+        assert_eq!(path.cost, 6);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::Error;
+use std::fmt::Display;
+
+/// A solution to both parts of one day's puzzle.
+///
+/// The input text is parsed once into a typed [`Puzzle`](Solver::Puzzle),
+/// which is then passed to each part.  Puzzles may borrow from the input text,
+/// but should do the work both parts share, such as validating the input, so
+/// that timing the parse separately from each part is meaningful.
+///
+/// Implementors are typically unit structs named for the day, such as `Day1`.
+pub trait Solver {
+    /// The parsed puzzle input.
+    type Puzzle<'a>;
+
+    /// The answer to part 1.
+    type Answer1: Display;
+
+    /// The answer to part 2.
+    type Answer2: Display;
+
+    fn parse(text: &str) -> Result<Self::Puzzle<'_>, Error>;
+
+    fn part1(puzzle: &Self::Puzzle<'_>) -> Result<Self::Answer1, Error>;
+
+    fn part2(puzzle: &Self::Puzzle<'_>) -> Result<Self::Answer2, Error>;
+}
@@ -0,0 +1,236 @@
+//! Leveled diagnostic events, grouped by spans, for reporting the progress of
+//! long-running solutions.
+//!
+//! Events are emitted with the [`error!`](crate::error!),
+//! [`warn!`](crate::warn!), [`info!`](crate::info!), [`debug!`](crate::debug!),
+//! and [`trace!`](crate::trace!) macros, and are written to standard error if
+//! their level is enabled.  Each event is prefixed by the names of the spans
+//! enclosing it on its thread, such as the year, day, and part being solved:
+//!
+//! ```text
+//! DEBUG 2021 day19 part1: 3 unconnected, 5 transformed
+//! ```
+//!
+//! Only warnings and errors are enabled by default.  Programs enable more
+//! with [`init_from_args`], which honors a `--log=LEVEL` argument, or with
+//! [`set_level`].
+
+use crate::{Error, ErrorKind, Result};
+use std::cell::RefCell;
+use std::env;
+use std::fmt::{self, Arguments, Display, Formatter};
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::time::Instant;
+
+/// The importance of an event, from most to least.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+/// Parses a level name, in any case.  `off` parses as None, enabling no
+/// events at all.
+pub fn parse_level(s: &str) -> Result<Option<Level>> {
+    match s.to_ascii_lowercase().as_str() {
+        "off" => Ok(None),
+        "error" => Ok(Some(Level::Error)),
+        "warn" => Ok(Some(Level::Warn)),
+        "info" => Ok(Some(Level::Info)),
+        "debug" => Ok(Some(Level::Debug)),
+        "trace" => Ok(Some(Level::Trace)),
+        _ => Err(Error::new(
+            ErrorKind::Other,
+            format!("{s}: expected off, error, warn, info, debug, or trace"),
+        )),
+    }
+}
+
+/// The least important level enabled, or 0 if none is.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+/// Enables events at `level` and above, or none at all.
+pub fn set_level(level: Option<Level>) {
+    MAX_LEVEL.store(level.map_or(0, |level| level as u8), Ordering::Relaxed);
+}
+
+/// Returns whether events at `level` are written.
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+}
+
+/// The prefix of the command line argument selecting a level.
+pub const OPTION: &str = "--log=";
+
+/// Sets the level from the program's `--log=LEVEL` argument, if any.
+pub fn init_from_args() -> Result<()> {
+    let arg = env::args()
+        .skip(1)
+        .find_map(|arg| arg.strip_prefix(OPTION).map(str::to_owned));
+    if let Some(level) = arg {
+        set_level(parse_level(&level)?);
+    }
+    Ok(())
+}
+
+thread_local! {
+    static SPANS: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A named period of work, such as solving one part of a puzzle.  Events
+/// emitted on the same thread while the span is alive are prefixed with its
+/// name.  Closing the span, by dropping it, emits a debug event reporting how
+/// long it was open.
+#[must_use = "the span closes when dropped"]
+pub struct Span {
+    start: Instant,
+}
+
+/// Opens a span having the specified name.
+pub fn span(name: impl Display) -> Span {
+    SPANS.with(|spans| spans.borrow_mut().push(name.to_string()));
+    Span {
+        start: Instant::now(),
+    }
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        event(
+            Level::Debug,
+            format_args!("done in {:.3?}", self.start.elapsed()),
+        );
+        SPANS.with(|spans| spans.borrow_mut().pop());
+    }
+}
+
+/// Writes an event to standard error if its level is enabled.  The event
+/// macros call this function.
+pub fn event(level: Level, message: Arguments) {
+    if enabled(level) {
+        eprintln!("{}", format_event(level, message));
+    }
+}
+
+fn format_event(level: Level, message: Arguments) -> String {
+    SPANS.with(|spans| match spans.borrow().join(" ").as_str() {
+        "" => format!("{level:<5} {message}"),
+        prefix => format!("{level:<5} {prefix}: {message}"),
+    })
+}
+
+/// Counts the iterations of a long loop, emitting a debug event each time
+/// another tenth of the expected total is done.
+pub struct Progress {
+    what: &'static str,
+    done: usize,
+    total: usize,
+    step: usize, // the number of iterations between events
+}
+
+impl Progress {
+    /// Returns a counter for `total` iterations of `what`, such as "moves".
+    pub fn new(what: &'static str, total: usize) -> Progress {
+        Progress {
+            what,
+            done: 0,
+            total,
+            step: (total / 10).max(1),
+        }
+    }
+
+    /// Counts one iteration.
+    pub fn tick(&mut self) {
+        self.done += 1;
+        if self.done % self.step == 0 {
+            event(
+                Level::Debug,
+                format_args!("{} {}/{}", self.what, self.done, self.total),
+            );
+        }
+    }
+}
+
+/// Emits an event at the specified level, with a message formatted like
+/// `format!`'s.
+#[macro_export]
+macro_rules! event {
+    ($level:expr, $($arg:tt)+) => {
+        $crate::trace::event($level, format_args!($($arg)+))
+    };
+}
+
+/// Emits an error event, formatted like `format!`.
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::event!($crate::trace::Level::Error, $($arg)+) };
+}
+
+/// Emits a warning event, formatted like `format!`.
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::event!($crate::trace::Level::Warn, $($arg)+) };
+}
+
+/// Emits an informational event, formatted like `format!`.
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::event!($crate::trace::Level::Info, $($arg)+) };
+}
+
+/// Emits a debug event, formatted like `format!`.
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::event!($crate::trace::Level::Debug, $($arg)+) };
+}
+
+/// Emits a trace event, formatted like `format!`.
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::event!($crate::trace::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(parse_level("DEBUG").unwrap(), Some(Level::Debug));
+        assert_eq!(parse_level("off").unwrap(), None);
+        assert!(parse_level("loud").is_err());
+        assert!(Level::Error < Level::Trace);
+        assert_eq!(format!("{:<5}|", Level::Info), "INFO |");
+    }
+
+    #[test]
+    fn test_spans() {
+        assert_eq!(format_event(Level::Info, format_args!("hi")), "INFO  hi");
+        let outer = span("2021 day19");
+        {
+            let _inner = span(format_args!("part{}", 1));
+            let line = format_event(Level::Debug, format_args!("{} left", 3));
+            assert_eq!(line, "DEBUG 2021 day19 part1: 3 left");
+        }
+        let line = format_event(Level::Warn, format_args!("x"));
+        assert_eq!(line, "WARN  2021 day19: x");
+        drop(outer);
+        assert_eq!(format_event(Level::Trace, format_args!("y")), "TRACE y");
+    }
+}
@@ -0,0 +1,331 @@
+//! Rendering the steps of grid simulations as images or terminal animations.
+//!
+//! A [`Recorder`] draws each grid it's given as one frame, either redrawing
+//! the terminal in place or writing a numbered PPM or PNG image file.  The
+//! solution programs that support it create one from a `--visualize=TARGET`
+//! argument, where `TARGET` is `ascii`, `ppm:DIR`, or `png:DIR`.
+
+use crate::grid::Grid;
+use crate::{Error, ErrorKind, Result};
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+/// A color, as red, green, and blue intensities.
+pub type Rgb = [u8; 3];
+
+/// A grid cell that can be drawn, as a character or as a square of color.
+pub trait Draw {
+    fn glyph(&self) -> char;
+    fn color(&self) -> Rgb;
+}
+
+/// Live or lit cells are drawn as white `#`, and others as black `.`.
+impl Draw for bool {
+    fn glyph(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+
+    fn color(&self) -> Rgb {
+        if *self {
+            [255, 255, 255]
+        } else {
+            [0, 0, 0]
+        }
+    }
+}
+
+/// Missing cells, such as the gaps between panels of a frame, are drawn as
+/// gray blanks.
+impl<T: Draw> Draw for Option<T> {
+    fn glyph(&self) -> char {
+        self.as_ref().map_or(' ', Draw::glyph)
+    }
+
+    fn color(&self) -> Rgb {
+        self.as_ref().map_or([128, 128, 128], Draw::color)
+    }
+}
+
+/// Where a [`Recorder`] sends its frames.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Target {
+    /// Redraw the terminal with each frame, pausing briefly between frames.
+    Terminal,
+    /// Write each frame to a PPM file in the specified directory.
+    Ppm(PathBuf),
+    /// Write each frame to a PNG file in the specified directory.
+    Png(PathBuf),
+}
+
+impl FromStr for Target {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Target> {
+        match s.split_once(':') {
+            None if s == "ascii" => Ok(Target::Terminal),
+            Some(("ppm", dir)) if !dir.is_empty() => Ok(Target::Ppm(dir.into())),
+            Some(("png", dir)) if !dir.is_empty() => Ok(Target::Png(dir.into())),
+            _ => Err(Error::new(
+                ErrorKind::Other,
+                format!("{s}: expected ascii, ppm:DIR, or png:DIR"),
+            )),
+        }
+    }
+}
+
+/// Draws successive frames of a simulation.
+#[derive(Debug)]
+pub struct Recorder {
+    target: Target,
+    frames: usize,
+    scale: usize,    // the width and height of each cell in image pixels
+    delay: Duration, // the pause after each frame drawn on the terminal
+}
+
+impl Recorder {
+    /// The prefix of the command line argument selecting a target.
+    pub const OPTION: &'static str = "--visualize=";
+
+    pub fn new(target: Target) -> Recorder {
+        Recorder {
+            target,
+            frames: 0,
+            scale: 4,
+            delay: Duration::from_millis(100),
+        }
+    }
+
+    /// Returns a recorder for the target named by the program's
+    /// `--visualize=TARGET` argument, or None if there is no such argument.
+    pub fn from_args() -> Result<Option<Recorder>> {
+        env::args()
+            .skip(1)
+            .find_map(|arg| arg.strip_prefix(Recorder::OPTION).map(str::parse))
+            .transpose()
+            .map(|target| target.map(Recorder::new))
+    }
+
+    /// Returns the number of frames recorded so far.
+    pub fn frames(&self) -> usize {
+        self.frames
+    }
+
+    /// Draws `grid` as the next frame.
+    pub fn record<T: Draw>(&mut self, grid: &Grid<T>) -> Result<()> {
+        match &self.target {
+            Target::Terminal => {
+                let mut out = io::stdout().lock();
+                write!(out, "\x1b[H\x1b[2J")?;
+                write_text(&mut out, grid)?;
+                writeln!(out, "frame {}", self.frames)?;
+                out.flush()?;
+                thread::sleep(self.delay);
+            }
+            Target::Ppm(dir) => self.save(dir, "ppm", |w| write_ppm(w, grid, self.scale))?,
+            Target::Png(dir) => self.save(dir, "png", |w| write_png(w, grid, self.scale))?,
+        }
+        self.frames += 1;
+        Ok(())
+    }
+
+    fn save(
+        &self,
+        dir: &Path,
+        extension: &str,
+        write: impl FnOnce(&mut BufWriter<File>) -> io::Result<()>,
+    ) -> Result<()> {
+        let path = dir.join(format!("frame{:04}.{extension}", self.frames));
+        let written = fs::create_dir_all(dir)
+            .and_then(|()| File::create(&path))
+            .and_then(|file| {
+                let mut w = BufWriter::new(file);
+                write(&mut w)?;
+                w.flush()
+            });
+        written.map_err(|err| Error::from(err).in_file(path))
+    }
+}
+
+/// Writes the grid's glyphs, one line per row.
+pub fn write_text<W: Write, T: Draw>(mut w: W, grid: &Grid<T>) -> io::Result<()> {
+    for row in grid.rows() {
+        let line: String = row.iter().map(Draw::glyph).collect();
+        writeln!(w, "{line}")?;
+    }
+    Ok(())
+}
+
+/// Returns the pixels of the grid's image, row by row, with each cell drawn
+/// as a `scale` by `scale` square.
+fn pixel_rows<'a, T: Draw>(grid: &'a Grid<T>, scale: usize) -> impl Iterator<Item = Vec<u8>> + 'a {
+    grid.rows().flat_map(move |row| {
+        let pixels: Vec<u8> = row
+            .iter()
+            .flat_map(|cell| {
+                let color = cell.color();
+                (0..scale).flat_map(move |_| color)
+            })
+            .collect();
+        (0..scale).map(move |_| pixels.clone())
+    })
+}
+
+/// Writes the grid as a binary PPM image.
+///
+/// <https://netpbm.sourceforge.net/doc/ppm.html>
+pub fn write_ppm<W: Write, T: Draw>(mut w: W, grid: &Grid<T>, scale: usize) -> io::Result<()> {
+    let (width, height) = (grid.width() * scale, grid.height() * scale);
+    write!(w, "P6\n{width} {height}\n255\n")?;
+    for row in pixel_rows(grid, scale) {
+        w.write_all(&row)?;
+    }
+    Ok(())
+}
+
+/// Writes the grid as a PNG image.  The image data is stored uncompressed,
+/// which keeps the encoder simple at some cost in file size.
+///
+/// <https://www.w3.org/TR/png/>
+pub fn write_png<W: Write, T: Draw>(mut w: W, grid: &Grid<T>, scale: usize) -> io::Result<()> {
+    let too_big = |_| io::Error::new(io::ErrorKind::InvalidInput, "image too big for PNG");
+    let width = u32::try_from(grid.width() * scale).map_err(too_big)?;
+    let height = u32::try_from(grid.height() * scale).map_err(too_big)?;
+
+    // Each scanline begins with its filter type, 0 for none.
+    let mut data = Vec::new();
+    for row in pixel_rows(grid, scale) {
+        data.push(0);
+        data.extend(row);
+    }
+
+    w.write_all(b"\x89PNG\r\n\x1a\n")?;
+    let mut header = Vec::new();
+    header.extend(width.to_be_bytes());
+    header.extend(height.to_be_bytes());
+    header.extend([8, 2, 0, 0, 0]); // 8-bit RGB, no interlacing
+    write_chunk(&mut w, b"IHDR", &header)?;
+    write_chunk(&mut w, b"IDAT", &zlib_stored(&data))?;
+    write_chunk(&mut w, b"IEND", &[])
+}
+
+fn write_chunk<W: Write>(mut w: W, kind: &[u8; 4], data: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(data.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "PNG chunk too big"))?;
+    w.write_all(&len.to_be_bytes())?;
+    w.write_all(kind)?;
+    w.write_all(data)?;
+    let crc = crc32(kind.iter().chain(data));
+    w.write_all(&crc.to_be_bytes())
+}
+
+/// Returns a zlib stream holding `data` in uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = 0xFFFF;
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let len = block.len() as u16;
+        stream.push(u8::from(last));
+        stream.extend(len.to_le_bytes());
+        stream.extend((!len).to_le_bytes());
+        stream.extend(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + u32::from(byte)) % MOD;
+        b = (b + a) % MOD;
+    }
+    b << 16 | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Grid<bool> {
+        Grid::from_fn(2, 3, |pos| pos.row == pos.column)
+    }
+
+    #[test]
+    fn test_target() {
+        assert_eq!("ascii".parse::<Target>().unwrap(), Target::Terminal);
+        assert_eq!(
+            "png:out/frames".parse::<Target>().unwrap(),
+            Target::Png("out/frames".into())
+        );
+        assert!("ppm:".parse::<Target>().is_err());
+        assert!("gif:out".parse::<Target>().is_err());
+    }
+
+    #[test]
+    fn test_write_text() {
+        let mut text = Vec::new();
+        write_text(&mut text, &sample()).unwrap();
+        assert_eq!(String::from_utf8(text).unwrap(), "#..\n.#.\n");
+    }
+
+    #[test]
+    fn test_write_ppm() {
+        let mut ppm = Vec::new();
+        write_ppm(&mut ppm, &sample(), 2).unwrap();
+        let header = b"P6\n6 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(ppm.len(), header.len() + 6 * 4 * 3);
+        assert_eq!(
+            &ppm[header.len()..][..9],
+            [255, 255, 255, 255, 255, 255, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_write_png() {
+        let mut png = Vec::new();
+        write_png(&mut png, &sample(), 1).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], [0, 0, 0, 3, 0, 0, 0, 2]);
+        assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xae\x42\x60\x82");
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+        let stream = zlib_stored(b"abc");
+        assert_eq!(stream[2..10], [1, 3, 0, 0xFC, 0xFF, b'a', b'b', b'c']);
+    }
+}
@@ -0,0 +1,63 @@
+//! Generates a test case for every sample input that has a recorded answer.
+//!
+//! Samples live beside each day's puzzle input, in files whose names begin
+//! with `sample`.  The answer to part 1 or 2 for a sample `NAME` (or
+//! `NAME.EXT`) is recorded in `NAME.answer1` (or `NAME.answer1.EXT`), and so
+//! on; samples with no recorded answers, such as those exercising only part
+//! of a solution, are ignored.  The generated tests are included by
+//! `tests/samples.rs`.
+
+use std::fmt::Write as _;
+use std::path::Path;
+use std::{env, fs};
+
+#[path = "src/layout.rs"]
+mod layout;
+
+const YEARS: [u16; 5] = [2020, 2021, 2022, 2023, 2024];
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").expect("manifest directory");
+    let root = Path::new(&manifest_dir).join("..");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/layout.rs");
+
+    let mut tests = String::new();
+    for year in YEARS {
+        for day in 1..=25 {
+            let dir = layout::day_dir(&root, year, day);
+            let Ok(entries) = fs::read_dir(&dir) else {
+                continue;
+            };
+            println!("cargo:rerun-if-changed={}", dir.display());
+            let mut samples: Vec<String> = entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter(|name| layout::is_sample(name))
+                .collect();
+            samples.sort();
+            for sample in samples {
+                for part in [1, 2] {
+                    let answer = dir.join(layout::answer_name(&sample, part));
+                    if !answer.is_file() {
+                        continue;
+                    }
+                    let name: String = sample
+                        .chars()
+                        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                        .collect();
+                    writeln!(
+                        tests,
+                        "#[test]\nfn y{year}_day{day}_{name}_part{part}() {{\n    \
+                         check({year}, {day}, {part}, {:?}, {:?});\n}}\n",
+                        dir.join(&sample),
+                        answer,
+                    )
+                    .expect("formatted test");
+                }
+            }
+        }
+    }
+
+    let out_dir = env::var("OUT_DIR").expect("output directory");
+    fs::write(Path::new(&out_dir).join("samples.rs"), tests).expect("generated tests");
+}
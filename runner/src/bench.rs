@@ -0,0 +1,263 @@
+//! Repeated timing of puzzle solutions, and reports of the results.
+
+use crate::Solution;
+use advent_common::Error;
+use std::fmt::{self, Display, Formatter};
+use std::hint::black_box;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// The step of a solution being timed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stage {
+    Parse,
+    Part(u8),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+/// Summary statistics of repeated timings.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Stats {
+    pub runs: usize,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    /// Summarizes the specified samples, which must not be empty.
+    pub fn new(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "no samples");
+        samples.sort_unstable();
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+        Stats {
+            runs,
+            median,
+            min: samples[0],
+            max: samples[runs - 1],
+        }
+    }
+}
+
+/// The timing of one stage of one day's puzzle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+impl Display for Measurement {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let Measurement {
+            year,
+            day,
+            stage,
+            stats,
+        } = self;
+        write!(
+            f,
+            "{year} day{day} {stage}: median {:?}, min {:?}, max {:?} ({} runs)",
+            stats.median, stats.min, stats.max, stats.runs
+        )
+    }
+}
+
+/// Parses `text` `runs` times and solves each parse with each of the
+/// specified solutions, which must all be for the same day.  Returns the
+/// timing of parsing followed by the timing of each part.
+pub fn bench(solutions: &[&Solution], text: &str, runs: usize) -> Result<Vec<Measurement>, Error> {
+    let Some(first) = solutions.first() else {
+        return Ok(Vec::new());
+    };
+    let (year, day) = (first.year, first.day);
+    let mut parses = Vec::new();
+    let mut solves = vec![Vec::new(); solutions.len()];
+    for _ in 0..runs {
+        let start = Instant::now();
+        let puzzle = (first.parse)(text)?;
+        parses.push(start.elapsed());
+        for (solution, samples) in solutions.iter().zip(&mut solves) {
+            let start = Instant::now();
+            black_box(solution.solve(&*puzzle)?);
+            samples.push(start.elapsed());
+        }
+    }
+    let mut measurements = vec![Measurement {
+        year,
+        day,
+        stage: Stage::Parse,
+        stats: Stats::new(parses),
+    }];
+    for (solution, samples) in solutions.iter().zip(solves) {
+        measurements.push(Measurement {
+            year,
+            day,
+            stage: Stage::Part(solution.part),
+            stats: Stats::new(samples),
+        });
+    }
+    Ok(measurements)
+}
+
+/// Writes measurements as CSV, with one row per measurement and durations in
+/// nanoseconds.
+pub fn write_csv<W: Write>(mut w: W, measurements: &[Measurement]) -> io::Result<()> {
+    writeln!(w, "year,day,stage,runs,median_ns,min_ns,max_ns")?;
+    for Measurement {
+        year,
+        day,
+        stage,
+        stats,
+    } in measurements
+    {
+        writeln!(
+            w,
+            "{year},{day},{stage},{},{},{},{}",
+            stats.runs,
+            stats.median.as_nanos(),
+            stats.min.as_nanos(),
+            stats.max.as_nanos()
+        )?;
+    }
+    Ok(())
+}
+
+/// Writes measurements as a JSON array of objects having the same fields as
+/// the CSV columns.
+pub fn write_json<W: Write>(mut w: W, measurements: &[Measurement]) -> io::Result<()> {
+    writeln!(w, "[")?;
+    for (index, measurement) in measurements.iter().enumerate() {
+        let Measurement {
+            year,
+            day,
+            stage,
+            stats,
+        } = measurement;
+        let comma = if index + 1 < measurements.len() {
+            ","
+        } else {
+            ""
+        };
+        write!(
+            w,
+            r#"  {{"year": {year}, "day": {day}, "stage": "{stage}", "runs": {}, "#,
+            stats.runs
+        )?;
+        writeln!(
+            w,
+            r#""median_ns": {}, "min_ns": {}, "max_ns": {}}}{comma}"#,
+            stats.median.as_nanos(),
+            stats.min.as_nanos(),
+            stats.max.as_nanos()
+        )?;
+    }
+    writeln!(w, "]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Answer, Puzzle};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(vec![ms(5), ms(1), ms(3)]);
+        assert_eq!(
+            stats,
+            Stats {
+                runs: 3,
+                median: ms(3),
+                min: ms(1),
+                max: ms(5)
+            }
+        );
+        assert_eq!(Stats::new(vec![ms(4), ms(1), ms(2), ms(9)]).median, ms(3));
+    }
+
+    static PARSES: AtomicUsize = AtomicUsize::new(0);
+
+    struct Echo<'a>(&'a str);
+
+    impl Puzzle for Echo<'_> {
+        fn solve(&self, _part: u8) -> Answer {
+            Ok(self.0.to_owned())
+        }
+    }
+
+    fn parse_echo(text: &str) -> Result<Box<dyn Puzzle + '_>, Error> {
+        PARSES.fetch_add(1, Ordering::Relaxed);
+        Ok(Box::new(Echo(text)))
+    }
+
+    #[test]
+    fn test_bench() {
+        let part = |part| Solution {
+            year: 2020,
+            day: 1,
+            part,
+            parse: parse_echo,
+        };
+        let measurements = bench(&[&part(1), &part(2)], "", 3).unwrap();
+        let stages: Vec<_> = measurements.iter().map(|m| m.stage).collect();
+        assert_eq!(stages, [Stage::Parse, Stage::Part(1), Stage::Part(2)]);
+        assert!(measurements.iter().all(|m| m.stats.runs == 3));
+        assert_eq!(PARSES.load(Ordering::Relaxed), 3);
+    }
+
+    #[test]
+    fn test_write() {
+        let stats = Stats::new(vec![ms(1), ms(2)]);
+        let measurements = [
+            Measurement {
+                year: 2020,
+                day: 1,
+                stage: Stage::Parse,
+                stats,
+            },
+            Measurement {
+                year: 2020,
+                day: 1,
+                stage: Stage::Part(1),
+                stats,
+            },
+        ];
+        let mut csv = Vec::new();
+        write_csv(&mut csv, &measurements).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "year,day,stage,runs,median_ns,min_ns,max_ns\n\
+             2020,1,parse,2,1500000,1000000,2000000\n\
+             2020,1,part1,2,1500000,1000000,2000000\n"
+        );
+        let mut json = Vec::new();
+        write_json(&mut json, &measurements).unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            r#"[
+  {"year": 2020, "day": 1, "stage": "parse", "runs": 2, "median_ns": 1500000, "min_ns": 1000000, "max_ns": 2000000},
+  {"year": 2020, "day": 1, "stage": "part1", "runs": 2, "median_ns": 1500000, "min_ns": 1000000, "max_ns": 2000000}
+]
+"#
+        );
+    }
+}
@@ -0,0 +1,33 @@
+//! Creates a new day's crate, with empty input and sample files, and
+//! registers it with its year's workspace and with the runner.
+
+use advent_runner::scaffold;
+use std::env;
+use std::path::Path;
+use std::process::exit;
+
+const USAGE: &str = "usage: advent-new YEAR DAY";
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (year, day) = match args.as_slice() {
+        [year, day] => match (year.parse(), day.parse()) {
+            (Ok(year), Ok(day)) => (year, day),
+            _ => {
+                eprintln!("error: bad year or day\n{USAGE}");
+                exit(2);
+            }
+        },
+        _ => {
+            eprintln!("{USAGE}");
+            exit(2);
+        }
+    };
+    let root = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+    if let Err(err) = scaffold::new_day(root, year, day) {
+        eprintln!("error: {err}");
+        exit(1);
+    }
+    let name = scaffold::crate_name(year, day);
+    println!("created {name}; paste the puzzle input into {year}/day{day}/src");
+}
@@ -0,0 +1,69 @@
+//! Where each year keeps its puzzle inputs.  Shared with the build script,
+//! which generates test cases from the sample inputs.
+
+// The library and the build script each use only some of these functions.
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+/// Returns the directory holding the specified day's input, samples, and
+/// recorded answers.
+pub fn day_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    match year {
+        2020..=2022 => root.join(format!("{year}/tests/day{day}")),
+        _ => root.join(format!("{year}/day{day}/src")),
+    }
+}
+
+/// Returns the name of the puzzle input file within a year's day directories.
+pub fn input_name(year: u16) -> &'static str {
+    match year {
+        2023 => "input.txt",
+        _ => "input",
+    }
+}
+
+/// Returns the name of the file recording the answer to the specified part
+/// for the named input or sample file: `input` becomes `answer1`, and any
+/// other file `stem.ext` becomes `stem.answer1.ext`.
+pub fn answer_name(input_name: &str, part: u8) -> String {
+    let (stem, extension) = match input_name.split_once('.') {
+        Some((stem, extension)) => (stem, Some(extension)),
+        None => (input_name, None),
+    };
+    let answer = match stem {
+        "input" => format!("answer{part}"),
+        _ => format!("{stem}.answer{part}"),
+    };
+    match extension {
+        Some(extension) => format!("{answer}.{extension}"),
+        None => answer,
+    }
+}
+
+/// Returns true if the named file is a sample input, rather than a recorded
+/// answer to one.
+pub fn is_sample(name: &str) -> bool {
+    name.starts_with("sample") && !name.contains(".answer")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer_name() {
+        assert_eq!(answer_name("input", 1), "answer1");
+        assert_eq!(answer_name("input.txt", 2), "answer2.txt");
+        assert_eq!(answer_name("sample1a", 1), "sample1a.answer1");
+        assert_eq!(answer_name("sample.txt", 2), "sample.answer2.txt");
+    }
+
+    #[test]
+    fn test_is_sample() {
+        assert!(is_sample("sample1a"));
+        assert!(is_sample("sample.txt"));
+        assert!(!is_sample("sample.answer1.txt"));
+        assert!(!is_sample("input"));
+    }
+}
@@ -0,0 +1,253 @@
+//! Registry of every puzzle solution in this repository, across all years.
+//!
+//! Each day of each year implements the shared [`Solver`] trait; this crate
+//! wraps each part of each day in a uniform [`Solution`] so that a single
+//! binary can run any subset of them against any input.
+
+use advent_common::{Error, Solver};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub mod bench;
+mod layout;
+pub mod output;
+pub mod pool;
+pub mod scaffold;
+mod year2020;
+mod year2021;
+mod year2022;
+mod year2023;
+mod year2024;
+
+/// The rendered answer to one part of one puzzle, or the reason it couldn't
+/// be computed.
+pub type Answer = Result<String, Error>;
+
+/// The outcome of checking a solution's answer against the recorded one.
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    /// The answer matches the recorded answer.
+    Pass,
+    /// The answer differs from the recorded answer.
+    Fail { expected: String, actual: String },
+    /// No answer has been recorded.
+    Missing { actual: String },
+    /// The solution failed to produce an answer.
+    Error(Error),
+}
+
+/// A day's puzzle input, parsed once so that both of its parts can be solved
+/// without parsing it again.
+pub trait Puzzle {
+    /// Solves the specified part of the puzzle.
+    fn solve(&self, part: u8) -> Answer;
+}
+
+struct Parsed<'a, S: Solver>(S::Puzzle<'a>);
+
+impl<S: Solver> Puzzle for Parsed<'_, S> {
+    fn solve(&self, part: u8) -> Answer {
+        match part {
+            1 => Ok(S::part1(&self.0)?.to_string()),
+            _ => Ok(S::part2(&self.0)?.to_string()),
+        }
+    }
+}
+
+fn parse<S: Solver + 'static>(text: &str) -> Result<Box<dyn Puzzle + '_>, Error> {
+    Ok(Box::new(Parsed::<S>(S::parse(text)?)))
+}
+
+/// A solver for one part of one day's puzzle.  Both parts of a day share the
+/// same `parse`, so a day's input need only be parsed once.
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub parse: fn(&str) -> Result<Box<dyn Puzzle + '_>, Error>,
+}
+
+impl Solution {
+    const fn part1<S: Solver + 'static>(year: u16, day: u8) -> Solution {
+        Solution {
+            year,
+            day,
+            part: 1,
+            parse: parse::<S>,
+        }
+    }
+
+    const fn part2<S: Solver + 'static>(year: u16, day: u8) -> Solution {
+        Solution {
+            year,
+            day,
+            part: 2,
+            parse: parse::<S>,
+        }
+    }
+
+    /// Solves this solution's part of a puzzle parsed by its `parse`.
+    pub fn solve(&self, puzzle: &dyn Puzzle) -> Answer {
+        puzzle.solve(self.part)
+    }
+
+    /// Returns the path of the puzzle input checked into this repository for
+    /// this solution's year and day.
+    pub fn default_input(&self) -> PathBuf {
+        let root = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."));
+        layout::day_dir(root, self.year, self.day).join(layout::input_name(self.year))
+    }
+
+    /// Returns the path of the recorded answer to this solution's default
+    /// input.  Answers are stored beside the input, named `answer1` or
+    /// `answer2` (with the input's extension, if any).
+    pub fn answer_path(&self) -> PathBuf {
+        let name = layout::answer_name(layout::input_name(self.year), self.part);
+        self.default_input().with_file_name(name)
+    }
+
+    /// Reads the recorded answer to this solution's default input, if any.
+    pub fn recorded_answer(&self) -> io::Result<Option<String>> {
+        match fs::read_to_string(self.answer_path()) {
+            Ok(answer) => Ok(Some(answer.trim_end().to_owned())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Solves `text` and compares the answer with `expected`.  Trailing
+    /// whitespace is ignored, since multi-line answers often end with a
+    /// newline.
+    pub fn verify(&self, text: &str, expected: Option<&str>) -> Verdict {
+        let answer = (self.parse)(text).and_then(|puzzle| self.solve(&*puzzle));
+        Verdict::new(answer, expected)
+    }
+}
+
+impl Verdict {
+    /// Compares an answer with `expected`, ignoring trailing whitespace.
+    pub fn new(answer: Answer, expected: Option<&str>) -> Verdict {
+        let actual = match answer {
+            Ok(answer) => answer.trim_end().to_owned(),
+            Err(err) => return Verdict::Error(err),
+        };
+        match expected {
+            None => Verdict::Missing { actual },
+            Some(expected) if expected.trim_end() == actual => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.trim_end().to_owned(),
+                actual,
+            },
+        }
+    }
+}
+
+/// Returns all registered solutions, ordered by year, day, and part.
+pub fn solutions() -> impl Iterator<Item = &'static Solution> {
+    [
+        year2020::SOLUTIONS,
+        year2021::SOLUTIONS,
+        year2022::SOLUTIONS,
+        year2023::SOLUTIONS,
+        year2024::SOLUTIONS,
+    ]
+    .into_iter()
+    .flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_common::fuzz::Fuzzer;
+
+    #[test]
+    fn test_solutions_are_ordered_and_unique() {
+        let keys: Vec<_> = solutions().map(|s| (s.year, s.day, s.part)).collect();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_default_inputs_exist() {
+        for solution in solutions() {
+            let path = solution.default_input();
+            assert!(path.is_file(), "{}", path.display());
+        }
+    }
+
+    /// Fuzzes each day's parser with inputs mutated from the files beside its
+    /// default input.
+    #[test]
+    fn fuzz_parsers() {
+        for solution in solutions().filter(|s| s.part == 1) {
+            let (year, day) = (solution.year, solution.day);
+            let input = solution.default_input();
+            let dir = input.parent().expect("a directory of inputs");
+            Fuzzer::new(format!("{year} day{day} parse"))
+                .seed_dir(dir)
+                .unwrap_or_else(|err| panic!("{}: {err}", dir.display()))
+                .run(|text| {
+                    let _ = (solution.parse)(text);
+                });
+        }
+    }
+
+    #[test]
+    fn test_answer_path() {
+        let path = |year, day, part| {
+            let solution = solutions()
+                .find(|s| (s.year, s.day, s.part) == (year, day, part))
+                .unwrap();
+            let path = solution.answer_path();
+            let input = solution.default_input();
+            assert_eq!(path.parent(), input.parent());
+            path.file_name().unwrap().to_string_lossy().into_owned()
+        };
+        assert_eq!(path(2020, 1, 2), "answer2");
+        assert_eq!(path(2023, 1, 1), "answer1.txt");
+        assert_eq!(path(2024, 1, 1), "answer1");
+    }
+
+    struct Length(usize);
+
+    impl Puzzle for Length {
+        fn solve(&self, _part: u8) -> Answer {
+            Ok(format!("{}\n", self.0))
+        }
+    }
+
+    fn parse_length(text: &str) -> Result<Box<dyn Puzzle + '_>, Error> {
+        match text {
+            "" => Err(Error::empty_input()),
+            _ => Ok(Box::new(Length(text.len()))),
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let solution = Solution {
+            year: 2020,
+            day: 1,
+            part: 1,
+            parse: parse_length,
+        };
+        assert_eq!(solution.verify("abc", Some("3\n")), Verdict::Pass);
+        assert_eq!(
+            solution.verify("abc", Some("4")),
+            Verdict::Fail {
+                expected: "4".to_owned(),
+                actual: "3".to_owned()
+            }
+        );
+        assert_eq!(
+            solution.verify("abc", None),
+            Verdict::Missing {
+                actual: "3".to_owned()
+            }
+        );
+        assert_eq!(
+            solution.verify("", Some("0")),
+            Verdict::Error(Error::empty_input())
+        );
+    }
+}
@@ -0,0 +1,416 @@
+//! Runs any subset of the puzzle solutions in this repository.
+//!
+//! With no arguments, every part of every day of every year is run against
+//! the input checked into the repository.  The `--year`, `--day`, and `--part`
+//! options narrow the selection, and `--input` supplies a different input file
+//! (or `-` for standard input) for a single selected day.
+//!
+//! With `--verify`, each selected solution's answer is instead compared with
+//! the answer recorded beside its default input, and the run fails if any
+//! answer differs from the recorded one or can't be computed.
+//!
+//! With `--bench`, each selected day is instead solved repeatedly (`--runs`
+//! times, 10 by default), and the median, minimum, and maximum times spent
+//! parsing its input and solving each part are reported.  `--output` also
+//! writes the timings to a `.json` or `.csv` file, for comparison across
+//! commits.
+//!
+//! With `--log LEVEL`, diagnostic events at that level and above, such as the
+//! progress of slow solutions, are written to standard error, prefixed with
+//! the year, day, and part being solved.
+//!
+//! With `--format json`, answers (or timings, with `--bench`) are printed as
+//! a JSON array instead of as text, so that scripts needn't scrape them.
+//!
+//! Days run concurrently, up to `--jobs` at a time (one per processor by
+//! default), but their results are always reported in order of year, day, and
+//! part.  Each day's input is parsed once, and both of its parts are solved
+//! from the same parse.  Benchmarks run one at a time, so that they don't
+//! compete for processors.
+
+use advent_common::{trace, Error, ErrorKind};
+use advent_runner::bench::{self, Measurement};
+use advent_runner::output::{self, Format, Outcome};
+use advent_runner::{pool, solutions, Answer, Solution, Verdict};
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process::exit;
+use std::time::Instant;
+use std::{env, fs};
+
+const USAGE: &str = "\
+usage: advent [--year YEAR] [--day DAY] [--part PART] [--input PATH|-]
+              [--verify | --bench [--runs N] [--output PATH.json|PATH.csv]]
+              [--format text|json] [--log LEVEL] [--jobs N]";
+
+enum Input {
+    Default,
+    Stdin,
+    Path(PathBuf),
+}
+
+struct Args {
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Input,
+    verify: bool,
+    bench: bool,
+    runs: usize,
+    output: Option<PathBuf>,
+    format: Format,
+    jobs: usize,
+}
+
+impl Args {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Args, String> {
+        let mut parsed = Args {
+            year: None,
+            day: None,
+            part: None,
+            input: Input::Default,
+            verify: false,
+            bench: false,
+            runs: 10,
+            output: None,
+            format: Format::Text,
+            jobs: pool::default_jobs(),
+        };
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or(format!("{arg}: missing value"));
+            match arg.as_str() {
+                "--year" => parsed.year = Some(parse_number(&arg, &value()?)?),
+                "--day" => parsed.day = Some(parse_number(&arg, &value()?)?),
+                "--part" => parsed.part = Some(parse_number(&arg, &value()?)?),
+                "--input" => {
+                    parsed.input = match value()?.as_str() {
+                        "-" => Input::Stdin,
+                        path => Input::Path(PathBuf::from(path)),
+                    }
+                }
+                "--verify" => parsed.verify = true,
+                "--bench" => parsed.bench = true,
+                "--runs" => match parse_number(&arg, &value()?)? {
+                    0 => return Err(format!("{arg}: must be positive")),
+                    runs => parsed.runs = runs,
+                },
+                "--jobs" => match parse_number(&arg, &value()?)? {
+                    0 => return Err(format!("{arg}: must be positive")),
+                    jobs => parsed.jobs = jobs,
+                },
+                "--output" => {
+                    let path = PathBuf::from(value()?);
+                    match path.extension().and_then(|ext| ext.to_str()) {
+                        Some("json" | "csv") => parsed.output = Some(path),
+                        _ => return Err(format!("{arg}: expected a .json or .csv file")),
+                    }
+                }
+                "--format" => {
+                    parsed.format = value()?.parse().map_err(|err| format!("{arg}: {err}"))?
+                }
+                "--log" => trace::set_level(
+                    trace::parse_level(&value()?).map_err(|err| format!("{arg}: {err}"))?,
+                ),
+                "-h" | "--help" => {
+                    println!("{USAGE}");
+                    exit(0);
+                }
+                _ => return Err(format!("{arg}: unrecognized argument")),
+            }
+        }
+        if parsed.verify && parsed.bench {
+            return Err("--verify and --bench are mutually exclusive".to_owned());
+        }
+        if parsed.output.is_some() && !parsed.bench {
+            return Err("--output requires --bench".to_owned());
+        }
+        if parsed.verify && parsed.format == Format::Json {
+            return Err("--verify reports only as text".to_owned());
+        }
+        Ok(parsed)
+    }
+
+    fn selects(&self, solution: &Solution) -> bool {
+        self.year.is_none_or(|year| year == solution.year)
+            && self.day.is_none_or(|day| day == solution.day)
+            && self.part.is_none_or(|part| part == solution.part)
+    }
+}
+
+fn parse_number<T: std::str::FromStr>(arg: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{arg}: bad value: {value}"))
+}
+
+/// Reads the text of the specified input, or of the solution's default input.
+fn read_input(input: &Input, solution: &Solution) -> Result<String, String> {
+    match input {
+        Input::Default => {
+            let path = solution.default_input();
+            fs::read_to_string(&path).map_err(|err| format!("{}: {err}", path.display()))
+        }
+        Input::Path(path) => {
+            fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+        }
+        Input::Stdin => {
+            let mut text = String::new();
+            io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|err| format!("stdin: {err}"))?;
+            Ok(text)
+        }
+    }
+}
+
+fn main() {
+    let args = Args::parse(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("error: {err}\n{USAGE}");
+        exit(2);
+    });
+
+    let selected: Vec<&Solution> = solutions().filter(|s| args.selects(s)).collect();
+    if selected.is_empty() {
+        eprintln!("error: no solutions match the selection");
+        exit(2);
+    }
+    let mut days: Vec<_> = selected.iter().map(|s| (s.year, s.day)).collect();
+    days.dedup();
+    if days.len() > 1 && !matches!(args.input, Input::Default) {
+        eprintln!("error: --input requires selecting a single year and day");
+        exit(2);
+    }
+
+    if args.verify && !matches!(args.input, Input::Default) {
+        eprintln!("error: --verify checks only the default inputs");
+        exit(2);
+    }
+
+    if args.bench {
+        exit(if run_benchmarks(&args, &selected) {
+            0
+        } else {
+            1
+        });
+    }
+
+    // Read each day's input once, even when it's shared by both parts.
+    let days: Vec<&[&Solution]> = selected
+        .chunk_by(|a, b| (a.year, a.day) == (b.year, b.day))
+        .collect();
+    let texts: Vec<Result<String, String>> = days
+        .iter()
+        .map(|solutions| read_input(&args.input, solutions[0]))
+        .collect();
+
+    let mut failed = false;
+    let mut counts = Counts::default();
+    let mut outcomes = Vec::new();
+    // Each day is one task, which parses the day's input once and solves
+    // each selected part of the parsed puzzle.
+    let solve = |&index: &usize| {
+        let text = texts[index].as_ref().ok()?;
+        let solutions = days[index];
+        let (year, day) = (solutions[0].year, solutions[0].day);
+        let start = Instant::now();
+        let parsed = {
+            let _span = trace::span(format_args!("{year} day{day} parse"));
+            (solutions[0].parse)(text)
+        };
+        let parse_time = start.elapsed();
+        let solved = solutions.iter().map(|solution| {
+            let part = solution.part;
+            let _span = trace::span(format_args!("{year} day{day} part{part}"));
+            let start = Instant::now();
+            let answer = match &parsed {
+                Ok(puzzle) => solution.solve(&**puzzle),
+                Err(err) => Err(err.clone()),
+            };
+            (answer, parse_time + start.elapsed())
+        });
+        Some(solved.collect::<Vec<_>>())
+    };
+    let tasks: Vec<usize> = (0..days.len()).collect();
+    pool::run_ordered(args.jobs, &tasks, solve, |index, solved| {
+        let text = &texts[index];
+        let mut solved = solved.into_iter().flatten();
+        for &solution in days[index] {
+            let (year, day, part) = (solution.year, solution.day, solution.part);
+            match text {
+                Ok(text) => {
+                    let (answer, elapsed) = solved.next().expect("an answer for each part");
+                    if args.verify {
+                        failed |= !verify(solution, answer, text, &mut counts);
+                        continue;
+                    }
+                    failed |= answer.is_err();
+                    match args.format {
+                        Format::Text => print_answer(solution, &answer, text),
+                        Format::Json => outcomes.push(Outcome {
+                            year,
+                            day,
+                            part,
+                            answer,
+                            elapsed,
+                        }),
+                    }
+                }
+                Err(err) if args.format == Format::Json => {
+                    outcomes.push(Outcome {
+                        year,
+                        day,
+                        part,
+                        answer: Err(Error::new(ErrorKind::Io, err)),
+                        elapsed: Default::default(),
+                    });
+                    failed = true;
+                }
+                Err(err) => {
+                    eprintln!("error: {year} day{day} part{part}: {err}");
+                    counts.failed += 1;
+                    failed = true;
+                }
+            }
+        }
+    });
+    if args.format == Format::Json {
+        if let Err(err) = output::write_json(io::stdout().lock(), &outcomes) {
+            eprintln!("error: stdout: {err}");
+            failed = true;
+        }
+    }
+    if args.verify {
+        let Counts {
+            passed,
+            failed,
+            missing,
+        } = counts;
+        println!("{passed} passed, {failed} failed, {missing} missing");
+    }
+    if failed {
+        exit(1);
+    }
+}
+
+/// Prints a solution's answer, or the error that prevented it.
+fn print_answer(solution: &Solution, answer: &Result<String, Error>, text: &str) {
+    let (year, day, part) = (solution.year, solution.day, solution.part);
+    match answer {
+        Ok(answer) if answer.contains('\n') => {
+            println!("{year} day{day} part{part}:\n{}", answer.trim_end())
+        }
+        Ok(answer) => println!("{year} day{day} part{part}: {answer}"),
+        Err(err) => report(&format!("{year} day{day} part{part}"), err, text),
+    }
+}
+
+/// Prints an error, followed by the line of input at fault if it's known.
+fn report(context: &str, err: &Error, text: &str) {
+    eprintln!("error: {context}: {err}");
+    if let Some(snippet) = err.snippet(text) {
+        eprint!("{snippet}");
+    }
+}
+
+#[derive(Default)]
+struct Counts {
+    passed: usize,
+    failed: usize,
+    missing: usize,
+}
+
+/// Checks a solution's answer to `text` against the recorded one, reporting
+/// the outcome.  Returns false if the answer is wrong or couldn't be computed.
+fn verify(solution: &Solution, answer: Answer, text: &str, counts: &mut Counts) -> bool {
+    let (year, day, part) = (solution.year, solution.day, solution.part);
+    let expected = match solution.recorded_answer() {
+        Ok(expected) => expected,
+        Err(err) => {
+            let path = solution.answer_path();
+            eprintln!(
+                "error: {year} day{day} part{part}: {}: {err}",
+                path.display()
+            );
+            counts.failed += 1;
+            return false;
+        }
+    };
+    match Verdict::new(answer, expected.as_deref()) {
+        Verdict::Pass => {
+            println!("{year} day{day} part{part}: pass");
+            counts.passed += 1;
+            true
+        }
+        Verdict::Missing { .. } => {
+            println!("{year} day{day} part{part}: missing");
+            counts.missing += 1;
+            true
+        }
+        Verdict::Fail { expected, actual } => {
+            println!("{year} day{day} part{part}: FAIL");
+            println!("  expected: {}", expected.replace('\n', "\n            "));
+            println!("  actual:   {}", actual.replace('\n', "\n            "));
+            counts.failed += 1;
+            false
+        }
+        Verdict::Error(err) => {
+            println!("{year} day{day} part{part}: FAIL");
+            report(&format!("{year} day{day} part{part}"), &err, text);
+            counts.failed += 1;
+            false
+        }
+    }
+}
+
+/// Times each selected day, printing and optionally saving the results.
+/// Returns false if any day couldn't be timed.
+fn run_benchmarks(args: &Args, selected: &[&Solution]) -> bool {
+    let mut ok = true;
+    let mut measurements: Vec<Measurement> = Vec::new();
+    for solutions in selected.chunk_by(|a, b| (a.year, a.day) == (b.year, b.day)) {
+        let (year, day) = (solutions[0].year, solutions[0].day);
+        let text = match read_input(&args.input, solutions[0]) {
+            Ok(text) => text,
+            Err(err) => {
+                eprintln!("error: {year} day{day}: {err}");
+                ok = false;
+                continue;
+            }
+        };
+        let _span = trace::span(format_args!("{year} day{day}"));
+        match bench::bench(solutions, &text, args.runs) {
+            Ok(timed) => {
+                if args.format == Format::Text {
+                    for measurement in &timed {
+                        println!("{measurement}");
+                    }
+                }
+                measurements.extend(timed);
+            }
+            Err(err) => {
+                report(&format!("{year} day{day}"), &err, &text);
+                ok = false;
+            }
+        }
+    }
+    if args.format == Format::Json {
+        if let Err(err) = bench::write_json(io::stdout().lock(), &measurements) {
+            eprintln!("error: stdout: {err}");
+            ok = false;
+        }
+    }
+    if let Some(path) = &args.output {
+        let written = fs::File::create(path).and_then(|file| {
+            let file = io::BufWriter::new(file);
+            match path.extension().and_then(|ext| ext.to_str()) {
+                Some("json") => bench::write_json(file, &measurements),
+                _ => bench::write_csv(file, &measurements),
+            }
+        });
+        if let Err(err) = written {
+            eprintln!("error: {}: {err}", path.display());
+            ok = false;
+        }
+    }
+    ok
+}
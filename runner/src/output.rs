@@ -0,0 +1,153 @@
+//! Machine-readable reports of solution runs, for consumption by scripts.
+
+use crate::Answer;
+use advent_common::{Error, ErrorKind};
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// How the runner reports its results.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    /// Lines of text, for people.
+    #[default]
+    Text,
+    /// A JSON array of objects, one per part or timed stage.
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("{s}: expected text or json")),
+        }
+    }
+}
+
+/// The result of running one part of one day's puzzle, and how long it took
+/// to parse the input and solve the part.
+#[derive(Debug)]
+pub struct Outcome {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Returns the name by which JSON reports identify an error kind.
+pub fn kind_name(kind: ErrorKind) -> &'static str {
+    match kind {
+        ErrorKind::Parse => "parse",
+        ErrorKind::EmptyInput => "empty_input",
+        ErrorKind::NoSolution => "no_solution",
+        ErrorKind::Io => "io",
+        ErrorKind::Other => "other",
+    }
+}
+
+/// Returns `s` as a quoted JSON string.
+pub fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c < ' ' => write!(quoted, "\\u{:04x}", c as u32).expect("write to string"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn error_json(err: &Error) -> String {
+    let optional = |n: Option<usize>| n.map_or("null".to_owned(), |n| n.to_string());
+    format!(
+        r#"{{"kind": "{}", "message": {}, "line": {}, "column": {}}}"#,
+        kind_name(err.kind()),
+        quote(&err.to_string()),
+        optional(err.line()),
+        optional(err.column())
+    )
+}
+
+/// Writes outcomes as a JSON array of objects, each having the fields
+/// `year`, `day`, `part`, `answer` (null if the part failed), `error` (null
+/// if it succeeded, or else an object with the error's `kind`, `message`,
+/// `line`, and `column`), and `elapsed_ns`.
+pub fn write_json<W: Write>(mut w: W, outcomes: &[Outcome]) -> io::Result<()> {
+    writeln!(w, "[")?;
+    for (index, outcome) in outcomes.iter().enumerate() {
+        let Outcome {
+            year,
+            day,
+            part,
+            answer,
+            elapsed,
+        } = outcome;
+        let (answer, error) = match answer {
+            Ok(answer) => (quote(answer.trim_end()), "null".to_owned()),
+            Err(err) => ("null".to_owned(), error_json(err)),
+        };
+        let comma = if index + 1 < outcomes.len() { "," } else { "" };
+        writeln!(
+            w,
+            r#"  {{"year": {year}, "day": {day}, "part": {part}, "answer": {answer}, "error": {error}, "elapsed_ns": {}}}{comma}"#,
+            elapsed.as_nanos()
+        )?;
+    }
+    writeln!(w, "]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("abc"), r#""abc""#);
+        assert_eq!(quote("a\"b\\c\nd\u{1}"), r#""a\"b\\c\nd\u0001""#);
+    }
+
+    #[test]
+    fn test_write_json() {
+        let outcomes = [
+            Outcome {
+                year: 2020,
+                day: 1,
+                part: 1,
+                answer: Ok("#.\n.#\n".to_owned()),
+                elapsed: Duration::from_micros(3),
+            },
+            Outcome {
+                year: 2020,
+                day: 1,
+                part: 2,
+                answer: Err(Error::parse("bad").at(2, 5)),
+                elapsed: Duration::from_micros(4),
+            },
+        ];
+        let mut json = Vec::new();
+        write_json(&mut json, &outcomes).unwrap();
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            r##"[
+  {"year": 2020, "day": 1, "part": 1, "answer": "#.\n.#", "error": null, "elapsed_ns": 3000},
+  {"year": 2020, "day": 1, "part": 2, "answer": null, "error": {"kind": "parse", "message": "2:5: bad", "line": 2, "column": 5}, "elapsed_ns": 4000}
+]
+"##
+        );
+        assert_eq!("json".parse(), Ok(Format::Json));
+        assert!("yaml".parse::<Format>().is_err());
+    }
+}
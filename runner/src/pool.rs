@@ -0,0 +1,107 @@
+//! Running independent tasks concurrently, while reporting their results in
+//! order.
+
+use std::collections::BTreeMap;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Returns the number of tasks to run at once by default: one per available
+/// processor.
+pub fn default_jobs() -> usize {
+    thread::available_parallelism().map_or(1, NonZeroUsize::get)
+}
+
+/// Performs `work` on each task, using up to `jobs` threads, and passes each
+/// result to `report` along with its task's index.  Results are reported in
+/// the order of their tasks, each as soon as it and every earlier result are
+/// ready, so the first results can be shown while later tasks still run.
+pub fn run_ordered<T, R, W, F>(jobs: usize, tasks: &[T], work: W, mut report: F)
+where
+    T: Sync,
+    R: Send,
+    W: Fn(&T) -> R + Sync,
+    F: FnMut(usize, R),
+{
+    let jobs = jobs.clamp(1, tasks.len().max(1));
+    if jobs == 1 {
+        for (index, task) in tasks.iter().enumerate() {
+            report(index, work(task));
+        }
+        return;
+    }
+    let next = AtomicUsize::new(0); // the index of the next task to start
+    thread::scope(|scope| {
+        let (sender, receiver) = mpsc::channel();
+        for _ in 0..jobs {
+            let (sender, next, work) = (sender.clone(), &next, &work);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(task) = tasks.get(index) else {
+                    break;
+                };
+                if sender.send((index, work(task))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        // Hold each result until every earlier one has been reported.
+        let mut pending = BTreeMap::new();
+        let mut reported = 0;
+        for (index, result) in receiver {
+            pending.insert(index, result);
+            while let Some(result) = pending.remove(&reported) {
+                report(reported, result);
+                reported += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_run_ordered() {
+        // Earlier tasks take longer, so they finish out of order.
+        let tasks: Vec<u64> = (0..12).collect();
+        for jobs in [1, 4, 100] {
+            let mut results = Vec::new();
+            run_ordered(
+                jobs,
+                &tasks,
+                |&task| {
+                    thread::sleep(Duration::from_millis(12 - task));
+                    task * 10
+                },
+                |index, result| results.push((index, result)),
+            );
+            let want: Vec<_> = (0..12).map(|task| (task as usize, task * 10)).collect();
+            assert_eq!(results, want, "jobs: {jobs}");
+        }
+    }
+
+    #[test]
+    fn test_run_ordered_concurrently() {
+        let tasks = [(); 4];
+        let mut threads = Vec::new();
+        run_ordered(
+            4,
+            &tasks,
+            |()| {
+                thread::sleep(Duration::from_millis(20));
+                thread::current().id()
+            },
+            |_, id| threads.push(id),
+        );
+        threads.sort_by_key(|id| format!("{id:?}"));
+        threads.dedup();
+        assert!(threads.len() > 1, "ran on one thread");
+        run_ordered(3, &[] as &[()], |()| (), |_, ()| panic!("no tasks"));
+    }
+}
@@ -0,0 +1,304 @@
+//! Creation of new days in the per-day crate layout used since 2023.
+//!
+//! Each day of such a year is its own crate, `advent{year}-day{day}`, in a
+//! subdirectory of the year's Cargo workspace.  A new day needs the crate
+//! itself, a `members` entry in the workspace manifest, a dependency in this
+//! crate's manifest, and a pair of entries in the year's list of solutions.
+//! The functions that edit existing files are pure, returning the new text.
+
+use crate::layout;
+use advent_common::Error;
+use std::fs;
+use std::path::Path;
+
+/// Returns the name of the crate for the specified day.
+pub fn crate_name(year: u16, day: u8) -> String {
+    format!("advent{year}-day{day}")
+}
+
+/// Returns the day number of a name like `day7`, or of a line mentioning
+/// such a name, like `"day7",`.
+fn day_of(line: &str) -> Option<u8> {
+    let start = line.find("day")? + "day".len();
+    let digits: String = line[start..]
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+/// Inserts `new` as a line of `text`, after the last line that satisfies
+/// `is_entry` for an earlier day, or else before the first entry.  Returns
+/// None if there are no entries at all.
+fn insert_line(text: &str, day: u8, new: &str, is_entry: impl Fn(&str) -> bool) -> Option<String> {
+    let mut lines: Vec<&str> = text.lines().collect();
+    let entries: Vec<usize> = (0..lines.len()).filter(|&i| is_entry(lines[i])).collect();
+    let index = match entries
+        .iter()
+        .rfind(|&&i| day_of(lines[i]).is_some_and(|d| d < day))
+    {
+        Some(&i) => i + 1,
+        None => *entries.first()?,
+    };
+    lines.insert(index, new);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds a day to the `members` of a workspace manifest, preserving whether
+/// the members are listed on one line or one per line.
+pub fn add_member(manifest: &str, day: u8) -> Result<String, Error> {
+    let start = manifest
+        .find("members = [")
+        .ok_or_else(|| Error::parse("workspace manifest has no members"))?;
+    let open = start + "members = [".len();
+    let close = open
+        + manifest[open..]
+            .find(']')
+            .ok_or_else(|| Error::parse("unterminated workspace members"))?;
+    let mut members: Vec<&str> = manifest[open..close]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect();
+    let name = format!("day{day}");
+    if members.contains(&name.as_str()) {
+        return Err(Error::parse(format!(
+            "{name} is already a workspace member"
+        )));
+    }
+    let index = members
+        .iter()
+        .position(|member| day_of(member).is_some_and(|d| d > day))
+        .unwrap_or(members.len());
+    members.insert(index, &name);
+    let list = if manifest[open..close].contains('\n') {
+        let lines: String = members.iter().map(|m| format!("    \"{m}\",\n")).collect();
+        format!("\n{lines}")
+    } else {
+        let quoted: Vec<String> = members.iter().map(|m| format!("\"{m}\"")).collect();
+        quoted.join(", ")
+    };
+    Ok(format!("{}{list}{}", &manifest[..open], &manifest[close..]))
+}
+
+/// Adds a dependency on the specified day's crate to this crate's manifest.
+pub fn add_dependency(manifest: &str, year: u16, day: u8) -> Result<String, Error> {
+    let name = crate_name(year, day);
+    let prefix = format!("advent{year}-day");
+    let new = format!("{name} = {{ path = \"../{year}/day{day}\" }}");
+    insert_line(manifest, day, &new, |line| line.starts_with(&prefix))
+        .ok_or_else(|| Error::parse(format!("no dependencies on {year} days")))
+}
+
+/// Adds solutions for both parts of the specified day to a year's list.
+pub fn add_solutions(module: &str, year: u16, day: u8) -> Result<String, Error> {
+    let path = format!("advent{year}_day{day}::Day{day}");
+    let is_entry = |line: &str| line.trim_start().starts_with("Solution::");
+    let text = insert_line(
+        module,
+        day,
+        &format!("    Solution::part2::<{path}>({year}, {day}),"),
+        is_entry,
+    )
+    .ok_or_else(|| Error::parse("no solutions to add to"))?;
+    // Part 1 goes just before part 2.
+    Ok(text.replacen(
+        &format!("    Solution::part2::<{path}>"),
+        &format!("    Solution::part1::<{path}>({year}, {day}),\n    Solution::part2::<{path}>"),
+        1,
+    ))
+}
+
+fn lib_rs(day: u8) -> String {
+    format!(
+        r#"pub mod part1;
+pub mod part2;
+
+use advent_common::{{Error, Solver}};
+
+/// Returns the lines of the puzzle input.
+pub fn lines(text: &str) -> Vec<&str> {{
+    text.lines().collect() // TODO
+}}
+
+pub struct Day{day};
+
+impl Solver for Day{day} {{
+    type Puzzle<'a> = Vec<&'a str>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(text: &str) -> Result<Vec<&str>, Error> {{
+        Ok(lines(text))
+    }}
+
+    fn part1(lines: &Vec<&str>) -> Result<usize, Error> {{
+        Ok(part1::solve(lines))
+    }}
+
+    fn part2(lines: &Vec<&str>) -> Result<usize, Error> {{
+        Ok(part2::solve(lines))
+    }}
+}}
+"#
+    )
+}
+
+fn part_rs(sample: &str) -> String {
+    format!(
+        r#"pub fn solve(lines: &[&str]) -> usize {{
+    lines.len() // TODO
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use crate::lines;
+
+    #[test]
+    fn test_solve() {{
+        assert_eq!(solve(&lines(include_str!("{sample}"))), 0); // TODO
+    }}
+}}
+"#
+    )
+}
+
+fn main_rs(year: u16, day: u8, input: &str) -> String {
+    format!(
+        r#"use advent{year}_day{day}::{{lines, part1, part2}};
+use advent_common::input;
+
+fn main() {{
+    let text = input::text_or(include_str!("{input}")).unwrap_or_else(|err| {{
+        eprintln!("error: {{err}}");
+        std::process::exit(3);
+    }});
+    let lines = lines(&text);
+    println!("{{}}", part1::solve(&lines));
+    println!("{{}}", part2::solve(&lines));
+}}
+"#
+    )
+}
+
+fn cargo_toml(year: u16, day: u8) -> String {
+    format!(
+        r#"[package]
+name = "{}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+advent-common = {{ path = "../../common" }}
+"#,
+        crate_name(year, day)
+    )
+}
+
+fn write(path: &Path, text: &str) -> Result<(), Error> {
+    fs::write(path, text).map_err(|err| Error::from(err).in_file(path))
+}
+
+/// Reads the file at `path` and returns its edited text, without writing it.
+fn edit(path: &Path, f: impl FnOnce(&str) -> Result<String, Error>) -> Result<String, Error> {
+    let text = fs::read_to_string(path).map_err(|err| Error::from(err).in_file(path))?;
+    f(&text).map_err(|err| err.in_file(path))
+}
+
+/// Creates a crate for the specified day under `root`, the repository root,
+/// with an empty input and sample, and registers it with the year's workspace
+/// and with this crate.  The year must already use the per-day crate layout.
+/// Nothing is written unless every existing file can be edited.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<(), Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::parse(format!("{day}: bad day")));
+    }
+    let workspace = root.join(format!("{year}/Cargo.toml"));
+    let dependencies = root.join("runner/Cargo.toml");
+    let solutions = root.join(format!("runner/src/year{year}.rs"));
+    let edits = [
+        (&workspace, edit(&workspace, |text| add_member(text, day))?),
+        (
+            &dependencies,
+            edit(&dependencies, |text| add_dependency(text, year, day))?,
+        ),
+        (
+            &solutions,
+            edit(&solutions, |text| add_solutions(text, year, day))?,
+        ),
+    ];
+    let src = layout::day_dir(root, year, day);
+    let crate_dir = src.parent().expect("crate directory");
+    if crate_dir.exists() {
+        return Err(Error::parse("day already exists").in_file(crate_dir));
+    }
+
+    let input = layout::input_name(year);
+    let sample = input.replacen("input", "sample", 1);
+    fs::create_dir_all(&src).map_err(|err| Error::from(err).in_file(&src))?;
+    write(&crate_dir.join("Cargo.toml"), &cargo_toml(year, day))?;
+    write(&src.join("lib.rs"), &lib_rs(day))?;
+    write(&src.join("part1.rs"), &part_rs(&sample))?;
+    write(&src.join("part2.rs"), &part_rs(&sample))?;
+    write(&src.join("main.rs"), &main_rs(year, day, input))?;
+    write(&src.join(input), "")?;
+    write(&src.join(&sample), "")?;
+    for (path, text) in edits {
+        write(path, &text)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_member_inline() {
+        let manifest = "[workspace]\nmembers = [\"day1\", \"day2\"]\nresolver = \"2\"\n";
+        assert_eq!(
+            add_member(manifest, 3).unwrap(),
+            "[workspace]\nmembers = [\"day1\", \"day2\", \"day3\"]\nresolver = \"2\"\n"
+        );
+        assert!(add_member(manifest, 2).is_err());
+    }
+
+    #[test]
+    fn test_add_member_multiline() {
+        let manifest = "[workspace]\nmembers = [\n    \"day9\",\n    \"day11\",\n]\n";
+        assert_eq!(
+            add_member(manifest, 10).unwrap(),
+            "[workspace]\nmembers = [\n    \"day9\",\n    \"day10\",\n    \"day11\",\n]\n"
+        );
+    }
+
+    #[test]
+    fn test_add_dependency() {
+        let manifest = "[dependencies]\n\
+                        advent2023-day1 = { path = \"../2023/day1\" }\n\
+                        advent2024-day1 = { path = \"../2024/day1\" }\n";
+        assert_eq!(
+            add_dependency(manifest, 2023, 2).unwrap(),
+            "[dependencies]\n\
+             advent2023-day1 = { path = \"../2023/day1\" }\n\
+             advent2023-day2 = { path = \"../2023/day2\" }\n\
+             advent2024-day1 = { path = \"../2024/day1\" }\n"
+        );
+    }
+
+    #[test]
+    fn test_add_solutions() {
+        let module = "pub const SOLUTIONS: &[Solution] = &[\n    \
+                      Solution::part1::<advent2024_day1::Day1>(2024, 1),\n    \
+                      Solution::part2::<advent2024_day1::Day1>(2024, 1),\n];\n";
+        assert_eq!(
+            add_solutions(module, 2024, 2).unwrap(),
+            "pub const SOLUTIONS: &[Solution] = &[\n    \
+             Solution::part1::<advent2024_day1::Day1>(2024, 1),\n    \
+             Solution::part2::<advent2024_day1::Day1>(2024, 1),\n    \
+             Solution::part1::<advent2024_day2::Day2>(2024, 2),\n    \
+             Solution::part2::<advent2024_day2::Day2>(2024, 2),\n];\n"
+        );
+    }
+}
@@ -0,0 +1,54 @@
+use crate::Solution;
+use advent2020::*;
+
+pub const SOLUTIONS: &[Solution] = &[
+    Solution::part1::<day1::Day1>(2020, 1),
+    Solution::part2::<day1::Day1>(2020, 1),
+    Solution::part1::<day2::Day2>(2020, 2),
+    Solution::part2::<day2::Day2>(2020, 2),
+    Solution::part1::<day3::Day3>(2020, 3),
+    Solution::part2::<day3::Day3>(2020, 3),
+    Solution::part1::<day4::Day4>(2020, 4),
+    Solution::part2::<day4::Day4>(2020, 4),
+    Solution::part1::<day5::Day5>(2020, 5),
+    Solution::part2::<day5::Day5>(2020, 5),
+    Solution::part1::<day6::Day6>(2020, 6),
+    Solution::part2::<day6::Day6>(2020, 6),
+    Solution::part1::<day7::Day7>(2020, 7),
+    Solution::part2::<day7::Day7>(2020, 7),
+    Solution::part1::<day8::Day8>(2020, 8),
+    Solution::part2::<day8::Day8>(2020, 8),
+    Solution::part1::<day9::Day9>(2020, 9),
+    Solution::part2::<day9::Day9>(2020, 9),
+    Solution::part1::<day10::Day10>(2020, 10),
+    Solution::part2::<day10::Day10>(2020, 10),
+    Solution::part1::<day11::Day11>(2020, 11),
+    Solution::part2::<day11::Day11>(2020, 11),
+    Solution::part1::<day12::Day12>(2020, 12),
+    Solution::part2::<day12::Day12>(2020, 12),
+    Solution::part1::<day13::Day13>(2020, 13),
+    Solution::part2::<day13::Day13>(2020, 13),
+    Solution::part1::<day14::Day14>(2020, 14),
+    Solution::part2::<day14::Day14>(2020, 14),
+    Solution::part1::<day15::Day15>(2020, 15),
+    Solution::part2::<day15::Day15>(2020, 15),
+    Solution::part1::<day16::Day16>(2020, 16),
+    Solution::part2::<day16::Day16>(2020, 16),
+    Solution::part1::<day17::Day17>(2020, 17),
+    Solution::part2::<day17::Day17>(2020, 17),
+    Solution::part1::<day18::Day18>(2020, 18),
+    Solution::part2::<day18::Day18>(2020, 18),
+    Solution::part1::<day19::Day19>(2020, 19),
+    Solution::part2::<day19::Day19>(2020, 19),
+    Solution::part1::<day20::Day20>(2020, 20),
+    Solution::part2::<day20::Day20>(2020, 20),
+    Solution::part1::<day21::Day21>(2020, 21),
+    Solution::part2::<day21::Day21>(2020, 21),
+    Solution::part1::<day22::Day22>(2020, 22),
+    Solution::part2::<day22::Day22>(2020, 22),
+    Solution::part1::<day23::Day23>(2020, 23),
+    Solution::part2::<day23::Day23>(2020, 23),
+    Solution::part1::<day24::Day24>(2020, 24),
+    Solution::part2::<day24::Day24>(2020, 24),
+    Solution::part1::<day25::Day25>(2020, 25),
+];
@@ -0,0 +1,47 @@
+use crate::Solution;
+use advent2021::*;
+
+pub const SOLUTIONS: &[Solution] = &[
+    Solution::part1::<day1::Day1>(2021, 1),
+    Solution::part2::<day1::Day1>(2021, 1),
+    Solution::part1::<day2::Day2>(2021, 2),
+    Solution::part2::<day2::Day2>(2021, 2),
+    Solution::part1::<day3::Day3>(2021, 3),
+    Solution::part2::<day3::Day3>(2021, 3),
+    Solution::part1::<day4::Day4>(2021, 4),
+    Solution::part2::<day4::Day4>(2021, 4),
+    Solution::part1::<day5::Day5>(2021, 5),
+    Solution::part2::<day5::Day5>(2021, 5),
+    Solution::part1::<day6::Day6>(2021, 6),
+    Solution::part2::<day6::Day6>(2021, 6),
+    Solution::part1::<day7::Day7>(2021, 7),
+    Solution::part2::<day7::Day7>(2021, 7),
+    Solution::part1::<day8::Day8>(2021, 8),
+    Solution::part2::<day8::Day8>(2021, 8),
+    Solution::part1::<day9::Day9>(2021, 9),
+    Solution::part2::<day9::Day9>(2021, 9),
+    Solution::part1::<day10::Day10>(2021, 10),
+    Solution::part2::<day10::Day10>(2021, 10),
+    Solution::part1::<day11::Day11>(2021, 11),
+    Solution::part2::<day11::Day11>(2021, 11),
+    Solution::part1::<day12::Day12>(2021, 12),
+    Solution::part2::<day12::Day12>(2021, 12),
+    Solution::part1::<day13::Day13>(2021, 13),
+    Solution::part2::<day13::Day13>(2021, 13),
+    Solution::part1::<day14::Day14>(2021, 14),
+    Solution::part2::<day14::Day14>(2021, 14),
+    Solution::part1::<day15::Day15>(2021, 15),
+    Solution::part2::<day15::Day15>(2021, 15),
+    Solution::part1::<day16::Day16>(2021, 16),
+    Solution::part2::<day16::Day16>(2021, 16),
+    Solution::part1::<day17::Day17>(2021, 17),
+    Solution::part2::<day17::Day17>(2021, 17),
+    Solution::part1::<day18::Day18>(2021, 18),
+    Solution::part2::<day18::Day18>(2021, 18),
+    Solution::part1::<day19::Day19>(2021, 19),
+    Solution::part2::<day19::Day19>(2021, 19),
+    Solution::part1::<day20::Day20>(2021, 20),
+    Solution::part2::<day20::Day20>(2021, 20),
+    Solution::part1::<day21::Day21>(2021, 21),
+    Solution::part2::<day21::Day21>(2021, 21),
+];
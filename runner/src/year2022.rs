@@ -0,0 +1,13 @@
+use crate::Solution;
+use advent2022::*;
+
+pub const SOLUTIONS: &[Solution] = &[
+    Solution::part1::<day1::Day1>(2022, 1),
+    Solution::part2::<day1::Day1>(2022, 1),
+    Solution::part1::<day2::Day2>(2022, 2),
+    Solution::part2::<day2::Day2>(2022, 2),
+    Solution::part1::<day3::Day3>(2022, 3),
+    Solution::part2::<day3::Day3>(2022, 3),
+    Solution::part1::<day4::Day4>(2022, 4),
+    Solution::part2::<day4::Day4>(2022, 4),
+];
@@ -0,0 +1,26 @@
+use crate::Solution;
+
+pub const SOLUTIONS: &[Solution] = &[
+    Solution::part1::<advent2023_day1::Day1>(2023, 1),
+    Solution::part2::<advent2023_day1::Day1>(2023, 1),
+    Solution::part1::<advent2023_day2::Day2>(2023, 2),
+    Solution::part2::<advent2023_day2::Day2>(2023, 2),
+    Solution::part1::<advent2023_day3::Day3>(2023, 3),
+    Solution::part2::<advent2023_day3::Day3>(2023, 3),
+    Solution::part1::<advent2023_day4::Day4>(2023, 4),
+    Solution::part2::<advent2023_day4::Day4>(2023, 4),
+    Solution::part1::<advent2023_day5::Day5>(2023, 5),
+    Solution::part2::<advent2023_day5::Day5>(2023, 5),
+    Solution::part1::<advent2023_day6::Day6>(2023, 6),
+    Solution::part2::<advent2023_day6::Day6>(2023, 6),
+    Solution::part1::<advent2023_day7::Day7>(2023, 7),
+    Solution::part2::<advent2023_day7::Day7>(2023, 7),
+    Solution::part1::<advent2023_day8::Day8>(2023, 8),
+    Solution::part2::<advent2023_day8::Day8>(2023, 8),
+    Solution::part1::<advent2023_day9::Day9>(2023, 9),
+    Solution::part2::<advent2023_day9::Day9>(2023, 9),
+    Solution::part1::<advent2023_day10::Day10>(2023, 10),
+    Solution::part2::<advent2023_day10::Day10>(2023, 10),
+    Solution::part1::<advent2023_day11::Day11>(2023, 11),
+    Solution::part2::<advent2023_day11::Day11>(2023, 11),
+];
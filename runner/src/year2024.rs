@@ -0,0 +1,10 @@
+use crate::Solution;
+
+pub const SOLUTIONS: &[Solution] = &[
+    Solution::part1::<advent2024_day1::Day1>(2024, 1),
+    Solution::part2::<advent2024_day1::Day1>(2024, 1),
+    Solution::part1::<advent2024_day2::Day2>(2024, 2),
+    Solution::part2::<advent2024_day2::Day2>(2024, 2),
+    Solution::part1::<advent2024_day3::Day3>(2024, 3),
+    Solution::part2::<advent2024_day3::Day3>(2024, 3),
+];
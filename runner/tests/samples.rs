@@ -0,0 +1,17 @@
+//! Checks each solution against every sample input having a recorded answer.
+//! The test cases are generated by the build script; see `build.rs` for the
+//! naming convention of sample and answer files.
+
+use advent_runner::{solutions, Verdict};
+use std::fs;
+
+fn check(year: u16, day: u8, part: u8, sample: &str, answer: &str) {
+    let solution = solutions()
+        .find(|s| (s.year, s.day, s.part) == (year, day, part))
+        .unwrap_or_else(|| panic!("no solution for {year} day{day} part{part}"));
+    let text = fs::read_to_string(sample).expect(sample);
+    let expected = fs::read_to_string(answer).expect(answer);
+    assert_eq!(solution.verify(&text, Some(&expected)), Verdict::Pass);
+}
+
+include!(concat!(env!("OUT_DIR"), "/samples.rs"));